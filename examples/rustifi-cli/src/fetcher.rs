@@ -414,7 +414,7 @@ fn print_device(device: &rustifi::models::SiteDevice) {
     println!("Device:");
     println!("  ID: {}", device.id);
     println!("  Name: {}", device.name);
-    println!("  MAC: {}", device.mac_address.as_str());
+    println!("  MAC: {}", device.mac_address);
     println!("  Model: {}", device.model);
     println!("  State: {:?}", device.state);
 
@@ -580,6 +580,13 @@ fn print_network(network: &rustifi::api::networks::Network) {
         println!("  Management: {:?}", management);
     }
 
+    if let Some(ref ipv4) = network.ipv4_configuration {
+        println!("  Subnet: {}", ipv4);
+        if let Some(range) = ipv4.dhcp.as_ref().and_then(|dhcp| dhcp.range.as_ref()) {
+            println!("  DHCP Range: {}", range);
+        }
+    }
+
     if let Some(ref metadata) = network.metadata {
         if let Some(ref origin) = metadata.origin {
             println!("  Origin: {:?}", origin);
@@ -622,14 +629,14 @@ fn print_device_statistics(stats: &rustifi::models::DeviceStatistics) {
     if let Some(ref uplink) = stats.uplink {
         println!("\n  Uplink:");
         println!(
-            "    TX Rate: {:.2} Mbps ({} bps)",
-            uplink.tx_rate_mbps(),
-            uplink.tx_rate_bps
+            "    TX Rate: {} ({} bps)",
+            uplink.tx_rate_bps,
+            uplink.tx_rate_bps.as_bps()
         );
         println!(
-            "    RX Rate: {:.2} Mbps ({} bps)",
-            uplink.rx_rate_mbps(),
-            uplink.rx_rate_bps
+            "    RX Rate: {} ({} bps)",
+            uplink.rx_rate_bps,
+            uplink.rx_rate_bps.as_bps()
         );
     }
 
@@ -653,7 +660,7 @@ fn print_device_details(device: &rustifi::models::DeviceDetails) {
     println!("Device Details:");
     println!("  ID: {}", device.id);
     println!("  Name: {}", device.name);
-    println!("  MAC: {}", device.mac_address.as_str());
+    println!("  MAC: {}", device.mac_address);
     println!("  IP: {}", device.ip_address.as_str());
     println!("  Model: {}", device.model);
     println!("  State: {}", device.state);
@@ -696,9 +703,9 @@ fn print_device_details(device: &rustifi::models::DeviceDetails) {
             println!("    Port {}:", port.idx);
             println!("      State: {:?}", port.state);
             println!("      Connector: {:?}", port.connector);
-            println!("      Max Speed: {}Mbps", port.max_speed_mbps);
+            println!("      Max Speed: {}", port.max_speed_mbps);
             if let Some(speed) = port.speed_mbps {
-                println!("      Speed: {}Mbps", speed);
+                println!("      Speed: {}", speed);
             }
             if let Some(ref poe) = port.poe {
                 println!("      PoE:");
@@ -1,7 +1,11 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::error::{Error, Result};
+use crate::models::common::{IsoDateTime, MacAddress};
+use crate::models::firewall::IpCidr;
 use crate::response::{DeleteResponse, MutationResponse, SiteResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::fmt;
 
 /// ACL rule action.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -11,8 +15,188 @@ pub enum AclAction {
     Block,
 }
 
+/// What one side of an ACL rule matches.
+///
+/// Sent as a type and value pair, e.g. `"sourceType": "MAC"` and
+/// `"sourceValue": "aa:bb:cc:dd:ee:ff"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AclTarget {
+    /// A single client by MAC address.
+    Mac(MacAddress),
+    /// An address or subnet.
+    Ip(IpCidr),
+    /// Every client of a network, by network ID.
+    Network(String),
+    /// Members of a client group, by group ID.
+    ClientGroup(String),
+    /// A UniFi device, by device ID.
+    Device(String),
+    /// A target this crate does not know or could not parse, kept as sent.
+    /// An empty type or a `None` value was missing from the response.
+    Other {
+        target_type: String,
+        value: Option<String>,
+    },
+}
+
+impl AclTarget {
+    /// Parse a target from its wire type and value, checking that the value
+    /// fits the type.
+    pub fn parse(target_type: &str, value: &str) -> Result<Self> {
+        let invalid =
+            |what: &str| Error::InvalidAclRule(format!("'{}' is not a valid {}", value, what));
+        let id = |what: &str| {
+            let value = value.trim();
+            if value.is_empty() {
+                Err(Error::InvalidAclRule(format!("{} ID is empty", what)))
+            } else {
+                Ok(value.to_string())
+            }
+        };
+        Ok(match target_type {
+            "MAC" => AclTarget::Mac(value.parse().map_err(|_| invalid("MAC address"))?),
+            "IP" => AclTarget::Ip(value.parse().map_err(|_| invalid("IP address or subnet"))?),
+            "NETWORK" => AclTarget::Network(id("Network")?),
+            "CLIENT_GROUP" => AclTarget::ClientGroup(id("Client group")?),
+            "DEVICE" => AclTarget::Device(id("Device")?),
+            _ => AclTarget::Other {
+                target_type: target_type.to_string(),
+                value: Some(value.to_string()),
+            },
+        })
+    }
+
+    /// Get the wire type, e.g. `"MAC"`.
+    pub fn target_type(&self) -> &str {
+        match self {
+            AclTarget::Mac(_) => "MAC",
+            AclTarget::Ip(_) => "IP",
+            AclTarget::Network(_) => "NETWORK",
+            AclTarget::ClientGroup(_) => "CLIENT_GROUP",
+            AclTarget::Device(_) => "DEVICE",
+            AclTarget::Other { target_type, .. } => target_type,
+        }
+    }
+
+    /// Get the wire value.
+    pub fn value(&self) -> String {
+        match self {
+            AclTarget::Mac(mac) => mac.to_string(),
+            AclTarget::Ip(cidr) if cidr.prefix_length() == host_prefix(cidr) => {
+                cidr.address().to_string()
+            }
+            AclTarget::Ip(cidr) => cidr.to_string(),
+            AclTarget::Network(id) | AclTarget::ClientGroup(id) | AclTarget::Device(id) => {
+                id.clone()
+            }
+            AclTarget::Other { value, .. } => value.clone().unwrap_or_default(),
+        }
+    }
+
+    /// Parse a target from a response, keeping values that do not parse as
+    /// [`AclTarget::Other`] so one odd rule does not fail a whole page.
+    fn from_wire(target_type: Option<String>, value: Option<String>) -> Self {
+        match (&target_type, &value) {
+            (Some(t), Some(v)) => AclTarget::parse(t, v).unwrap_or_else(|_| AclTarget::Other {
+                target_type: t.clone(),
+                value: Some(v.clone()),
+            }),
+            _ => AclTarget::Other {
+                target_type: target_type.unwrap_or_default(),
+                value,
+            },
+        }
+    }
+
+    /// Check if this target matches everything `other` does. Only IP targets
+    /// can contain other targets; the rest must be equal.
+    pub fn covers(&self, other: &AclTarget) -> bool {
+        match (self, other) {
+            (AclTarget::Ip(outer), AclTarget::Ip(inner)) => {
+                outer.prefix_length() <= inner.prefix_length() && outer.contains(inner.address())
+            }
+            _ => self == other,
+        }
+    }
+}
+
+fn host_prefix(cidr: &IpCidr) -> u8 {
+    if cidr.address().is_ipv4() {
+        32
+    } else {
+        128
+    }
+}
+
+impl fmt::Display for AclTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.target_type(), self.value())
+    }
+}
+
+impl From<MacAddress> for AclTarget {
+    fn from(mac: MacAddress) -> Self {
+        AclTarget::Mac(mac)
+    }
+}
+
+impl From<IpCidr> for AclTarget {
+    fn from(cidr: IpCidr) -> Self {
+        AclTarget::Ip(cidr)
+    }
+}
+
+/// Serde adapters for an optional [`AclTarget`] flattened into a type field
+/// and a value field.
+macro_rules! acl_target_fields {
+    ($module:ident, $type_field:literal, $value_field:literal) => {
+        mod $module {
+            use super::AclTarget;
+            use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+            #[derive(Deserialize, Serialize)]
+            struct Fields {
+                #[serde(rename = $type_field, default, skip_serializing_if = "Option::is_none")]
+                target_type: Option<String>,
+                #[serde(rename = $value_field, default, skip_serializing_if = "Option::is_none")]
+                value: Option<String>,
+            }
+
+            pub fn serialize<S: Serializer>(
+                target: &Option<AclTarget>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                Fields {
+                    target_type: target
+                        .as_ref()
+                        .map(|t| t.target_type().to_string())
+                        .filter(|t| !t.is_empty()),
+                    value: target.as_ref().and_then(|t| match t {
+                        AclTarget::Other { value, .. } => value.clone(),
+                        _ => Some(t.value()),
+                    }),
+                }
+                .serialize(serializer)
+            }
+
+            pub fn deserialize<'de, D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Option<AclTarget>, D::Error> {
+                let fields = Fields::deserialize(deserializer)?;
+                Ok(match (fields.target_type, fields.value) {
+                    (None, None) => None,
+                    (target_type, value) => Some(AclTarget::from_wire(target_type, value)),
+                })
+            }
+        }
+    };
+}
+
+acl_target_fields!(source_target, "sourceType", "sourceValue");
+acl_target_fields!(destination_target, "destinationType", "destinationValue");
+
 /// ACL rule.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct AclRule {
     pub id: String,
@@ -20,18 +204,19 @@ pub struct AclRule {
     pub action: AclAction,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(default)]
-    pub source_type: Option<String>,
-    #[serde(default)]
-    pub source_value: Option<String>,
-    #[serde(default)]
-    pub destination_type: Option<String>,
-    #[serde(default)]
-    pub destination_value: Option<String>,
+    /// Traffic source. `None` matches any source.
+    #[serde(flatten, with = "source_target")]
+    pub source: Option<AclTarget>,
+    /// Traffic destination. `None` matches any destination.
+    #[serde(flatten, with = "destination_target")]
+    pub destination: Option<AclTarget>,
+    /// When the rule was last changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<IsoDateTime>,
 }
 
 /// Fetch all ACL rules for a specific site.
@@ -39,14 +224,40 @@ pub struct AclRule {
 #[derive(Debug, Clone)]
 pub struct GetAclRules {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetAclRules {
+    /// Create a new GetAclRules endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Create a new GetAclRules endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
         }
     }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetAclRules {
@@ -57,6 +268,18 @@ impl Endpoint for GetAclRules {
     fn build_path(&self) -> String {
         format!("sites/{}/acl-rules", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetAclRules {
+    type Item = AclRule;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Fetch a specific ACL rule by ID within a site.
@@ -98,14 +321,10 @@ pub struct AclRuleRequest {
     pub order: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_type: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_value: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_type: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_value: Option<String>,
+    #[serde(flatten, with = "source_target")]
+    pub source: Option<AclTarget>,
+    #[serde(flatten, with = "destination_target")]
+    pub destination: Option<AclTarget>,
 }
 
 impl AclRuleRequest {
@@ -116,13 +335,55 @@ impl AclRuleRequest {
             enabled: None,
             order: None,
             description: None,
-            source_type: None,
-            source_value: None,
-            destination_type: None,
-            destination_value: None,
+            source: None,
+            destination: None,
         }
     }
 
+    /// Block a client from reaching a network.
+    pub fn block_mac_to_network(
+        name: impl Into<String>,
+        mac: MacAddress,
+        network_id: impl Into<String>,
+    ) -> Self {
+        Self::new(name, AclAction::Block)
+            .source(AclTarget::Mac(mac))
+            .destination(AclTarget::Network(network_id.into()))
+    }
+
+    /// Allow a client to reach a network.
+    pub fn allow_mac_to_network(
+        name: impl Into<String>,
+        mac: MacAddress,
+        network_id: impl Into<String>,
+    ) -> Self {
+        Self::new(name, AclAction::Allow)
+            .source(AclTarget::Mac(mac))
+            .destination(AclTarget::Network(network_id.into()))
+    }
+
+    /// Block every client of one network from reaching another.
+    pub fn block_network_to_network(
+        name: impl Into<String>,
+        source_network_id: impl Into<String>,
+        destination_network_id: impl Into<String>,
+    ) -> Self {
+        Self::new(name, AclAction::Block)
+            .source(AclTarget::Network(source_network_id.into()))
+            .destination(AclTarget::Network(destination_network_id.into()))
+    }
+
+    /// Block a client group from reaching a network.
+    pub fn block_client_group_to_network(
+        name: impl Into<String>,
+        group_id: impl Into<String>,
+        network_id: impl Into<String>,
+    ) -> Self {
+        Self::new(name, AclAction::Block)
+            .source(AclTarget::ClientGroup(group_id.into()))
+            .destination(AclTarget::Network(network_id.into()))
+    }
+
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.enabled = Some(enabled);
         self
@@ -138,25 +399,67 @@ impl AclRuleRequest {
         self
     }
 
-    pub fn source(
-        mut self,
-        source_type: impl Into<String>,
-        source_value: impl Into<String>,
-    ) -> Self {
-        self.source_type = Some(source_type.into());
-        self.source_value = Some(source_value.into());
+    pub fn source(mut self, source: impl Into<AclTarget>) -> Self {
+        self.source = Some(source.into());
         self
     }
 
-    pub fn destination(
-        mut self,
-        dest_type: impl Into<String>,
-        dest_value: impl Into<String>,
-    ) -> Self {
-        self.destination_type = Some(dest_type.into());
-        self.destination_value = Some(dest_value.into());
+    pub fn destination(mut self, destination: impl Into<AclTarget>) -> Self {
+        self.destination = Some(destination.into());
         self
     }
+
+    /// Check the rule before sending it to the controller.
+    ///
+    /// The name must not be empty, the rule must match on a source or a
+    /// destination, and the two must differ. Typed targets are checked when
+    /// they are built; [`AclTarget::Other`] values must be non-empty, and
+    /// ones kept from a response must parse for their type.
+    pub fn validate(&self) -> Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(Error::InvalidAclRule("Rule name is empty".to_string()));
+        }
+        if self.source.is_none() && self.destination.is_none() {
+            return Err(Error::InvalidAclRule(format!(
+                "Rule '{}' has neither a source nor a destination",
+                self.name
+            )));
+        }
+        if self.source.is_some() && self.source == self.destination {
+            return Err(Error::InvalidAclRule(format!(
+                "Rule '{}' has the same source and destination",
+                self.name
+            )));
+        }
+        for target in self.source.iter().chain(&self.destination) {
+            if let AclTarget::Other { target_type, value } = target {
+                let value = value.as_deref().unwrap_or_default();
+                if target_type.is_empty() || value.is_empty() {
+                    return Err(Error::InvalidAclRule(format!(
+                        "Rule '{}' has an empty target",
+                        self.name
+                    )));
+                }
+                // Values kept from a response must still fit a known type
+                AclTarget::parse(target_type, value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<&AclRule> for AclRuleRequest {
+    fn from(rule: &AclRule) -> Self {
+        Self {
+            name: rule.name.clone(),
+            action: rule.action.clone(),
+            enabled: Some(rule.enabled),
+            order: rule.order,
+            description: rule.description.clone(),
+            source: rule.source.clone(),
+            destination: rule.destination.clone(),
+        }
+    }
 }
 
 /// Create a new ACL rule within a site.
@@ -288,3 +591,128 @@ impl Endpoint for ReorderAclRules {
         })?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_acl_rule_typed_targets() {
+        let rule: AclRule = serde_json::from_value(json!({
+            "id": "acl-1",
+            "name": "Block camera",
+            "action": "BLOCK",
+            "enabled": true,
+            "sourceType": "MAC",
+            "sourceValue": "AA:BB:CC:DD:EE:FF",
+            "destinationType": "IP",
+            "destinationValue": "10.0.0.0/24"
+        }))
+        .unwrap();
+
+        let mac = MacAddress::new("aa:bb:cc:dd:ee:ff").unwrap();
+        assert_eq!(rule.source, Some(AclTarget::Mac(mac)));
+        assert_eq!(
+            rule.destination,
+            Some(AclTarget::Ip("10.0.0.0/24".parse().unwrap()))
+        );
+
+        let any_source: AclRule = serde_json::from_value(json!({
+            "id": "acl-2",
+            "name": "Guests",
+            "action": "ALLOW",
+            "destinationType": "FUTURE_TYPE",
+            "destinationValue": "x"
+        }))
+        .unwrap();
+        assert_eq!(any_source.source, None);
+        assert_eq!(
+            any_source.destination.as_ref().unwrap().target_type(),
+            "FUTURE_TYPE"
+        );
+    }
+
+    #[test]
+    fn test_acl_target_parse_rejects_mismatched_values() {
+        assert!(AclTarget::parse("MAC", "10.0.0.5").is_err());
+        assert!(AclTarget::parse("IP", "aa:bb:cc:dd:ee:ff").is_err());
+        assert!(AclTarget::parse("NETWORK", " ").is_err());
+        assert!(AclTarget::parse("IP", "10.0.0.5").is_ok());
+    }
+
+    #[test]
+    fn test_acl_rule_keeps_unparsed_targets() {
+        let fixture = json!({
+            "id": "acl-1",
+            "name": "Rule",
+            "action": "BLOCK",
+            "enabled": true,
+            "sourceType": "MAC",
+            "sourceValue": "not-a-mac",
+            "destinationType": "ANY"
+        });
+        let rule: AclRule = serde_json::from_value(fixture.clone()).unwrap();
+        assert_eq!(
+            rule.source,
+            Some(AclTarget::Other {
+                target_type: "MAC".to_string(),
+                value: Some("not-a-mac".to_string()),
+            })
+        );
+        assert_eq!(
+            rule.destination,
+            Some(AclTarget::Other {
+                target_type: "ANY".to_string(),
+                value: None,
+            })
+        );
+        assert_eq!(serde_json::to_value(&rule).unwrap(), fixture);
+
+        let request = AclRuleRequest::from(&rule);
+        assert!(request.validate().is_err());
+    }
+
+    #[test]
+    fn test_acl_rule_request_constructors() {
+        let mac = MacAddress::new("aa:bb:cc:dd:ee:ff").unwrap();
+        let request = AclRuleRequest::block_mac_to_network("Block camera", mac, "net-1");
+        assert!(request.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "name": "Block camera",
+                "action": "BLOCK",
+                "sourceType": "MAC",
+                "sourceValue": "aa:bb:cc:dd:ee:ff",
+                "destinationType": "NETWORK",
+                "destinationValue": "net-1"
+            })
+        );
+
+        let host = AclRuleRequest::new("Printer", AclAction::Allow)
+            .destination(IpCidr::host("10.0.0.20".parse().unwrap()));
+        assert_eq!(
+            serde_json::to_value(&host).unwrap()["destinationValue"],
+            "10.0.0.20"
+        );
+    }
+
+    #[test]
+    fn test_acl_rule_request_validate() {
+        assert!(AclRuleRequest::new("Everything", AclAction::Block)
+            .validate()
+            .is_err());
+        assert!(
+            AclRuleRequest::block_network_to_network("Loop", "net-1", "net-1")
+                .validate()
+                .is_err()
+        );
+        assert!(
+            AclRuleRequest::block_client_group_to_network(" ", "group-1", "net-1")
+                .validate()
+                .is_err()
+        );
+        assert!(AclTarget::parse("MAC", "not-a-mac").is_err());
+    }
+}
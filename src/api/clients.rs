@@ -1,7 +1,9 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
-use crate::models::Client;
-use crate::response::{EmptyResponse, SiteResponse};
-use serde::Serialize;
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::models::units::{self, Bandwidth, DataSize, Minutes};
+use crate::models::{Client, GuestAuthorization};
+use crate::response::SiteResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// Fetch all clients for a specific site with optional pagination.
@@ -55,14 +57,15 @@ impl Endpoint for GetClients {
     }
 
     fn query_params(&self) -> Vec<(&'static str, String)> {
-        let mut params = vec![];
-        if let Some(offset) = self.offset {
-            params.push(("offset", offset.to_string()));
-        }
-        if let Some(limit) = self.limit {
-            params.push(("limit", limit.to_string()));
-        }
-        params
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetClients {
+    type Item = Client;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
     }
 }
 
@@ -94,7 +97,7 @@ impl Endpoint for GetClient {
 }
 
 /// Client action types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ClientAction {
     /// Block the client from the network.
@@ -103,22 +106,127 @@ pub enum ClientAction {
     Unblock,
     /// Force the client to reconnect.
     Reconnect,
+    /// Authorize a guest client through the hotspot, optionally with limits.
+    AuthorizeGuestAccess,
+    /// Revoke a guest client's hotspot authorization.
+    UnauthorizeGuestAccess,
+}
+
+/// Limits applied when authorizing a guest.
+///
+/// Unset limits fall back to the site's hotspot defaults.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestAccessLimits {
+    #[serde(rename = "timeLimitMinutes", skip_serializing_if = "Option::is_none")]
+    pub time_limit: Option<Minutes>,
+    #[serde(
+        rename = "dataUsageLimitMBytes",
+        with = "units::serde::megabytes::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data_usage_limit: Option<DataSize>,
+    #[serde(
+        rename = "rxRateLimitKbps",
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rx_rate_limit: Option<Bandwidth>,
+    #[serde(
+        rename = "txRateLimitKbps",
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tx_rate_limit: Option<Bandwidth>,
+}
+
+impl GuestAccessLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how long the authorization lasts.
+    pub fn time_limit(mut self, limit: Minutes) -> Self {
+        self.time_limit = Some(limit);
+        self
+    }
+
+    /// Set the data quota. Must be a whole number of megabytes.
+    pub fn data_usage_limit(mut self, limit: DataSize) -> Self {
+        self.data_usage_limit = Some(limit);
+        self
+    }
+
+    /// Set the download rate limit. Must be a whole number of kbps.
+    pub fn rx_rate_limit(mut self, limit: Bandwidth) -> Self {
+        self.rx_rate_limit = Some(limit);
+        self
+    }
+
+    /// Set the upload rate limit. Must be a whole number of kbps.
+    pub fn tx_rate_limit(mut self, limit: Bandwidth) -> Self {
+        self.tx_rate_limit = Some(limit);
+        self
+    }
+
+    /// Build limits that extend `current` by `extension` as of `now`.
+    ///
+    /// The new time limit is the time remaining plus `extension`; data and rate
+    /// limits are carried over. An expired authorization is extended from `now`.
+    /// Returns `None` if the time limit would overflow.
+    pub fn extending(
+        current: &GuestAuthorization,
+        extension: Minutes,
+        now: DateTime<Utc>,
+    ) -> Option<Self> {
+        let remaining = match current.remaining_at(now) {
+            // Round partial minutes up so an extension never shortens access
+            Some(left) => Minutes::from_duration(left + chrono::Duration::seconds(59))?,
+            None => Minutes::new(0),
+        };
+
+        Some(Self {
+            time_limit: Some(remaining.checked_add(extension)?),
+            data_usage_limit: current.data_usage_limit,
+            rx_rate_limit: current.rx_rate_limit,
+            tx_rate_limit: current.tx_rate_limit,
+        })
+    }
 }
 
 /// Request body for client actions.
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
-struct ClientActionRequest {
+struct ClientActionRequest<'a> {
     action: ClientAction,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    limits: Option<&'a GuestAccessLimits>,
 }
 
-/// Execute an action on a client (block, unblock, reconnect).
+/// Result of a client action.
+///
+/// Guest authorization actions report the authorization that was granted or
+/// revoked; other actions return an empty body.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientActionResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<ClientAction>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub granted_authorization: Option<GuestAuthorization>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revoked_authorization: Option<GuestAuthorization>,
+}
+
+/// Execute an action on a client (block, unblock, reconnect, guest authorization).
 /// Endpoint: POST /v1/sites/{siteId}/clients/{id}/action
 #[derive(Debug, Clone)]
 pub struct ExecuteClientAction {
     pub site_id: String,
     pub client_id: String,
     pub action: ClientAction,
+    /// Limits for [`ClientAction::AuthorizeGuestAccess`].
+    pub limits: Option<GuestAccessLimits>,
 }
 
 impl ExecuteClientAction {
@@ -131,6 +239,7 @@ impl ExecuteClientAction {
             site_id: site_id.into(),
             client_id: client_id.into(),
             action,
+            limits: None,
         }
     }
 
@@ -148,12 +257,30 @@ impl ExecuteClientAction {
     pub fn reconnect(site_id: impl Into<String>, client_id: impl Into<String>) -> Self {
         Self::new(site_id, client_id, ClientAction::Reconnect)
     }
+
+    /// Create an action authorizing a guest client with the given limits.
+    ///
+    /// Authorizing an already authorized guest replaces its authorization.
+    pub fn authorize_guest(
+        site_id: impl Into<String>,
+        client_id: impl Into<String>,
+        limits: GuestAccessLimits,
+    ) -> Self {
+        let mut action = Self::new(site_id, client_id, ClientAction::AuthorizeGuestAccess);
+        action.limits = Some(limits);
+        action
+    }
+
+    /// Create an action revoking a guest client's authorization.
+    pub fn unauthorize_guest(site_id: impl Into<String>, client_id: impl Into<String>) -> Self {
+        Self::new(site_id, client_id, ClientAction::UnauthorizeGuestAccess)
+    }
 }
 
 impl Endpoint for ExecuteClientAction {
     const PATH: &'static str = "sites/{site_id}/clients/{client_id}/action";
     const METHOD: HttpMethod = HttpMethod::Post;
-    type Response = EmptyResponse;
+    type Response = ClientActionResponse;
 
     fn build_path(&self) -> String {
         format!("sites/{}/clients/{}/action", self.site_id, self.client_id)
@@ -162,6 +289,103 @@ impl Endpoint for ExecuteClientAction {
     fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
         Ok(Some(serde_json::to_value(ClientActionRequest {
             action: self.action,
+            limits: self.limits.as_ref(),
         })?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_block_action_body() {
+        let endpoint = ExecuteClientAction::block("site-1", "client-1");
+
+        assert_eq!(
+            endpoint.build_path(),
+            "sites/site-1/clients/client-1/action"
+        );
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({"action": "BLOCK"}))
+        );
+    }
+
+    #[test]
+    fn test_authorize_guest_body() {
+        let limits = GuestAccessLimits::new()
+            .time_limit(Minutes::from_hours(2).unwrap())
+            .data_usage_limit("1GB".parse().unwrap())
+            .rx_rate_limit("10Mbps".parse().unwrap())
+            .tx_rate_limit("2Mbps".parse().unwrap());
+        let endpoint = ExecuteClientAction::authorize_guest("site-1", "client-1", limits);
+
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "action": "AUTHORIZE_GUEST_ACCESS",
+                "timeLimitMinutes": 120,
+                "dataUsageLimitMBytes": 1000,
+                "rxRateLimitKbps": 10000,
+                "txRateLimitKbps": 2000
+            }))
+        );
+
+        let minimal =
+            ExecuteClientAction::authorize_guest("site-1", "client-1", GuestAccessLimits::new());
+        assert_eq!(
+            minimal.request_body().unwrap(),
+            Some(json!({"action": "AUTHORIZE_GUEST_ACCESS"}))
+        );
+        assert_eq!(
+            ExecuteClientAction::unauthorize_guest("site-1", "client-1")
+                .request_body()
+                .unwrap(),
+            Some(json!({"action": "UNAUTHORIZE_GUEST_ACCESS"}))
+        );
+    }
+
+    #[test]
+    fn test_client_action_response() {
+        let response: ClientActionResponse = serde_json::from_value(json!({
+            "action": "AUTHORIZE_GUEST_ACCESS",
+            "grantedAuthorization": {
+                "authorizedAt": "2024-01-01T12:00:00Z",
+                "authorizationMethod": "API",
+                "expiresAt": "2024-01-01T14:00:00Z",
+                "rxRateLimitKbps": 10000
+            }
+        }))
+        .unwrap();
+
+        assert_eq!(response.action, Some(ClientAction::AuthorizeGuestAccess));
+        let granted = response.granted_authorization.unwrap();
+        assert_eq!(granted.rx_rate_limit, Bandwidth::from_mbps(10));
+        assert!(response.revoked_authorization.is_none());
+
+        let empty: ClientActionResponse = serde_json::from_value(json!({})).unwrap();
+        assert_eq!(empty, ClientActionResponse::default());
+    }
+
+    #[test]
+    fn test_extending_limits() {
+        let current = GuestAuthorization {
+            expires_at: Some("2024-01-01T14:00:00Z".parse().unwrap()),
+            data_usage_limit: DataSize::from_mb(500),
+            rx_rate_limit: Bandwidth::from_mbps(5),
+            ..Default::default()
+        };
+
+        let now: DateTime<Utc> = "2024-01-01T13:29:30Z".parse().unwrap();
+        let limits = GuestAccessLimits::extending(&current, Minutes::new(60), now).unwrap();
+        assert_eq!(limits.time_limit, Some(Minutes::new(91)));
+        assert_eq!(limits.data_usage_limit, DataSize::from_mb(500));
+        assert_eq!(limits.rx_rate_limit, Bandwidth::from_mbps(5));
+
+        let expired: DateTime<Utc> = "2024-01-01T15:00:00Z".parse().unwrap();
+        let limits = GuestAccessLimits::extending(&current, Minutes::new(30), expired).unwrap();
+        assert_eq!(limits.time_limit, Some(Minutes::new(30)));
+    }
+}
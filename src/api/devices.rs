@@ -1,5 +1,5 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
-use crate::models::{DeviceDetails, DeviceStatistics, SiteDevice};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::models::{DeviceDetails, DeviceStatistics, IpAddress, SiteDevice};
 use crate::response::{EmptyResponse, MutationResponse, SiteResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -55,14 +55,15 @@ impl Endpoint for GetDevices {
     }
 
     fn query_params(&self) -> Vec<(&'static str, String)> {
-        let mut params = vec![];
-        if let Some(offset) = self.offset {
-            params.push(("offset", offset.to_string()));
-        }
-        if let Some(limit) = self.limit {
-            params.push(("limit", limit.to_string()));
-        }
-        params
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetDevices {
+    type Item = SiteDevice;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
     }
 }
 
@@ -127,17 +128,17 @@ impl Endpoint for GetDeviceStatistics {
 }
 
 /// Pending device awaiting adoption.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PendingDevice {
     pub id: String,
     pub mac: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub model: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
-    #[serde(default)]
-    pub ip_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<IpAddress>,
 }
 
 /// Fetch pending devices awaiting adoption for a specific site.
@@ -145,14 +146,40 @@ pub struct PendingDevice {
 #[derive(Debug, Clone)]
 pub struct GetPendingDevices {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetPendingDevices {
+    /// Create a new GetPendingDevices endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Create a new GetPendingDevices endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
         }
     }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetPendingDevices {
@@ -163,6 +190,18 @@ impl Endpoint for GetPendingDevices {
     fn build_path(&self) -> String {
         format!("sites/{}/devices/pending", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetPendingDevices {
+    type Item = PendingDevice;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Request body for adopting a device.
@@ -231,6 +270,8 @@ pub enum DeviceAction {
     LocateOn,
     /// Turn off locate LED.
     LocateOff,
+    /// Upgrade the device to the latest available firmware.
+    Upgrade,
 }
 
 /// Request body for device actions.
@@ -240,7 +281,7 @@ struct DeviceActionRequest {
     action: DeviceAction,
 }
 
-/// Execute an action on a device (restart, locate LED).
+/// Execute an action on a device (restart, locate LED, firmware upgrade).
 /// Endpoint: POST /v1/sites/{siteId}/devices/{id}/action
 #[derive(Debug, Clone)]
 pub struct ExecuteDeviceAction {
@@ -276,6 +317,11 @@ impl ExecuteDeviceAction {
     pub fn locate_off(site_id: impl Into<String>, device_id: impl Into<String>) -> Self {
         Self::new(site_id, device_id, DeviceAction::LocateOff)
     }
+
+    /// Create a firmware upgrade action for a device.
+    pub fn upgrade(site_id: impl Into<String>, device_id: impl Into<String>) -> Self {
+        Self::new(site_id, device_id, DeviceAction::Upgrade)
+    }
 }
 
 impl Endpoint for ExecuteDeviceAction {
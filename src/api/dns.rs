@@ -1,4 +1,4 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
 use crate::response::{DeleteResponse, MutationResponse, SiteResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -12,7 +12,7 @@ pub enum DnsAction {
 }
 
 /// DNS policy.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DnsPolicy {
     pub id: String,
@@ -20,7 +20,7 @@ pub struct DnsPolicy {
     pub action: DnsAction,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(default)]
     pub domains: Vec<String>,
@@ -33,14 +33,40 @@ pub struct DnsPolicy {
 #[derive(Debug, Clone)]
 pub struct GetDnsPolicies {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetDnsPolicies {
+    /// Create a new GetDnsPolicies endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetDnsPolicies endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetDnsPolicies {
@@ -51,6 +77,18 @@ impl Endpoint for GetDnsPolicies {
     fn build_path(&self) -> String {
         format!("sites/{}/dns/policies", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetDnsPolicies {
+    type Item = DnsPolicy;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Fetch a specific DNS policy by ID within a site.
@@ -1,3 +1,4 @@
+use crate::response::SiteResponse;
 use serde::Deserialize;
 use serde_json::Value;
 
@@ -34,3 +35,31 @@ pub trait Endpoint {
         Ok(None)
     }
 }
+
+/// An endpoint that lists a resource in offset/limit pages.
+///
+/// Implementors return a [`SiteResponse`] and can produce a copy of themselves
+/// scoped to a specific page, which lets [`PageStream`](crate::pagination::PageStream)
+/// and [`UnifiClient::fetch_all`](crate::UnifiClient::fetch_all) walk any list endpoint.
+pub trait Paginated: Endpoint<Response = SiteResponse<<Self as Paginated>::Item>> {
+    /// The type of item contained in each page.
+    type Item: for<'a> Deserialize<'a>;
+
+    /// Return a copy of this endpoint requesting the page at `offset` with `limit` items.
+    fn page(&self, offset: usize, limit: usize) -> Self;
+}
+
+/// Build `offset`/`limit` query parameters for paginated endpoints.
+pub(crate) fn pagination_params(
+    offset: Option<usize>,
+    limit: Option<usize>,
+) -> Vec<(&'static str, String)> {
+    let mut params = vec![];
+    if let Some(offset) = offset {
+        params.push(("offset", offset.to_string()));
+    }
+    if let Some(limit) = limit {
+        params.push(("limit", limit.to_string()));
+    }
+    params
+}
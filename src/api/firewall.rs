@@ -1,5 +1,10 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
-use crate::models::firewall::{FirewallAction, FirewallPolicy, FirewallZone};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::error::{Error, Result};
+use crate::models::firewall::{
+    AddressMatch, ConnectionState, FirewallAction, FirewallPolicy, FirewallZone, IpVersion,
+    PortMatch, Protocol,
+};
+use crate::models::wifi::ScheduleWindow;
 use crate::response::{DeleteResponse, MutationResponse, SiteResponse};
 use serde::Serialize;
 use serde_json::Value;
@@ -13,14 +18,40 @@ use serde_json::Value;
 #[derive(Debug, Clone)]
 pub struct GetFirewallZones {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetFirewallZones {
+    /// Create a new GetFirewallZones endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetFirewallZones endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetFirewallZones {
@@ -31,6 +62,18 @@ impl Endpoint for GetFirewallZones {
     fn build_path(&self) -> String {
         format!("sites/{}/firewall/zones", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetFirewallZones {
+    type Item = FirewallZone;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Fetch a specific firewall zone by ID within a site.
@@ -187,14 +230,40 @@ impl Endpoint for DeleteFirewallZone {
 #[derive(Debug, Clone)]
 pub struct GetFirewallPolicies {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetFirewallPolicies {
+    /// Create a new GetFirewallPolicies endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetFirewallPolicies endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetFirewallPolicies {
@@ -205,6 +274,18 @@ impl Endpoint for GetFirewallPolicies {
     fn build_path(&self) -> String {
         format!("sites/{}/firewall/policies", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetFirewallPolicies {
+    type Item = FirewallPolicy;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Fetch a specific firewall policy by ID within a site.
@@ -251,15 +332,23 @@ pub struct FirewallPolicyRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub protocol: Option<String>,
+    pub protocol: Option<Protocol>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_addresses: Option<Vec<AddressMatch>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_addresses: Option<Vec<AddressMatch>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_ports: Option<Vec<PortMatch>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_ports: Option<Vec<PortMatch>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_addresses: Option<Vec<String>>,
+    pub connection_states: Option<Vec<ConnectionState>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_addresses: Option<Vec<String>>,
+    pub ip_version: Option<IpVersion>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub source_ports: Option<Vec<String>>,
+    pub logging_enabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination_ports: Option<Vec<String>>,
+    pub schedule: Option<Vec<ScheduleWindow>>,
 }
 
 impl FirewallPolicyRequest {
@@ -277,6 +366,10 @@ impl FirewallPolicyRequest {
             destination_addresses: None,
             source_ports: None,
             destination_ports: None,
+            connection_states: None,
+            ip_version: None,
+            logging_enabled: None,
+            schedule: None,
         }
     }
 
@@ -305,30 +398,137 @@ impl FirewallPolicyRequest {
         self
     }
 
-    pub fn protocol(mut self, protocol: impl Into<String>) -> Self {
-        self.protocol = Some(protocol.into());
+    pub fn protocol(mut self, protocol: Protocol) -> Self {
+        self.protocol = Some(protocol);
         self
     }
 
-    pub fn source_addresses(mut self, addresses: Vec<String>) -> Self {
+    pub fn source_addresses(mut self, addresses: Vec<AddressMatch>) -> Self {
         self.source_addresses = Some(addresses);
         self
     }
 
-    pub fn destination_addresses(mut self, addresses: Vec<String>) -> Self {
+    pub fn destination_addresses(mut self, addresses: Vec<AddressMatch>) -> Self {
         self.destination_addresses = Some(addresses);
         self
     }
 
-    pub fn source_ports(mut self, ports: Vec<String>) -> Self {
+    pub fn source_ports(mut self, ports: Vec<PortMatch>) -> Self {
         self.source_ports = Some(ports);
         self
     }
 
-    pub fn destination_ports(mut self, ports: Vec<String>) -> Self {
+    pub fn destination_ports(mut self, ports: Vec<PortMatch>) -> Self {
         self.destination_ports = Some(ports);
         self
     }
+
+    /// Match only connections in the given states.
+    pub fn connection_states(mut self, states: Vec<ConnectionState>) -> Self {
+        self.connection_states = Some(states);
+        self
+    }
+
+    pub fn ip_version(mut self, version: IpVersion) -> Self {
+        self.ip_version = Some(version);
+        self
+    }
+
+    /// Set whether matching packets are logged.
+    pub fn logging(mut self, enabled: bool) -> Self {
+        self.logging_enabled = Some(enabled);
+        self
+    }
+
+    /// Add a time window during which the rule is active.
+    pub fn schedule_window(mut self, window: ScheduleWindow) -> Self {
+        self.schedule.get_or_insert_with(Vec::new).push(window);
+        self
+    }
+
+    /// Check the request for combinations the controller accepts but that
+    /// never match traffic.
+    ///
+    /// - Every value must be one this crate recognizes, not an `Unknown`
+    ///   kept from a controller response
+    /// - Ports require TCP or UDP
+    /// - Literal addresses must agree with the IP version
+    /// - ICMP only applies to IPv4 and ICMPv6 only to IPv6
+    pub fn validate(&self) -> Result<()> {
+        let unknown = |value: &dyn std::fmt::Display| {
+            Err(Error::InvalidFirewallRule(format!(
+                "Unrecognized value: {}",
+                value
+            )))
+        };
+        if let Some(protocol @ Protocol::Unknown(_)) = &self.protocol {
+            return unknown(protocol);
+        }
+        if let Some(version @ IpVersion::Unknown(_)) = &self.ip_version {
+            return unknown(version);
+        }
+        let addresses = [&self.source_addresses, &self.destination_addresses];
+        if let Some(address) = addresses
+            .into_iter()
+            .flatten()
+            .flatten()
+            .find(|a| matches!(a, AddressMatch::Unknown(_)))
+        {
+            return unknown(address);
+        }
+        let ports = [&self.source_ports, &self.destination_ports];
+        if let Some(port) = ports
+            .into_iter()
+            .flatten()
+            .flatten()
+            .find(|p| matches!(p, PortMatch::Unknown(_)))
+        {
+            return unknown(port);
+        }
+        if let Some(state) = self
+            .connection_states
+            .iter()
+            .flatten()
+            .find(|s| matches!(s, ConnectionState::Unknown(_)))
+        {
+            return unknown(state);
+        }
+
+        let protocol = self.protocol.clone().unwrap_or(Protocol::Any);
+        let has_ports = [&self.source_ports, &self.destination_ports]
+            .iter()
+            .any(|ports| ports.as_ref().is_some_and(|p| !p.is_empty()));
+        if has_ports && !protocol.has_ports() {
+            return Err(Error::InvalidFirewallRule(format!(
+                "Port matches require TCP or UDP, not {}",
+                protocol
+            )));
+        }
+
+        let version = self.ip_version.clone().unwrap_or(IpVersion::Both);
+        match (&protocol, &version) {
+            (Protocol::Icmp, IpVersion::Ipv6) | (Protocol::Icmpv6, IpVersion::Ipv4) => {
+                return Err(Error::InvalidFirewallRule(format!(
+                    "Protocol {} does not apply to {:?}",
+                    protocol, version
+                )));
+            }
+            _ => {}
+        }
+
+        let addresses = [&self.source_addresses, &self.destination_addresses];
+        for address in addresses.into_iter().flatten().flatten() {
+            if let Some(family) = address.ip_version() {
+                if !version.includes(&family) {
+                    return Err(Error::InvalidFirewallRule(format!(
+                        "Address {} does not match IP version {:?}",
+                        address, version
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Create a new firewall policy within a site.
@@ -464,3 +664,92 @@ impl Endpoint for ReorderFirewallPolicies {
         })?))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::firewall::FirewallActionType;
+    use serde_json::json;
+
+    fn block(name: &str) -> FirewallPolicyRequest {
+        FirewallPolicyRequest::new(
+            name,
+            FirewallAction {
+                action_type: FirewallActionType::Block,
+            },
+        )
+    }
+
+    #[test]
+    fn test_firewall_policy_request_body() {
+        let request = block("Block IoT SSH")
+            .protocol(Protocol::Tcp)
+            .source_addresses(vec!["10.0.20.0/24".parse().unwrap()])
+            .destination_addresses(vec![AddressMatch::Network("net-lan".to_string())])
+            .destination_ports(vec![PortMatch::Single(22), PortMatch::Range(8000, 8080)])
+            .connection_states(vec![ConnectionState::New])
+            .ip_version(IpVersion::Ipv4)
+            .logging(true);
+        assert!(request.validate().is_ok());
+
+        let endpoint = CreateFirewallPolicy::new("site-1", request);
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "name": "Block IoT SSH",
+                "action": {"type": "BLOCK"},
+                "protocol": "tcp",
+                "sourceAddresses": ["10.0.20.0/24"],
+                "destinationAddresses": ["network:net-lan"],
+                "destinationPorts": ["22", "8000-8080"],
+                "connectionStates": ["NEW"],
+                "ipVersion": "IPV4",
+                "loggingEnabled": true
+            }))
+        );
+    }
+
+    #[test]
+    fn test_firewall_policy_request_validate() {
+        let icmp_ports = block("ICMP")
+            .protocol(Protocol::Icmp)
+            .destination_ports(vec![PortMatch::Single(22)]);
+        assert!(icmp_ports.validate().is_err());
+        assert!(block("Any")
+            .destination_ports(vec![PortMatch::Single(22)])
+            .validate()
+            .is_err());
+
+        let icmpv6_on_v4 = block("ICMPv6")
+            .protocol(Protocol::Icmpv6)
+            .ip_version(IpVersion::Ipv4);
+        assert!(icmpv6_on_v4.validate().is_err());
+
+        let mixed = block("Mixed")
+            .ip_version(IpVersion::Ipv6)
+            .source_addresses(vec!["10.0.0.0/8".parse().unwrap()]);
+        assert!(mixed.validate().is_err());
+
+        let both = block("Both").source_addresses(vec![
+            "10.0.0.0/8".parse().unwrap(),
+            "fd00::/8".parse().unwrap(),
+        ]);
+        assert!(both.validate().is_ok());
+
+        let host = block("Host").source_addresses(vec!["10.0.0.5".parse().unwrap()]);
+        assert!(host.validate().is_ok());
+    }
+
+    #[test]
+    fn test_firewall_policy_request_rejects_unknown_values() {
+        let protocol = block("Protocol").protocol(Protocol::Unknown("sctp".into()));
+        assert!(protocol.validate().is_err());
+
+        let version = block("Version").ip_version(IpVersion::Unknown("IPV5".into()));
+        assert!(version.validate().is_err());
+
+        let address =
+            block("Address").destination_addresses(vec![AddressMatch::Unknown("geo:NL".into())]);
+        assert!(address.validate().is_err());
+    }
+}
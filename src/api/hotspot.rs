@@ -1,6 +1,8 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::models::units::{self, Bandwidth, DataSize, Minutes};
 use crate::models::voucher::Voucher;
 use crate::response::{DeleteResponse, MutationResponse, SiteResponse};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -51,14 +53,15 @@ impl Endpoint for GetVouchers {
     }
 
     fn query_params(&self) -> Vec<(&'static str, String)> {
-        let mut params = vec![];
-        if let Some(offset) = self.offset {
-            params.push(("offset", offset.to_string()));
-        }
-        if let Some(limit) = self.limit {
-            params.push(("limit", limit.to_string()));
-        }
-        params
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetVouchers {
+    type Item = Voucher;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
     }
 }
 
@@ -95,13 +98,31 @@ impl Endpoint for GetVoucher {
 pub struct GenerateVouchersRequest {
     pub count: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub duration_minutes: Option<u32>,
+    pub name: Option<String>,
+    #[serde(
+        rename = "authorizedGuestLimit",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub guest_limit: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub data_limit_mb: Option<u64>,
+    pub valid_until: Option<DateTime<Utc>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub bandwidth_limit_down: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub bandwidth_limit_up: Option<u64>,
+    pub duration_minutes: Option<Minutes>,
+    #[serde(
+        with = "units::serde::megabytes::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data_limit_mb: Option<DataSize>,
+    #[serde(
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bandwidth_limit_down: Option<Bandwidth>,
+    #[serde(
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bandwidth_limit_up: Option<Bandwidth>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
 }
@@ -111,6 +132,9 @@ impl GenerateVouchersRequest {
     pub fn new(count: u32) -> Self {
         Self {
             count,
+            name: None,
+            guest_limit: None,
+            valid_until: None,
             duration_minutes: None,
             data_limit_mb: None,
             bandwidth_limit_down: None,
@@ -119,27 +143,68 @@ impl GenerateVouchersRequest {
         }
     }
 
-    /// Set the duration in minutes for the vouchers.
-    pub fn duration_minutes(mut self, minutes: u32) -> Self {
-        self.duration_minutes = Some(minutes);
+    /// Set the name shown for the vouchers in the controller.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
         self
     }
 
-    /// Set the data limit in MB for the vouchers.
-    pub fn data_limit_mb(mut self, mb: u64) -> Self {
-        self.data_limit_mb = Some(mb);
+    /// Set how many guests may redeem each voucher.
+    ///
+    /// Vouchers without a limit can be redeemed by any number of guests.
+    pub fn guests_per_voucher(mut self, limit: u32) -> Self {
+        self.guest_limit = Some(limit);
+        self
+    }
+
+    /// Set the last moment the vouchers can be redeemed.
+    pub fn valid_until(mut self, valid_until: DateTime<Utc>) -> Self {
+        self.valid_until = Some(valid_until);
+        self
+    }
+
+    /// Set how long the vouchers grant access once redeemed.
+    pub fn duration(mut self, duration: Minutes) -> Self {
+        self.duration_minutes = Some(duration);
         self
     }
 
+    /// Set the data limit for the vouchers.
+    ///
+    /// The API takes whole megabytes; serializing a limit that is not a whole
+    /// number of megabytes fails.
+    pub fn data_limit(mut self, limit: DataSize) -> Self {
+        self.data_limit_mb = Some(limit);
+        self
+    }
+
+    /// Set the duration in minutes for the vouchers.
+    #[deprecated(note = "use `duration` with a `Minutes` value")]
+    pub fn duration_minutes(self, minutes: u32) -> Self {
+        self.duration(Minutes::new(minutes))
+    }
+
+    /// Set the data limit in MB for the vouchers.
+    #[deprecated(note = "use `data_limit` with a `DataSize` value")]
+    pub fn data_limit_mb(self, mb: u64) -> Self {
+        self.data_limit(DataSize::from_bytes(mb.saturating_mul(1_000_000)))
+    }
+
     /// Set the download bandwidth limit for the vouchers.
-    pub fn bandwidth_limit_down(mut self, kbps: u64) -> Self {
-        self.bandwidth_limit_down = Some(kbps);
+    ///
+    /// The API takes whole kbps; serializing a limit that is not a whole number
+    /// of kbps fails.
+    pub fn bandwidth_limit_down(mut self, limit: Bandwidth) -> Self {
+        self.bandwidth_limit_down = Some(limit);
         self
     }
 
     /// Set the upload bandwidth limit for the vouchers.
-    pub fn bandwidth_limit_up(mut self, kbps: u64) -> Self {
-        self.bandwidth_limit_up = Some(kbps);
+    ///
+    /// The API takes whole kbps; serializing a limit that is not a whole number
+    /// of kbps fails.
+    pub fn bandwidth_limit_up(mut self, limit: Bandwidth) -> Self {
+        self.bandwidth_limit_up = Some(limit);
         self
     }
 
@@ -151,7 +216,7 @@ impl GenerateVouchersRequest {
 }
 
 /// Response containing generated vouchers.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GeneratedVouchers {
     pub vouchers: Vec<Voucher>,
@@ -303,16 +368,22 @@ mod tests {
         assert!(body.get("bandwidthLimitDown").is_none());
         assert!(body.get("bandwidthLimitUp").is_none());
         assert!(body.get("note").is_none());
+        assert!(body.get("name").is_none());
+        assert!(body.get("authorizedGuestLimit").is_none());
+        assert!(body.get("validUntil").is_none());
     }
 
     #[test]
     fn test_generate_vouchers_request_body_full() {
         let request = GenerateVouchersRequest::new(10)
-            .duration_minutes(60)
-            .data_limit_mb(1024)
-            .bandwidth_limit_down(10000)
-            .bandwidth_limit_up(5000)
-            .note("Test vouchers");
+            .duration(Minutes::new(60))
+            .data_limit("1024MB".parse().unwrap())
+            .bandwidth_limit_down("10Mbps".parse().unwrap())
+            .bandwidth_limit_up(Bandwidth::from_kbps(5000).unwrap())
+            .note("Test vouchers")
+            .name("Front desk")
+            .guests_per_voucher(4)
+            .valid_until("2024-01-08T00:00:00Z".parse().unwrap());
         let endpoint = GenerateVouchers::new("site-123", request);
         let body = endpoint.request_body().unwrap().unwrap();
 
@@ -322,6 +393,18 @@ mod tests {
         assert_eq!(body["bandwidthLimitDown"], 10000);
         assert_eq!(body["bandwidthLimitUp"], 5000);
         assert_eq!(body["note"], "Test vouchers");
+        assert_eq!(body["name"], "Front desk");
+        assert_eq!(body["authorizedGuestLimit"], 4);
+        assert_eq!(body["validUntil"], "2024-01-08T00:00:00Z");
+    }
+
+    #[test]
+    fn test_generate_vouchers_rejects_fractional_kbps() {
+        let request =
+            GenerateVouchersRequest::new(1).bandwidth_limit_down(Bandwidth::from_bps(1500));
+        let endpoint = GenerateVouchers::new("site-123", request);
+
+        assert!(endpoint.request_body().is_err());
     }
 
     #[test]
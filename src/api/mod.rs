@@ -11,4 +11,4 @@ pub mod sites;
 pub mod traffic;
 pub mod wifi;
 
-pub use endpoint::Endpoint;
+pub use endpoint::{Endpoint, Paginated};
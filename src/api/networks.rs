@@ -1,10 +1,14 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::error::{Error, Result};
 use crate::response::{DeleteResponse, MutationResponse, SiteResponse};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
 
 /// Network management type.
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Management {
     Unmanaged,
@@ -13,7 +17,7 @@ pub enum Management {
 }
 
 /// Network origin type.
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Origin {
     UserDefined,
@@ -22,25 +26,338 @@ pub enum Origin {
 }
 
 /// Network metadata.
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct NetworkMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub origin: Option<Origin>,
 }
 
+/// IPv4 addressing of a network: the gateway address, the subnet and DHCP.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Ipv4Config {
+    /// Gateway address, which also determines the subnet.
+    ///
+    /// `0.0.0.0` when a response leaves it out.
+    #[serde(
+        rename = "hostIpAddress",
+        default = "unspecified_gateway",
+        skip_serializing_if = "Ipv4Addr::is_unspecified"
+    )]
+    pub gateway: Ipv4Addr,
+    /// Prefix length of the subnet. `0` when a response leaves it out.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub prefix_length: u8,
+    #[serde(
+        default,
+        rename = "dhcpConfiguration",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub dhcp: Option<DhcpServer>,
+}
+
+fn unspecified_gateway() -> Ipv4Addr {
+    Ipv4Addr::UNSPECIFIED
+}
+
+fn is_zero(value: &u8) -> bool {
+    *value == 0
+}
+
+impl Ipv4Config {
+    /// Create an IPv4 configuration from a gateway address and prefix length.
+    ///
+    /// Returns [`Error::InvalidNetworkConfig`] if the prefix is longer than 30
+    /// bits or the gateway is the subnet's network or broadcast address.
+    pub fn new(gateway: Ipv4Addr, prefix_length: u8) -> Result<Self> {
+        let config = Self {
+            gateway,
+            prefix_length,
+            dhcp: None,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Set the DHCP server configuration.
+    pub fn dhcp(mut self, dhcp: DhcpServer) -> Self {
+        self.dhcp = Some(dhcp);
+        self
+    }
+
+    /// Get the subnet mask.
+    ///
+    /// A prefix length above 32, which [`validate`](Self::validate) rejects,
+    /// gives a host mask.
+    pub fn netmask(&self) -> Ipv4Addr {
+        let bits = match 32u32.checked_sub(u32::from(self.prefix_length)) {
+            Some(host_bits) => u32::MAX.checked_shl(host_bits).unwrap_or(0),
+            None => u32::MAX,
+        };
+        Ipv4Addr::from(bits)
+    }
+
+    /// Get the first address of the subnet.
+    pub fn network_address(&self) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.gateway) & u32::from(self.netmask()))
+    }
+
+    /// Get the last address of the subnet.
+    pub fn broadcast_address(&self) -> Ipv4Addr {
+        Ipv4Addr::from(u32::from(self.gateway) | !u32::from(self.netmask()))
+    }
+
+    /// Check if an address is inside the subnet.
+    pub fn contains(&self, address: Ipv4Addr) -> bool {
+        u32::from(address) & u32::from(self.netmask()) == u32::from(self.network_address())
+    }
+
+    /// Check that the subnet and DHCP range are usable.
+    ///
+    /// The prefix must be at most 30 bits and the gateway must be a host
+    /// address of the subnet. The DHCP range must lie within the subnet's host
+    /// addresses, start before it ends and not include the gateway.
+    pub fn validate(&self) -> Result<()> {
+        if self.prefix_length > 30 {
+            return Err(Error::InvalidNetworkConfig(format!(
+                "Prefix length /{} leaves no room for hosts",
+                self.prefix_length
+            )));
+        }
+        if self.gateway == self.network_address() || self.gateway == self.broadcast_address() {
+            return Err(Error::InvalidNetworkConfig(format!(
+                "Gateway {} is not a host address in {}",
+                self.gateway, self
+            )));
+        }
+
+        let Some(range) = self.dhcp.as_ref().and_then(|dhcp| dhcp.range.as_ref()) else {
+            return Ok(());
+        };
+        let is_host = |address: Ipv4Addr| {
+            self.contains(address)
+                && address != self.network_address()
+                && address != self.broadcast_address()
+        };
+
+        if !is_host(range.start) || !is_host(range.stop) {
+            return Err(Error::InvalidNetworkConfig(format!(
+                "DHCP range {} is outside {}",
+                range, self
+            )));
+        }
+        if range.start > range.stop {
+            return Err(Error::InvalidNetworkConfig(format!(
+                "DHCP range {} ends before it starts",
+                range
+            )));
+        }
+        if range.contains(self.gateway) {
+            return Err(Error::InvalidNetworkConfig(format!(
+                "DHCP range {} includes the gateway {}",
+                range, self.gateway
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Ipv4Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.gateway, self.prefix_length)
+    }
+}
+
+/// Parse a gateway address in CIDR notation (e.g. "192.168.10.1/24").
+impl FromStr for Ipv4Config {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::InvalidNetworkConfig(format!("Invalid subnet: {}", s));
+        let (address, prefix) = s.trim().split_once('/').ok_or_else(invalid)?;
+        let gateway = address.parse().map_err(|_| invalid())?;
+        let prefix_length = prefix.parse().map_err(|_| invalid())?;
+        Self::new(gateway, prefix_length)
+    }
+}
+
+/// DHCP server settings of a network.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DhcpServer {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(
+        default,
+        rename = "ipAddressRange",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub range: Option<DhcpRange>,
+    /// Lease time in seconds.
+    #[serde(
+        default,
+        rename = "leaseTimeSeconds",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lease_time: Option<u32>,
+    /// DNS servers handed out to clients. Empty means the gateway.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dns_servers: Vec<Ipv4Addr>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<DhcpOption>,
+}
+
+impl DhcpServer {
+    /// Create an enabled DHCP server handing out `start..=stop`.
+    pub fn new(start: Ipv4Addr, stop: Ipv4Addr) -> Self {
+        Self {
+            enabled: true,
+            range: Some(DhcpRange { start, stop }),
+            ..Default::default()
+        }
+    }
+
+    /// Create a disabled DHCP server.
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    /// Set the lease time in seconds.
+    pub fn lease_time(mut self, seconds: u32) -> Self {
+        self.lease_time = Some(seconds);
+        self
+    }
+
+    /// Set the DNS servers handed out to clients.
+    pub fn dns_servers(mut self, servers: impl IntoIterator<Item = Ipv4Addr>) -> Self {
+        self.dns_servers = servers.into_iter().collect();
+        self
+    }
+
+    /// Add a DHCP option.
+    pub fn option(mut self, option: DhcpOption) -> Self {
+        self.options.push(option);
+        self
+    }
+}
+
+/// Range of addresses handed out by a DHCP server (inclusive).
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+pub struct DhcpRange {
+    pub start: Ipv4Addr,
+    pub stop: Ipv4Addr,
+}
+
+impl DhcpRange {
+    /// Check if an address is inside the range.
+    pub fn contains(&self, address: Ipv4Addr) -> bool {
+        (self.start..=self.stop).contains(&address)
+    }
+
+    /// Get the number of addresses in the range.
+    pub fn size(&self) -> u32 {
+        (u32::from(self.stop) + 1).saturating_sub(u32::from(self.start))
+    }
+}
+
+impl fmt::Display for DhcpRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.stop)
+    }
+}
+
+/// Additional DHCP option handed out to clients.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DhcpOption {
+    /// Option 42.
+    NtpServers { servers: Vec<Ipv4Addr> },
+    /// Option 66.
+    TftpServer { server: String },
+    /// Option 67.
+    BootFile { file: String },
+    /// Option 44.
+    WinsServers { servers: Vec<Ipv4Addr> },
+    /// Any other option by code.
+    Custom { code: u8, value: String },
+    /// An option type this crate does not know, kept as sent.
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
+/// IPv6 addressing of a network.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Ipv6Mode {
+    /// Delegate a prefix from a WAN's DHCPv6-PD lease.
+    #[serde(rename_all = "camelCase")]
+    PrefixDelegation {
+        /// WAN to delegate from, or `None` for the primary WAN.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        wan_network_id: Option<String>,
+    },
+    /// Statically assigned gateway address and prefix.
+    #[serde(rename_all = "camelCase")]
+    Static {
+        gateway: Ipv6Addr,
+        prefix_length: u8,
+    },
+    /// Clients configure themselves from router advertisements.
+    Slaac,
+    /// A mode this crate does not know (e.g. `NONE`), kept as sent.
+    #[serde(untagged)]
+    Unknown(Value),
+}
+
 /// Network resource from the UniFi API.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Network {
     pub id: String,
     pub name: String,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vlan_id: Option<i64>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub management: Option<Management>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub metadata: Option<NetworkMetadata>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv4_configuration: Option<Ipv4Config>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ipv6_configuration: Option<Ipv6Mode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+    /// Whether clients on this network are isolated from other networks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isolation_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub igmp_snooping_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub internet_access_enabled: Option<bool>,
+}
+
+impl Network {
+    /// Get the DHCP server settings, if the network has IPv4 configured.
+    pub fn dhcp(&self) -> Option<&DhcpServer> {
+        self.ipv4_configuration.as_ref()?.dhcp.as_ref()
+    }
+
+    /// Check if the gateway runs a DHCP server on this network.
+    pub fn is_dhcp_enabled(&self) -> bool {
+        self.dhcp().is_some_and(|dhcp| dhcp.enabled)
+    }
+
+    /// Check if clients on this network are isolated from other networks.
+    pub fn is_isolated(&self) -> bool {
+        self.isolation_enabled.unwrap_or(false)
+    }
+
+    /// Check if clients on this network can reach the internet.
+    pub fn has_internet_access(&self) -> bool {
+        self.internet_access_enabled.unwrap_or(true)
+    }
 }
 
 /// Fetch all networks for a specific site.
@@ -48,14 +365,40 @@ pub struct Network {
 #[derive(Debug, Clone)]
 pub struct GetNetworks {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetNetworks {
+    /// Create a new GetNetworks endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetNetworks endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetNetworks {
@@ -66,6 +409,18 @@ impl Endpoint for GetNetworks {
     fn build_path(&self) -> String {
         format!("sites/{}/networks", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetNetworks {
+    type Item = Network;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Fetch a specific network by ID within a site.
@@ -104,6 +459,18 @@ pub struct NetworkRequest {
     pub vlan_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipv4_configuration: Option<Ipv4Config>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ipv6_configuration: Option<Ipv6Mode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolation_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub igmp_snooping_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub internet_access_enabled: Option<bool>,
 }
 
 impl NetworkRequest {
@@ -113,6 +480,12 @@ impl NetworkRequest {
             name: name.into(),
             vlan_id: None,
             enabled: None,
+            ipv4_configuration: None,
+            ipv6_configuration: None,
+            domain_name: None,
+            isolation_enabled: None,
+            igmp_snooping_enabled: None,
+            internet_access_enabled: None,
         }
     }
 
@@ -127,6 +500,60 @@ impl NetworkRequest {
         self.enabled = Some(enabled);
         self
     }
+
+    /// Set the gateway address, subnet and DHCP server.
+    pub fn ipv4(mut self, config: Ipv4Config) -> Self {
+        self.ipv4_configuration = Some(config);
+        self
+    }
+
+    /// Set the IPv6 mode.
+    pub fn ipv6(mut self, mode: Ipv6Mode) -> Self {
+        self.ipv6_configuration = Some(mode);
+        self
+    }
+
+    /// Set the domain name handed out to DHCP clients.
+    pub fn domain_name(mut self, domain_name: impl Into<String>) -> Self {
+        self.domain_name = Some(domain_name.into());
+        self
+    }
+
+    /// Set whether clients are isolated from other networks.
+    pub fn isolated(mut self, isolated: bool) -> Self {
+        self.isolation_enabled = Some(isolated);
+        self
+    }
+
+    /// Set whether IGMP snooping is enabled.
+    pub fn igmp_snooping(mut self, enabled: bool) -> Self {
+        self.igmp_snooping_enabled = Some(enabled);
+        self
+    }
+
+    /// Set whether clients can reach the internet.
+    pub fn internet_access(mut self, enabled: bool) -> Self {
+        self.internet_access_enabled = Some(enabled);
+        self
+    }
+
+    /// Check the request for inconsistent settings before sending it.
+    ///
+    /// See [`Ipv4Config::validate`].
+    pub fn validate(&self) -> Result<()> {
+        if let Some(vlan_id) = self.vlan_id {
+            if !(1..=4094).contains(&vlan_id) {
+                return Err(Error::InvalidNetworkConfig(format!(
+                    "VLAN ID {} is outside 1-4094",
+                    vlan_id
+                )));
+            }
+        }
+        match &self.ipv4_configuration {
+            Some(config) => config.validate(),
+            None => Ok(()),
+        }
+    }
 }
 
 /// Create a new network within a site.
@@ -219,3 +646,180 @@ impl Endpoint for DeleteNetwork {
         format!("sites/{}/networks/{}", self.site_id, self.id)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_ipv4_config_from_cidr() {
+        let config: Ipv4Config = "192.168.10.1/24".parse().unwrap();
+        assert_eq!(config.netmask(), Ipv4Addr::new(255, 255, 255, 0));
+        assert_eq!(config.network_address(), Ipv4Addr::new(192, 168, 10, 0));
+        assert_eq!(config.broadcast_address(), Ipv4Addr::new(192, 168, 10, 255));
+        assert!(config.contains(Ipv4Addr::new(192, 168, 10, 77)));
+        assert!(!config.contains(Ipv4Addr::new(192, 168, 11, 1)));
+        assert_eq!(config.to_string(), "192.168.10.1/24");
+
+        assert!("192.168.10.0/24".parse::<Ipv4Config>().is_err());
+        assert!("192.168.10.1/31".parse::<Ipv4Config>().is_err());
+        assert!("192.168.10.1".parse::<Ipv4Config>().is_err());
+    }
+
+    #[test]
+    fn test_ipv4_config_out_of_range_prefix() {
+        // Built directly, as a controller response would be
+        let config = Ipv4Config {
+            gateway: Ipv4Addr::new(10, 0, 0, 1),
+            prefix_length: 40,
+            dhcp: None,
+        };
+        assert_eq!(config.netmask(), Ipv4Addr::new(255, 255, 255, 255));
+        assert!(config.validate().is_err());
+        assert!(NetworkRequest::new("LAN").ipv4(config).validate().is_err());
+
+        let network_gateway = Ipv4Config {
+            gateway: Ipv4Addr::new(10, 0, 0, 0),
+            prefix_length: 24,
+            dhcp: None,
+        };
+        assert!(NetworkRequest::new("LAN")
+            .ipv4(network_gateway)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_network_request_validate() {
+        let subnet: Ipv4Config = "10.0.20.1/24".parse().unwrap();
+        let with_range = |start: [u8; 4], stop: [u8; 4]| {
+            NetworkRequest::new("IoT").ipv4(
+                subnet
+                    .clone()
+                    .dhcp(DhcpServer::new(start.into(), stop.into())),
+            )
+        };
+
+        assert!(with_range([10, 0, 20, 100], [10, 0, 20, 200])
+            .validate()
+            .is_ok());
+        assert!(with_range([10, 0, 20, 1], [10, 0, 20, 200])
+            .validate()
+            .is_err());
+        assert!(with_range([10, 0, 20, 100], [10, 0, 21, 10])
+            .validate()
+            .is_err());
+        assert!(with_range([10, 0, 20, 200], [10, 0, 20, 100])
+            .validate()
+            .is_err());
+        assert!(NetworkRequest::new("IoT").vlan_id(4095).validate().is_err());
+    }
+
+    #[test]
+    fn test_network_request_body() {
+        let request = NetworkRequest::new("IoT")
+            .vlan_id(20)
+            .ipv4(
+                "10.0.20.1/24".parse::<Ipv4Config>().unwrap().dhcp(
+                    DhcpServer::new([10, 0, 20, 100].into(), [10, 0, 20, 200].into())
+                        .lease_time(86400)
+                        .dns_servers([Ipv4Addr::new(1, 1, 1, 1)])
+                        .option(DhcpOption::TftpServer {
+                            server: "10.0.0.5".to_string(),
+                        }),
+                ),
+            )
+            .ipv6(Ipv6Mode::PrefixDelegation {
+                wan_network_id: None,
+            })
+            .domain_name("iot.lan")
+            .isolated(true)
+            .internet_access(false);
+
+        let endpoint = CreateNetwork::new("site-1", request);
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "name": "IoT",
+                "vlanId": 20,
+                "ipv4Configuration": {
+                    "hostIpAddress": "10.0.20.1",
+                    "prefixLength": 24,
+                    "dhcpConfiguration": {
+                        "enabled": true,
+                        "ipAddressRange": {"start": "10.0.20.100", "stop": "10.0.20.200"},
+                        "leaseTimeSeconds": 86400,
+                        "dnsServers": ["1.1.1.1"],
+                        "options": [{"type": "TFTP_SERVER", "server": "10.0.0.5"}]
+                    }
+                },
+                "ipv6Configuration": {"type": "PREFIX_DELEGATION"},
+                "domainName": "iot.lan",
+                "isolationEnabled": true,
+                "internetAccessEnabled": false
+            }))
+        );
+    }
+
+    #[test]
+    fn test_network_helpers() {
+        let network: Network = serde_json::from_value(json!({
+            "id": "net-1",
+            "name": "Guests",
+            "enabled": true,
+            "ipv4Configuration": {
+                "hostIpAddress": "10.0.30.1",
+                "prefixLength": 24,
+                "dhcpConfiguration": {"enabled": true}
+            },
+            "ipv6Configuration": {"type": "STATIC", "gateway": "fd00:30::1", "prefixLength": 64},
+            "isolationEnabled": true
+        }))
+        .unwrap();
+
+        assert!(network.is_dhcp_enabled());
+        assert!(network.is_isolated());
+        assert!(network.has_internet_access());
+        assert!(matches!(
+            network.ipv6_configuration,
+            Some(Ipv6Mode::Static {
+                prefix_length: 64,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_network_keeps_unknown_settings() {
+        let fixture = json!({
+            "id": "net-2",
+            "name": "Lab",
+            "enabled": true,
+            "ipv4Configuration": {
+                "dhcpConfiguration": {
+                    "enabled": true,
+                    "options": [
+                        {"type": "NTP_SERVERS", "servers": ["10.0.0.1"]},
+                        {"type": "DOMAIN_SEARCH", "domains": ["lab.lan"]}
+                    ]
+                }
+            },
+            "ipv6Configuration": {"type": "NONE"}
+        });
+        let network: Network = serde_json::from_value(fixture.clone()).unwrap();
+
+        let config = network.ipv4_configuration.as_ref().unwrap();
+        assert!(config.gateway.is_unspecified());
+        assert!(config.validate().is_err());
+        assert!(matches!(
+            network.dhcp().unwrap().options[1],
+            DhcpOption::Unknown(_)
+        ));
+        assert_eq!(
+            network.ipv6_configuration,
+            Some(Ipv6Mode::Unknown(json!({"type": "NONE"})))
+        );
+        assert_eq!(serde_json::to_value(&network).unwrap(), fixture);
+    }
+}
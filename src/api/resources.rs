@@ -1,25 +1,26 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::models::IpAddress;
 use crate::response::SiteResponse;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 // ============================================================================
 // WAN Resources
 // ============================================================================
 
 /// WAN interface configuration.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Wan {
     pub id: String,
     pub name: String,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub interface_type: Option<String>,
-    #[serde(default)]
-    pub ip_address: Option<String>,
-    #[serde(default)]
-    pub gateway: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<IpAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gateway: Option<IpAddress>,
 }
 
 /// Fetch all WAN interfaces for a specific site.
@@ -27,14 +28,40 @@ pub struct Wan {
 #[derive(Debug, Clone)]
 pub struct GetWans {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetWans {
+    /// Create a new GetWans endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Create a new GetWans endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
         }
     }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetWans {
@@ -45,6 +72,18 @@ impl Endpoint for GetWans {
     fn build_path(&self) -> String {
         format!("sites/{}/wans", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetWans {
+    type Item = Wan;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 // ============================================================================
@@ -52,7 +91,7 @@ impl Endpoint for GetWans {
 // ============================================================================
 
 /// VPN connection status.
-#[derive(Debug, Clone, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum VpnStatus {
     Connected,
@@ -64,17 +103,17 @@ pub enum VpnStatus {
 }
 
 /// VPN server configuration.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct VpnServer {
     pub id: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vpn_type: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<VpnStatus>,
 }
 
@@ -83,14 +122,40 @@ pub struct VpnServer {
 #[derive(Debug, Clone)]
 pub struct GetVpnServers {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetVpnServers {
+    /// Create a new GetVpnServers endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetVpnServers endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetVpnServers {
@@ -101,20 +166,32 @@ impl Endpoint for GetVpnServers {
     fn build_path(&self) -> String {
         format!("sites/{}/vpn/servers", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetVpnServers {
+    type Item = VpnServer;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Site-to-site VPN tunnel.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct VpnTunnel {
     pub id: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub status: Option<VpnStatus>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub remote_host: Option<String>,
 }
 
@@ -123,14 +200,40 @@ pub struct VpnTunnel {
 #[derive(Debug, Clone)]
 pub struct GetVpnTunnels {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetVpnTunnels {
+    /// Create a new GetVpnTunnels endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetVpnTunnels endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetVpnTunnels {
@@ -141,6 +244,18 @@ impl Endpoint for GetVpnTunnels {
     fn build_path(&self) -> String {
         format!("sites/{}/vpn/site-to-site-tunnels", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetVpnTunnels {
+    type Item = VpnTunnel;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 // ============================================================================
@@ -148,17 +263,17 @@ impl Endpoint for GetVpnTunnels {
 // ============================================================================
 
 /// RADIUS profile configuration.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct RadiusProfile {
     pub id: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
-    pub ip_address: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<IpAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub port: Option<u16>,
 }
 
@@ -167,14 +282,40 @@ pub struct RadiusProfile {
 #[derive(Debug, Clone)]
 pub struct GetRadiusProfiles {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetRadiusProfiles {
+    /// Create a new GetRadiusProfiles endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetRadiusProfiles endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetRadiusProfiles {
@@ -185,6 +326,18 @@ impl Endpoint for GetRadiusProfiles {
     fn build_path(&self) -> String {
         format!("sites/{}/radius/profiles", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetRadiusProfiles {
+    type Item = RadiusProfile;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 // ============================================================================
@@ -192,12 +345,12 @@ impl Endpoint for GetRadiusProfiles {
 // ============================================================================
 
 /// Device tag for grouping devices.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DeviceTag {
     pub id: String,
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
     #[serde(default)]
     pub device_ids: Vec<String>,
@@ -208,14 +361,40 @@ pub struct DeviceTag {
 #[derive(Debug, Clone)]
 pub struct GetDeviceTags {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetDeviceTags {
+    /// Create a new GetDeviceTags endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetDeviceTags endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetDeviceTags {
@@ -226,6 +405,18 @@ impl Endpoint for GetDeviceTags {
     fn build_path(&self) -> String {
         format!("sites/{}/device-tags", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetDeviceTags {
+    type Item = DeviceTag;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 // ============================================================================
@@ -233,12 +424,12 @@ impl Endpoint for GetDeviceTags {
 // ============================================================================
 
 /// DPI application category.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DpiCategory {
     pub id: String,
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(default)]
     pub applications: Vec<String>,
@@ -247,35 +438,119 @@ pub struct DpiCategory {
 /// Fetch all DPI categories.
 /// Endpoint: GET /v1/dpi/categories
 #[derive(Debug, Clone, Default)]
-pub struct GetDpiCategories;
+pub struct GetDpiCategories {
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl GetDpiCategories {
+    /// Create a new GetDpiCategories endpoint without pagination.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new GetDpiCategories endpoint with pagination parameters.
+    pub fn with_pagination(offset: usize, limit: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
 
 impl Endpoint for GetDpiCategories {
     const PATH: &'static str = "dpi/categories";
     const METHOD: HttpMethod = HttpMethod::Get;
     type Response = SiteResponse<DpiCategory>;
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetDpiCategories {
+    type Item = DpiCategory;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(offset, limit)
+    }
 }
 
 /// DPI application.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DpiApplication {
     pub id: String,
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub category_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
 }
 
 /// Fetch all DPI applications.
 /// Endpoint: GET /v1/dpi/applications
 #[derive(Debug, Clone, Default)]
-pub struct GetDpiApplications;
+pub struct GetDpiApplications {
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl GetDpiApplications {
+    /// Create a new GetDpiApplications endpoint without pagination.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new GetDpiApplications endpoint with pagination parameters.
+    pub fn with_pagination(offset: usize, limit: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
 
 impl Endpoint for GetDpiApplications {
     const PATH: &'static str = "dpi/applications";
     const METHOD: HttpMethod = HttpMethod::Get;
     type Response = SiteResponse<DpiApplication>;
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetDpiApplications {
+    type Item = DpiApplication;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(offset, limit)
+    }
 }
 
 // ============================================================================
@@ -283,7 +558,7 @@ impl Endpoint for GetDpiApplications {
 // ============================================================================
 
 /// Country reference data.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Country {
     pub code: String,
@@ -293,10 +568,52 @@ pub struct Country {
 /// Fetch all available countries.
 /// Endpoint: GET /v1/countries
 #[derive(Debug, Clone, Default)]
-pub struct GetCountries;
+pub struct GetCountries {
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl GetCountries {
+    /// Create a new GetCountries endpoint without pagination.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new GetCountries endpoint with pagination parameters.
+    pub fn with_pagination(offset: usize, limit: usize) -> Self {
+        Self {
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
 
 impl Endpoint for GetCountries {
     const PATH: &'static str = "countries";
     const METHOD: HttpMethod = HttpMethod::Get;
     type Response = SiteResponse<Country>;
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetCountries {
+    type Item = Country;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(offset, limit)
+    }
 }
@@ -1,5 +1,10 @@
-use crate::api::endpoint::{Endpoint, HttpMethod};
-use crate::models::wifi::{WifiBroadcast, WifiSecurity};
+use crate::api::endpoint::{pagination_params, Endpoint, HttpMethod, Paginated};
+use crate::error::{Error, Result};
+use crate::models::catalog::RadioBand;
+use crate::models::wifi::{
+    ApTargeting, MinimumDataRates, Passphrase, PmfMode, Ppsk, ScheduleWindow, WifiBroadcast,
+    WifiSecurity,
+};
 use crate::response::{DeleteResponse, MutationResponse, SiteResponse};
 use serde::Serialize;
 use serde_json::Value;
@@ -9,14 +14,40 @@ use serde_json::Value;
 #[derive(Debug, Clone)]
 pub struct GetWifiBroadcasts {
     pub site_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
 }
 
 impl GetWifiBroadcasts {
+    /// Create a new GetWifiBroadcasts endpoint without pagination.
     pub fn new(site_id: impl Into<String>) -> Self {
         Self {
             site_id: site_id.into(),
+            offset: None,
+            limit: None,
         }
     }
+
+    /// Create a new GetWifiBroadcasts endpoint with pagination parameters.
+    pub fn with_pagination(site_id: impl Into<String>, offset: usize, limit: usize) -> Self {
+        Self {
+            site_id: site_id.into(),
+            offset: Some(offset),
+            limit: Some(limit),
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
 }
 
 impl Endpoint for GetWifiBroadcasts {
@@ -27,6 +58,18 @@ impl Endpoint for GetWifiBroadcasts {
     fn build_path(&self) -> String {
         format!("sites/{}/wifi/broadcasts", self.site_id)
     }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetWifiBroadcasts {
+    type Item = WifiBroadcast;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::with_pagination(self.site_id.clone(), offset, limit)
+    }
 }
 
 /// Fetch a specific WiFi broadcast by ID within a site.
@@ -67,11 +110,39 @@ pub struct WifiBroadcastRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub security: Option<WifiSecurity>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub passphrase: Option<String>,
+    pub passphrase: Option<Passphrase>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vlan_id: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub hide_ssid: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pmf_mode: Option<PmfMode>,
+    #[serde(
+        rename = "broadcastingFrequenciesGHz",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub bands: Vec<RadioBand>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_data_rates: Option<MinimumDataRates>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub band_steering_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fast_roaming_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bss_transition_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_isolation_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicast_to_unicast_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub radius_profile_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ScheduleWindow>,
+    #[serde(
+        rename = "broadcastingDeviceFilter",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ap_targeting: Option<ApTargeting>,
 }
 
 impl WifiBroadcastRequest {
@@ -85,6 +156,17 @@ impl WifiBroadcastRequest {
             passphrase: None,
             vlan_id: None,
             hide_ssid: None,
+            pmf_mode: None,
+            bands: Vec::new(),
+            minimum_data_rates: None,
+            band_steering_enabled: None,
+            fast_roaming_enabled: None,
+            bss_transition_enabled: None,
+            client_isolation_enabled: None,
+            multicast_to_unicast_enabled: None,
+            radius_profile_id: None,
+            schedule: Vec::new(),
+            ap_targeting: None,
         }
     }
 
@@ -102,7 +184,7 @@ impl WifiBroadcastRequest {
 
     /// Set the WPA passphrase.
     pub fn passphrase(mut self, passphrase: impl Into<String>) -> Self {
-        self.passphrase = Some(passphrase.into());
+        self.passphrase = Some(Passphrase::new(passphrase));
         self
     }
 
@@ -117,6 +199,124 @@ impl WifiBroadcastRequest {
         self.hide_ssid = Some(hide_ssid);
         self
     }
+
+    /// Set the Protected Management Frames mode.
+    pub fn pmf_mode(mut self, mode: PmfMode) -> Self {
+        self.pmf_mode = Some(mode);
+        self
+    }
+
+    /// Broadcast only on the given bands.
+    pub fn bands(mut self, bands: impl IntoIterator<Item = RadioBand>) -> Self {
+        self.bands = bands.into_iter().collect();
+        self
+    }
+
+    /// Set the minimum data rates.
+    pub fn minimum_data_rates(mut self, rates: MinimumDataRates) -> Self {
+        self.minimum_data_rates = Some(rates);
+        self
+    }
+
+    /// Set whether dual-band clients are steered to 5 GHz.
+    pub fn band_steering(mut self, enabled: bool) -> Self {
+        self.band_steering_enabled = Some(enabled);
+        self
+    }
+
+    /// Set whether 802.11r fast roaming is enabled.
+    pub fn fast_roaming(mut self, enabled: bool) -> Self {
+        self.fast_roaming_enabled = Some(enabled);
+        self
+    }
+
+    /// Set whether 802.11v BSS transition management is enabled.
+    pub fn bss_transition(mut self, enabled: bool) -> Self {
+        self.bss_transition_enabled = Some(enabled);
+        self
+    }
+
+    /// Set whether clients are isolated from each other.
+    pub fn client_isolation(mut self, enabled: bool) -> Self {
+        self.client_isolation_enabled = Some(enabled);
+        self
+    }
+
+    /// Set whether multicast traffic is converted to unicast.
+    pub fn multicast_to_unicast(mut self, enabled: bool) -> Self {
+        self.multicast_to_unicast_enabled = Some(enabled);
+        self
+    }
+
+    /// Set the RADIUS profile used by enterprise security.
+    pub fn radius_profile(mut self, radius_profile_id: impl Into<String>) -> Self {
+        self.radius_profile_id = Some(radius_profile_id.into());
+        self
+    }
+
+    /// Add a broadcast schedule window. Without windows the network is always on.
+    pub fn schedule_window(mut self, window: ScheduleWindow) -> Self {
+        self.schedule.push(window);
+        self
+    }
+
+    /// Set which access points broadcast the network.
+    pub fn ap_targeting(mut self, targeting: ApTargeting) -> Self {
+        self.ap_targeting = Some(targeting);
+        self
+    }
+
+    /// Check the request for settings the controller would reject.
+    ///
+    /// - WPA2 and WPA3 personal security need an 8-63 character printable
+    ///   ASCII passphrase
+    /// - Enterprise security needs a RADIUS profile
+    /// - WPA3 and 6 GHz need PMF required
+    /// - Open networks cannot use PMF
+    ///
+    /// An unset PMF mode leaves the choice to the controller and is accepted.
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |message: &str| Err(Error::InvalidWifiConfig(message.to_string()));
+        let security = self.security.as_ref().unwrap_or(&WifiSecurity::Open);
+
+        match security {
+            WifiSecurity::Wpa2 | WifiSecurity::Wpa3 | WifiSecurity::Wpa2Wpa3
+                if !self
+                    .passphrase
+                    .as_ref()
+                    .is_some_and(Passphrase::is_valid_length) =>
+            {
+                return invalid("Passphrase must be 8-63 printable ASCII characters");
+            }
+            WifiSecurity::WpaEnterprise if self.radius_profile_id.is_none() => {
+                return invalid("Enterprise security requires a RADIUS profile");
+            }
+            _ => {}
+        }
+
+        match (security, self.pmf_mode) {
+            (WifiSecurity::Open, Some(PmfMode::Optional | PmfMode::Required)) => {
+                return invalid("Open networks cannot use PMF");
+            }
+            (WifiSecurity::Wpa3, Some(PmfMode::Disabled | PmfMode::Optional)) => {
+                return invalid("WPA3 requires PMF to be required");
+            }
+            (WifiSecurity::Wpa2Wpa3, Some(PmfMode::Disabled)) => {
+                return invalid("WPA2/WPA3 transition mode requires PMF");
+            }
+            _ => {}
+        }
+
+        if self.bands.contains(&RadioBand::Band6GHz) {
+            if !matches!(security, WifiSecurity::Wpa3 | WifiSecurity::WpaEnterprise) {
+                return invalid("6 GHz requires WPA3 or enterprise security");
+            }
+            if matches!(self.pmf_mode, Some(PmfMode::Disabled | PmfMode::Optional)) {
+                return invalid("6 GHz requires PMF to be required");
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Create a new WiFi broadcast within a site.
@@ -213,3 +413,331 @@ impl Endpoint for DeleteWifiBroadcast {
         format!("sites/{}/wifi/broadcasts/{}", self.site_id, self.id)
     }
 }
+
+/// Fetch the private pre-shared keys of a WiFi broadcast.
+/// Endpoint: GET /v1/sites/{siteId}/wifi/broadcasts/{broadcastId}/ppsks
+#[derive(Debug, Clone)]
+pub struct GetPpsks {
+    pub site_id: String,
+    pub broadcast_id: String,
+    pub offset: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl GetPpsks {
+    pub fn new(site_id: impl Into<String>, broadcast_id: impl Into<String>) -> Self {
+        Self {
+            site_id: site_id.into(),
+            broadcast_id: broadcast_id.into(),
+            offset: None,
+            limit: None,
+        }
+    }
+
+    /// Set the offset for pagination.
+    pub fn offset(mut self, offset: usize) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Set the limit for pagination.
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl Endpoint for GetPpsks {
+    const PATH: &'static str = "sites/{site_id}/wifi/broadcasts/{broadcast_id}/ppsks";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = SiteResponse<Ppsk>;
+
+    fn build_path(&self) -> String {
+        format!(
+            "sites/{}/wifi/broadcasts/{}/ppsks",
+            self.site_id, self.broadcast_id
+        )
+    }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        pagination_params(self.offset, self.limit)
+    }
+}
+
+impl Paginated for GetPpsks {
+    type Item = Ppsk;
+
+    fn page(&self, offset: usize, limit: usize) -> Self {
+        Self::new(self.site_id.clone(), self.broadcast_id.clone())
+            .offset(offset)
+            .limit(limit)
+    }
+}
+
+/// Request body for creating or updating a private pre-shared key.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PpskRequest {
+    pub name: String,
+    pub passphrase: Passphrase,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vlan_id: Option<i64>,
+}
+
+impl PpskRequest {
+    /// Create a new PPSK request with the required fields.
+    pub fn new(name: impl Into<String>, passphrase: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            passphrase: Passphrase::new(passphrase),
+            network_id: None,
+            vlan_id: None,
+        }
+    }
+
+    /// Place clients using this key on a network.
+    pub fn network_id(mut self, network_id: impl Into<String>) -> Self {
+        self.network_id = Some(network_id.into());
+        self
+    }
+
+    /// Place clients using this key on a VLAN.
+    pub fn vlan_id(mut self, vlan_id: i64) -> Self {
+        self.vlan_id = Some(vlan_id);
+        self
+    }
+}
+
+impl From<&Ppsk> for PpskRequest {
+    fn from(ppsk: &Ppsk) -> Self {
+        Self {
+            name: ppsk.name.clone(),
+            passphrase: ppsk.passphrase.clone(),
+            network_id: ppsk.network_id.clone(),
+            vlan_id: ppsk.vlan_id,
+        }
+    }
+}
+
+/// Add a private pre-shared key to a WiFi broadcast.
+/// Endpoint: POST /v1/sites/{siteId}/wifi/broadcasts/{broadcastId}/ppsks
+#[derive(Debug, Clone)]
+pub struct CreatePpsk {
+    pub site_id: String,
+    pub broadcast_id: String,
+    pub request: PpskRequest,
+}
+
+impl CreatePpsk {
+    pub fn new(
+        site_id: impl Into<String>,
+        broadcast_id: impl Into<String>,
+        request: PpskRequest,
+    ) -> Self {
+        Self {
+            site_id: site_id.into(),
+            broadcast_id: broadcast_id.into(),
+            request,
+        }
+    }
+}
+
+impl Endpoint for CreatePpsk {
+    const PATH: &'static str = "sites/{site_id}/wifi/broadcasts/{broadcast_id}/ppsks";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = MutationResponse<Ppsk>;
+
+    fn build_path(&self) -> String {
+        format!(
+            "sites/{}/wifi/broadcasts/{}/ppsks",
+            self.site_id, self.broadcast_id
+        )
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.request)?))
+    }
+}
+
+/// Update a private pre-shared key.
+/// Endpoint: PUT /v1/sites/{siteId}/wifi/broadcasts/{broadcastId}/ppsks/{id}
+#[derive(Debug, Clone)]
+pub struct UpdatePpsk {
+    pub site_id: String,
+    pub broadcast_id: String,
+    pub id: String,
+    pub request: PpskRequest,
+}
+
+impl UpdatePpsk {
+    pub fn new(
+        site_id: impl Into<String>,
+        broadcast_id: impl Into<String>,
+        id: impl Into<String>,
+        request: PpskRequest,
+    ) -> Self {
+        Self {
+            site_id: site_id.into(),
+            broadcast_id: broadcast_id.into(),
+            id: id.into(),
+            request,
+        }
+    }
+}
+
+impl Endpoint for UpdatePpsk {
+    const PATH: &'static str = "sites/{site_id}/wifi/broadcasts/{broadcast_id}/ppsks/{id}";
+    const METHOD: HttpMethod = HttpMethod::Put;
+    type Response = MutationResponse<Ppsk>;
+
+    fn build_path(&self) -> String {
+        format!(
+            "sites/{}/wifi/broadcasts/{}/ppsks/{}",
+            self.site_id, self.broadcast_id, self.id
+        )
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.request)?))
+    }
+}
+
+/// Remove a private pre-shared key.
+/// Endpoint: DELETE /v1/sites/{siteId}/wifi/broadcasts/{broadcastId}/ppsks/{id}
+#[derive(Debug, Clone)]
+pub struct DeletePpsk {
+    pub site_id: String,
+    pub broadcast_id: String,
+    pub id: String,
+}
+
+impl DeletePpsk {
+    pub fn new(
+        site_id: impl Into<String>,
+        broadcast_id: impl Into<String>,
+        id: impl Into<String>,
+    ) -> Self {
+        Self {
+            site_id: site_id.into(),
+            broadcast_id: broadcast_id.into(),
+            id: id.into(),
+        }
+    }
+}
+
+impl Endpoint for DeletePpsk {
+    const PATH: &'static str = "sites/{site_id}/wifi/broadcasts/{broadcast_id}/ppsks/{id}";
+    const METHOD: HttpMethod = HttpMethod::Delete;
+    type Response = DeleteResponse;
+
+    fn build_path(&self) -> String {
+        format!(
+            "sites/{}/wifi/broadcasts/{}/ppsks/{}",
+            self.site_id, self.broadcast_id, self.id
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Bandwidth;
+    use chrono::{NaiveTime, Weekday};
+    use serde_json::json;
+
+    #[test]
+    fn test_wifi_broadcast_request_debug_redacts_passphrase() {
+        let request = WifiBroadcastRequest::new("Office", "Office WiFi")
+            .security(WifiSecurity::Wpa2)
+            .passphrase("correct horse");
+        let create = CreateWifiBroadcast::new("site-1", request.clone());
+
+        for debug in [format!("{:?}", request), format!("{:?}", create)] {
+            assert!(debug.contains("<redacted>"));
+            assert!(!debug.contains("correct horse"));
+        }
+    }
+
+    #[test]
+    fn test_wifi_broadcast_request_body() {
+        let request = WifiBroadcastRequest::new("Office", "Office WiFi")
+            .security(WifiSecurity::Wpa2Wpa3)
+            .passphrase("correct horse")
+            .pmf_mode(PmfMode::Optional)
+            .bands([RadioBand::Band2_4GHz, RadioBand::Band5GHz])
+            .minimum_data_rates(MinimumDataRates {
+                band_2_4ghz: Bandwidth::from_mbps(12),
+                ..Default::default()
+            })
+            .fast_roaming(true)
+            .multicast_to_unicast(true)
+            .schedule_window(ScheduleWindow::new(
+                [Weekday::Mon],
+                NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            ))
+            .ap_targeting(ApTargeting::ApGroups {
+                ap_group_ids: vec!["group-1".to_string()],
+            });
+        assert!(request.validate().is_ok());
+
+        let endpoint = CreateWifiBroadcast::new("site-1", request);
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "name": "Office",
+                "ssid": "Office WiFi",
+                "security": "WPA2_WPA3",
+                "passphrase": "correct horse",
+                "pmfMode": "OPTIONAL",
+                "broadcastingFrequenciesGHz": [2.4, 5],
+                "minimumDataRates": {"band2_4GHzKbps": 12000},
+                "fastRoamingEnabled": true,
+                "multicastToUnicastEnabled": true,
+                "schedule": [{"days": ["Mon"], "start": "08:00:00", "end": "18:00:00"}],
+                "broadcastingDeviceFilter": {"type": "AP_GROUPS", "apGroupIds": ["group-1"]}
+            }))
+        );
+    }
+
+    #[test]
+    fn test_wifi_broadcast_request_validate() {
+        let wpa3 = || {
+            WifiBroadcastRequest::new("Secure", "Secure")
+                .security(WifiSecurity::Wpa3)
+                .passphrase("long enough")
+        };
+        assert!(wpa3().pmf_mode(PmfMode::Required).validate().is_ok());
+        assert!(wpa3().pmf_mode(PmfMode::Optional).validate().is_err());
+        assert!(wpa3().validate().is_ok());
+        assert!(wpa3()
+            .pmf_mode(PmfMode::Required)
+            .bands([RadioBand::Band6GHz])
+            .validate()
+            .is_ok());
+        assert!(wpa3().bands([RadioBand::Band6GHz]).validate().is_ok());
+        assert!(wpa3()
+            .pmf_mode(PmfMode::Optional)
+            .bands([RadioBand::Band6GHz])
+            .validate()
+            .is_err());
+        assert!(wpa3().passphrase("short").validate().is_err());
+        assert!(wpa3().passphrase("ééééééé").validate().is_err());
+
+        let wpa2_on_6ghz = WifiBroadcastRequest::new("Legacy", "Legacy")
+            .security(WifiSecurity::Wpa2)
+            .passphrase("long enough")
+            .bands([RadioBand::Band6GHz]);
+        assert!(wpa2_on_6ghz.validate().is_err());
+
+        let enterprise =
+            WifiBroadcastRequest::new("Corp", "Corp").security(WifiSecurity::WpaEnterprise);
+        assert!(enterprise.validate().is_err());
+        assert!(enterprise.radius_profile("radius-1").validate().is_ok());
+
+        let open = WifiBroadcastRequest::new("Guest", "Guest").pmf_mode(PmfMode::Required);
+        assert!(open.validate().is_err());
+    }
+}
@@ -1,5 +1,6 @@
 use crate::api::endpoint::{Endpoint, HttpMethod};
 use crate::error::Result;
+use crate::response::ResponseMeta;
 use reqwest::{cookie::Jar, Client};
 use std::sync::Arc;
 
@@ -308,19 +309,7 @@ impl UnifiClient {
         E: Endpoint,
         E::Response: for<'a> serde::Deserialize<'a>,
     {
-        let path = endpoint.build_path();
-
-        // Build URL based on whether this is a remote or local client
-        let url = if let Some(host_id) = &self.host_id {
-            // Remote API: https://api.ui.com/v1/connector/consoles/{host_id}/{path}
-            format!(
-                "{}/{}/connector/consoles/{}/{}",
-                self.base_url, self.base_path, host_id, path
-            )
-        } else {
-            // Local API: {base_url}/{base_path}/{path}
-            format!("{}/{}/{}", self.base_url, self.base_path, path)
-        };
+        let url = self.url(&endpoint.build_path());
 
         let mut headers = reqwest::header::HeaderMap::new();
 
@@ -344,9 +333,15 @@ impl UnifiClient {
         let response = request.send().await?;
 
         if !response.status().is_success() {
-            return Err(crate::error::Error::Request(
-                response.error_for_status().unwrap_err(),
-            ));
+            let error = response.error_for_status_ref().unwrap_err();
+            // The classic API reports failures as {"meta": {"rc": "error", "msg": ...}}
+            let body = response.text().await.unwrap_or_default();
+            if let Ok(envelope) = serde_json::from_str::<ErrorEnvelope>(&body) {
+                if !envelope.meta.is_ok() {
+                    return Err(envelope.meta.into_error());
+                }
+            }
+            return Err(crate::error::Error::Request(error));
         }
 
         let body = response.text().await?;
@@ -372,6 +367,29 @@ impl UnifiClient {
     pub fn base_path(&self) -> &str {
         &self.base_path
     }
+
+    /// Build the full URL for an endpoint path, skipping an empty base path.
+    fn url(&self, path: &str) -> String {
+        let mut url = self.base_url.clone();
+        if !self.base_path.is_empty() {
+            url.push('/');
+            url.push_str(&self.base_path);
+        }
+        // Remote API: https://api.ui.com/v1/connector/consoles/{host_id}/{path}
+        if let Some(host_id) = &self.host_id {
+            url.push_str("/connector/consoles/");
+            url.push_str(host_id);
+        }
+        url.push('/');
+        url.push_str(path);
+        url
+    }
+}
+
+/// Error body returned by the classic controller API.
+#[derive(serde::Deserialize)]
+struct ErrorEnvelope {
+    meta: ResponseMeta,
 }
 
 impl From<HttpMethod> for reqwest::Method {
@@ -385,3 +403,33 @@ impl From<HttpMethod> for reqwest::Method {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_url_joins_base_path() {
+        let client = UnifiClient::new("https://unifi.example.com/").unwrap();
+        assert_eq!(
+            client.url("sites"),
+            "https://unifi.example.com/api/v1/sites"
+        );
+
+        let client = UnifiClient::with_base_path("https://192.168.1.1", "/proxy/network").unwrap();
+        assert_eq!(
+            client.url("api/s/default/stat/device"),
+            "https://192.168.1.1/proxy/network/api/s/default/stat/device"
+        );
+    }
+
+    #[test]
+    fn test_url_with_root_base_path() {
+        let client = UnifiClient::with_base_path("https://controller:8443", "/").unwrap();
+        assert_eq!(client.base_path(), "");
+        assert_eq!(
+            client.url("api/s/default/stat/device"),
+            "https://controller:8443/api/s/default/stat/device"
+        );
+    }
+}
@@ -17,6 +17,9 @@ pub enum Error {
     #[error("Invalid header value: {0}")]
     InvalidHeader(#[from] reqwest::header::InvalidHeaderValue),
 
+    #[error("Controller returned {code}: {message}")]
+    Api { code: String, message: String },
+
     #[error("Endpoint not found: {0}")]
     NotFound(String),
 
@@ -26,8 +29,33 @@ pub enum Error {
     #[error("CSRF token missing")]
     MissingCsrfToken,
 
+    #[error("Invalid MAC address: {0}")]
+    InvalidMacAddress(String),
+
+    #[error("Invalid quantity: {0}")]
+    InvalidQuantity(String),
+
+    #[error("Invalid network configuration: {0}")]
+    InvalidNetworkConfig(String),
+
+    #[error("Invalid WiFi configuration: {0}")]
+    InvalidWifiConfig(String),
+
+    #[error("Invalid firewall rule: {0}")]
+    InvalidFirewallRule(String),
+
+    #[error("Invalid ACL rule: {0}")]
+    InvalidAclRule(String),
+
     #[error("URL parsing failed: {0}")]
     UrlParse(#[from] url::ParseError),
+
+    #[error("Site {site_id} failed: {source}")]
+    Site {
+        site_id: String,
+        #[source]
+        source: Box<Error>,
+    },
 }
 
 pub type Result<T, E = Error> = std::result::Result<T, E>;
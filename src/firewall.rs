@@ -0,0 +1,747 @@
+//! Offline firewall policy evaluation.
+//!
+//! [`FirewallEvaluator`] answers "would this traffic be allowed?" from fetched
+//! zones, policies and networks, without asking the controller. Addresses are
+//! placed in zones through the networks whose subnet contains them. Enabled
+//! policies are then checked in `order`, and the first match decides.
+//!
+//! Addresses outside every network, such as internet hosts, have no zone.
+//! Traffic from a zone to such an address is allowed by default, as the
+//! gateway lets outbound traffic through unless a policy blocks it.
+//!
+//! Only networks with a static IPv6 prefix place IPv6 addresses in a zone. The
+//! prefixes of prefix-delegation and SLAAC networks are not known offline, so
+//! when such a network exists a verdict for an IPv6 address outside every
+//! known subnet is marked [`uncertain`](Verdict::uncertain).
+//!
+//! [`ZoneMatrix`] summarizes the outcome for every pair of zones, and
+//! [`ZoneMatrix::diff`] shows how an edit to the policies changes it.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::api::firewall::{GetFirewallPolicies, GetFirewallZones};
+//! use rustifi::api::networks::GetNetworks;
+//! use rustifi::firewall::{FirewallEvaluator, Packet};
+//! use rustifi::UnifiClient;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+//! let zones = client.fetch_all(GetFirewallZones::new("site-id")).await?;
+//! let policies = client.fetch_all(GetFirewallPolicies::new("site-id")).await?;
+//! let networks = client.fetch_all(GetNetworks::new("site-id")).await?;
+//!
+//! let evaluator = FirewallEvaluator::new(&zones, &policies, &networks);
+//! let packet = Packet::tcp("10.0.20.5".parse().unwrap(), "10.0.30.8".parse().unwrap(), 443);
+//! let verdict = evaluator.evaluate(&packet);
+//! println!(
+//!     "{:?} by {}",
+//!     verdict.action,
+//!     verdict.policy.and_then(|p| p.name.as_deref()).unwrap_or("default")
+//! );
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::networks::{Ipv6Mode, Network};
+use crate::api::traffic::TrafficList;
+use crate::models::firewall::{
+    AddressMatch, ConnectionState, FirewallActionType, FirewallPolicy, FirewallZone, IpCidr,
+    IpVersion, PortMatch, Protocol,
+};
+use chrono::NaiveDateTime;
+use std::collections::HashMap;
+use std::net::IpAddr;
+
+/// Traffic to evaluate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Packet {
+    pub source: IpAddr,
+    pub destination: IpAddr,
+    /// IP protocol number (6 for TCP, 17 for UDP, ...).
+    pub protocol: u8,
+    pub source_port: Option<u16>,
+    pub destination_port: Option<u16>,
+    pub state: ConnectionState,
+}
+
+impl Packet {
+    /// A new connection using any IP protocol, without ports.
+    pub fn new(source: IpAddr, destination: IpAddr, protocol: u8) -> Self {
+        Self {
+            source,
+            destination,
+            protocol,
+            source_port: None,
+            destination_port: None,
+            state: ConnectionState::New,
+        }
+    }
+
+    /// A new TCP connection to a destination port.
+    pub fn tcp(source: IpAddr, destination: IpAddr, destination_port: u16) -> Self {
+        Self {
+            destination_port: Some(destination_port),
+            ..Self::new(source, destination, 6)
+        }
+    }
+
+    /// A new UDP flow to a destination port.
+    pub fn udp(source: IpAddr, destination: IpAddr, destination_port: u16) -> Self {
+        Self {
+            destination_port: Some(destination_port),
+            ..Self::new(source, destination, 17)
+        }
+    }
+
+    /// An ICMP or ICMPv6 echo, depending on the address family.
+    pub fn ping(source: IpAddr, destination: IpAddr) -> Self {
+        let protocol = if source.is_ipv4() { 1 } else { 58 };
+        Self::new(source, destination, protocol)
+    }
+
+    pub fn source_port(mut self, port: u16) -> Self {
+        self.source_port = Some(port);
+        self
+    }
+
+    pub fn state(mut self, state: ConnectionState) -> Self {
+        self.state = state;
+        self
+    }
+
+    fn ip_version(&self) -> IpVersion {
+        IpVersion::of(self.source)
+    }
+}
+
+/// The outcome of evaluating a packet.
+#[derive(Debug, Clone)]
+pub struct Verdict<'a> {
+    pub action: FirewallActionType,
+    /// The policy that matched, or `None` if the zone default applied.
+    pub policy: Option<&'a FirewallPolicy>,
+    /// Zone of the source address, or `None` if no network contains it.
+    pub source_zone: Option<&'a FirewallZone>,
+    pub destination_zone: Option<&'a FirewallZone>,
+    /// Whether an earlier policy was skipped because it referenced a network
+    /// or traffic list the evaluator could not resolve, or used a value this
+    /// crate does not recognize. The verdict may be wrong if that policy would
+    /// have matched.
+    ///
+    /// Also set when an IPv6 address has no zone but could belong to a
+    /// network whose IPv6 prefix is not known offline.
+    pub uncertain: bool,
+}
+
+impl Verdict<'_> {
+    /// Check if the traffic is let through.
+    pub fn is_allowed(&self) -> bool {
+        self.action == FirewallActionType::Allow
+    }
+}
+
+/// Evaluates firewall policies against packets, offline.
+///
+/// Policies without a zone match any zone, and addresses outside every known
+/// network have no zone, so they only match such policies. Traffic that no
+/// policy matches gets the zone default: allowed within a zone and from a zone
+/// to an address outside every zone, and blocked otherwise unless configured
+/// otherwise.
+#[derive(Debug, Clone)]
+pub struct FirewallEvaluator<'a> {
+    zones: &'a [FirewallZone],
+    policies: Vec<&'a FirewallPolicy>,
+    subnets: Vec<(IpCidr, &'a str)>,
+    traffic_lists: HashMap<&'a str, &'a TrafficList>,
+    default_action: FirewallActionType,
+    zone_defaults: HashMap<(Option<String>, Option<String>), FirewallActionType>,
+    at: Option<NaiveDateTime>,
+    /// Whether some network has an IPv6 prefix the evaluator cannot know.
+    unresolved_ipv6: bool,
+}
+
+impl<'a> FirewallEvaluator<'a> {
+    pub fn new(
+        zones: &'a [FirewallZone],
+        policies: &'a [FirewallPolicy],
+        networks: &'a [Network],
+    ) -> Self {
+        let mut ordered: Vec<&FirewallPolicy> = policies.iter().filter(|p| p.enabled).collect();
+        // Policies without an order run last; the sort is stable
+        ordered.sort_by_key(|p| p.order.unwrap_or(i32::MAX));
+
+        let mut subnets: Vec<(IpCidr, &str)> = networks
+            .iter()
+            .flat_map(|network| network_subnets(network).map(|s| (s, network.id.as_str())))
+            .collect();
+        // Most specific subnet first
+        subnets.sort_by_key(|(subnet, _)| std::cmp::Reverse(subnet.prefix_length()));
+        let unresolved_ipv6 = networks.iter().any(|network| {
+            !matches!(
+                network.ipv6_configuration,
+                None | Some(Ipv6Mode::Static { .. })
+            )
+        });
+
+        Self {
+            zones,
+            policies: ordered,
+            subnets,
+            traffic_lists: HashMap::new(),
+            default_action: FirewallActionType::Block,
+            zone_defaults: HashMap::new(),
+            at: None,
+            unresolved_ipv6,
+        }
+    }
+
+    /// Resolve traffic list references using these lists.
+    ///
+    /// Without them, policies that reference a traffic list are skipped and
+    /// the verdict is marked uncertain.
+    pub fn traffic_lists(mut self, lists: &'a [TrafficList]) -> Self {
+        self.traffic_lists = lists.iter().map(|l| (l.id.as_str(), l)).collect();
+        self
+    }
+
+    /// Set the action for traffic between different zones that no policy
+    /// matches. Defaults to `Block`.
+    ///
+    /// Traffic from a zone to an address outside every zone is allowed
+    /// regardless; use [`zone_default`](Self::zone_default) with a `None`
+    /// destination to change that.
+    pub fn default_action(mut self, action: FirewallActionType) -> Self {
+        self.default_action = action;
+        self
+    }
+
+    /// Set the default action for one zone pair. `None` stands for addresses
+    /// outside every zone.
+    pub fn zone_default(
+        mut self,
+        source_zone_id: Option<&str>,
+        destination_zone_id: Option<&str>,
+        action: FirewallActionType,
+    ) -> Self {
+        let key = (
+            source_zone_id.map(str::to_string),
+            destination_zone_id.map(str::to_string),
+        );
+        self.zone_defaults.insert(key, action);
+        self
+    }
+
+    /// Evaluate scheduled policies at a local date and time.
+    ///
+    /// Without it, scheduled policies are treated as always active.
+    pub fn at(mut self, at: NaiveDateTime) -> Self {
+        self.at = Some(at);
+        self
+    }
+
+    /// Get the ID of the network containing an address.
+    pub fn network_of(&self, address: IpAddr) -> Option<&'a str> {
+        self.subnets
+            .iter()
+            .find(|(subnet, _)| subnet.contains(address))
+            .map(|(_, id)| *id)
+    }
+
+    /// Get the zone containing an address.
+    pub fn zone_of(&self, address: IpAddr) -> Option<&'a FirewallZone> {
+        let network = self.network_of(address)?;
+        self.zones
+            .iter()
+            .find(|zone| zone.networks.iter().any(|n| n == network))
+    }
+
+    /// Get the action for traffic between two zones that no policy matches.
+    pub fn default_for(
+        &self,
+        source_zone_id: Option<&str>,
+        destination_zone_id: Option<&str>,
+    ) -> FirewallActionType {
+        let key = (
+            source_zone_id.map(str::to_string),
+            destination_zone_id.map(str::to_string),
+        );
+        match self.zone_defaults.get(&key) {
+            Some(action) => action.clone(),
+            None if source_zone_id.is_some() && source_zone_id == destination_zone_id => {
+                FirewallActionType::Allow
+            }
+            // Outbound to the internet
+            None if source_zone_id.is_some() && destination_zone_id.is_none() => {
+                FirewallActionType::Allow
+            }
+            None => self.default_action.clone(),
+        }
+    }
+
+    /// Evaluate a packet against the policies.
+    pub fn evaluate(&self, packet: &Packet) -> Verdict<'a> {
+        let source_zone = self.zone_of(packet.source);
+        let destination_zone = self.zone_of(packet.destination);
+        let source_zone_id = source_zone.map(|z| z.id.as_str());
+        let destination_zone_id = destination_zone.map(|z| z.id.as_str());
+
+        let unzoned_ipv6 =
+            |address: IpAddr, zone: Option<&FirewallZone>| address.is_ipv6() && zone.is_none();
+        let mut uncertain = self.unresolved_ipv6
+            && (unzoned_ipv6(packet.source, source_zone)
+                || unzoned_ipv6(packet.destination, destination_zone));
+        for policy in &self.policies {
+            if !zone_matches(&policy.source_zone_id, source_zone_id)
+                || !zone_matches(&policy.destination_zone_id, destination_zone_id)
+            {
+                continue;
+            }
+            match self.policy_matches(policy, packet) {
+                Some(true) => {
+                    return Verdict {
+                        action: policy.action.action_type.clone(),
+                        policy: Some(policy),
+                        source_zone,
+                        destination_zone,
+                        uncertain,
+                    }
+                }
+                Some(false) => {}
+                None => uncertain = true,
+            }
+        }
+
+        Verdict {
+            action: self.default_for(source_zone_id, destination_zone_id),
+            policy: None,
+            source_zone,
+            destination_zone,
+            uncertain,
+        }
+    }
+
+    /// Check the non-zone conditions of a policy. Returns `None` if the
+    /// outcome depends on a reference that cannot be resolved, or on a value
+    /// this crate does not recognize.
+    fn policy_matches(&self, policy: &FirewallPolicy, packet: &Packet) -> Option<bool> {
+        let version = match &policy.ip_version {
+            None => Some(true),
+            Some(IpVersion::Unknown(_)) => None,
+            Some(v) => Some(v.includes(&packet.ip_version())),
+        };
+        let protocol = match &policy.protocol {
+            None => Some(true),
+            Some(Protocol::Unknown(_)) => None,
+            Some(p) => Some(p.matches(packet.protocol)),
+        };
+        let state = any_of(&policy.connection_states, |s| match s {
+            ConnectionState::Unknown(_) => None,
+            s => Some(*s == packet.state),
+        });
+        let schedule = match self.at {
+            Some(at) if !policy.schedule.is_empty() => {
+                Some(policy.schedule.iter().any(|w| w.is_active_at(at)))
+            }
+            _ => Some(true),
+        };
+
+        let checks = [
+            version,
+            protocol,
+            state,
+            schedule,
+            self.addresses_match(&policy.source_addresses, packet.source),
+            self.addresses_match(&policy.destination_addresses, packet.destination),
+            self.ports_match(&policy.source_ports, packet.source_port),
+            self.ports_match(&policy.destination_ports, packet.destination_port),
+        ];
+        // A definite mismatch wins over an unresolved reference
+        if checks.contains(&Some(false)) {
+            Some(false)
+        } else if checks.contains(&None) {
+            None
+        } else {
+            Some(true)
+        }
+    }
+
+    fn addresses_match(&self, matches: &[AddressMatch], address: IpAddr) -> Option<bool> {
+        any_of(matches, |m| match m {
+            AddressMatch::Network(id) => Some(self.network_of(address) == Some(id.as_str())),
+            AddressMatch::TrafficList(id) => {
+                let list = self.traffic_lists.get(id.as_str())?;
+                Some(list.entries.iter().any(|entry| {
+                    entry
+                        .parse::<AddressMatch>()
+                        .ok()
+                        .and_then(|m| m.contains(address))
+                        .unwrap_or(false)
+                }))
+            }
+            literal => literal.contains(address),
+        })
+    }
+
+    fn ports_match(&self, matches: &[PortMatch], port: Option<u16>) -> Option<bool> {
+        if matches.is_empty() {
+            return Some(true);
+        }
+        let Some(port) = port else {
+            return Some(false);
+        };
+        any_of(matches, |m| match m {
+            PortMatch::TrafficList(id) => {
+                let list = self.traffic_lists.get(id.as_str())?;
+                Some(list.entries.iter().any(|entry| {
+                    entry
+                        .parse::<PortMatch>()
+                        .ok()
+                        .and_then(|m| m.contains(port))
+                        .unwrap_or(false)
+                }))
+            }
+            literal => literal.contains(port),
+        })
+    }
+
+    /// Summarize the outcome for every pair of zones.
+    pub fn zone_matrix(&self) -> ZoneMatrix {
+        let mut cells = Vec::new();
+        for source in self.zones {
+            for destination in self.zones {
+                cells.push(self.zone_pair(source, destination));
+            }
+        }
+        ZoneMatrix { cells }
+    }
+
+    fn zone_pair(&self, source: &FirewallZone, destination: &FirewallZone) -> ZonePair {
+        let default_action = self.default_for(Some(&source.id), Some(&destination.id));
+        let mut conditional_policy_ids = Vec::new();
+        let mut deciding = None;
+
+        for policy in &self.policies {
+            if !zone_matches(&policy.source_zone_id, Some(&source.id))
+                || !zone_matches(&policy.destination_zone_id, Some(&destination.id))
+            {
+                continue;
+            }
+            if is_unconditional(policy) {
+                deciding = Some(*policy);
+                break;
+            }
+            conditional_policy_ids.push(policy.id.clone());
+        }
+
+        ZonePair {
+            source_zone_id: source.id.clone(),
+            destination_zone_id: destination.id.clone(),
+            effective_action: deciding
+                .map(|p| p.action.action_type.clone())
+                .unwrap_or_else(|| default_action.clone()),
+            default_action,
+            deciding_policy_id: deciding.map(|p| p.id.clone()),
+            conditional_policy_ids,
+        }
+    }
+}
+
+/// Summary of the policies between one pair of zones.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZonePair {
+    pub source_zone_id: String,
+    pub destination_zone_id: String,
+    /// Action when no policy matches.
+    pub default_action: FirewallActionType,
+    /// Action for traffic that no conditional policy matches: the first
+    /// unconditional policy's, or the default.
+    pub effective_action: FirewallActionType,
+    /// The first policy that matches all traffic between the zones.
+    pub deciding_policy_id: Option<String>,
+    /// Policies that match only some traffic, checked before the deciding one.
+    pub conditional_policy_ids: Vec<String>,
+}
+
+/// Zone-by-zone summary of firewall policies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZoneMatrix {
+    cells: Vec<ZonePair>,
+}
+
+/// A zone pair whose summary differs between two matrices.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ZonePairChange {
+    pub before: Option<ZonePair>,
+    pub after: Option<ZonePair>,
+}
+
+impl ZoneMatrix {
+    /// Get every zone pair, row by row.
+    pub fn pairs(&self) -> &[ZonePair] {
+        &self.cells
+    }
+
+    /// Get the summary for one zone pair.
+    pub fn get(&self, source_zone_id: &str, destination_zone_id: &str) -> Option<&ZonePair> {
+        self.cells.iter().find(|c| {
+            c.source_zone_id == source_zone_id && c.destination_zone_id == destination_zone_id
+        })
+    }
+
+    /// List the zone pairs that differ in `after`, including pairs of added or
+    /// removed zones.
+    pub fn diff(&self, after: &ZoneMatrix) -> Vec<ZonePairChange> {
+        let mut changes: Vec<ZonePairChange> = self
+            .cells
+            .iter()
+            .filter_map(|before| {
+                let other = after.get(&before.source_zone_id, &before.destination_zone_id);
+                (other != Some(before)).then(|| ZonePairChange {
+                    before: Some(before.clone()),
+                    after: other.cloned(),
+                })
+            })
+            .collect();
+
+        changes.extend(
+            after
+                .cells
+                .iter()
+                .filter(|cell| {
+                    self.get(&cell.source_zone_id, &cell.destination_zone_id)
+                        .is_none()
+                })
+                .map(|cell| ZonePairChange {
+                    before: None,
+                    after: Some(cell.clone()),
+                }),
+        );
+        changes
+    }
+}
+
+/// Get the subnets of a network, IPv4 first.
+fn network_subnets(network: &Network) -> impl Iterator<Item = IpCidr> + '_ {
+    let ipv4 = network
+        .ipv4_configuration
+        .as_ref()
+        .filter(|config| !config.gateway.is_unspecified())
+        .and_then(|config| IpCidr::new(config.network_address().into(), config.prefix_length).ok());
+    let ipv6 = match &network.ipv6_configuration {
+        Some(Ipv6Mode::Static {
+            gateway,
+            prefix_length,
+        }) => IpCidr::new((*gateway).into(), *prefix_length).ok(),
+        _ => None,
+    };
+    ipv4.into_iter().chain(ipv6)
+}
+
+fn zone_matches(policy_zone: &Option<String>, zone: Option<&str>) -> bool {
+    policy_zone
+        .as_deref()
+        .is_none_or(|policy_zone| Some(policy_zone) == zone)
+}
+
+/// Check if a policy matches all traffic between its zones.
+fn is_unconditional(policy: &FirewallPolicy) -> bool {
+    policy.protocol.as_ref().is_none_or(|p| *p == Protocol::Any)
+        && policy.source_addresses.is_empty()
+        && policy.destination_addresses.is_empty()
+        && policy.source_ports.is_empty()
+        && policy.destination_ports.is_empty()
+        && policy.connection_states.is_empty()
+        && policy
+            .ip_version
+            .as_ref()
+            .is_none_or(|v| *v == IpVersion::Both)
+        && policy.schedule.is_empty()
+}
+
+/// Match any of a list; an empty list matches everything. Returns `None` if
+/// nothing matched and some entries could not be resolved.
+fn any_of<T>(matches: &[T], check: impl Fn(&T) -> Option<bool>) -> Option<bool> {
+    if matches.is_empty() {
+        return Some(true);
+    }
+    let mut unresolved = false;
+    for m in matches {
+        match check(m) {
+            Some(true) => return Some(true),
+            Some(false) => {}
+            None => unresolved = true,
+        }
+    }
+    if unresolved {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fixtures() -> (Vec<FirewallZone>, Vec<FirewallPolicy>, Vec<Network>) {
+        let zones = serde_json::from_value(json!([
+            {"id": "internal", "name": "Internal", "networks": ["lan"]},
+            {"id": "iot", "name": "IoT", "networks": ["iot"]},
+            {"id": "servers", "name": "Servers", "networks": ["srv"]}
+        ]))
+        .unwrap();
+        let networks = serde_json::from_value(json!([
+            {"id": "lan", "name": "LAN", "ipv4Configuration": {"hostIpAddress": "10.0.0.1", "prefixLength": 16}},
+            {"id": "iot", "name": "IoT", "ipv4Configuration": {"hostIpAddress": "10.0.20.1", "prefixLength": 24}},
+            {"id": "srv", "name": "Servers", "ipv4Configuration": {"hostIpAddress": "10.0.30.1", "prefixLength": 24}}
+        ]))
+        .unwrap();
+        let policies = serde_json::from_value(json!([
+            {
+                "id": "iot-https", "name": "IoT to HTTPS", "enabled": true, "order": 10,
+                "action": {"type": "ALLOW"}, "sourceZoneId": "iot", "destinationZoneId": "servers",
+                "protocol": "tcp", "destinationPorts": ["443"]
+            },
+            {
+                "id": "iot-block", "name": "Block IoT", "enabled": true, "order": 20,
+                "action": {"type": "BLOCK"}, "sourceZoneId": "iot"
+            },
+            {
+                "id": "lan-servers", "name": "LAN to servers", "enabled": true, "order": 30,
+                "action": {"type": "ALLOW"}, "sourceZoneId": "internal", "destinationZoneId": "servers"
+            },
+            {
+                "id": "disabled", "enabled": false, "order": 1,
+                "action": {"type": "ALLOW"}, "sourceZoneId": "iot"
+            }
+        ]))
+        .unwrap();
+        (zones, policies, networks)
+    }
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_zone_resolution_prefers_specific_subnet() {
+        let (zones, policies, networks) = fixtures();
+        let evaluator = FirewallEvaluator::new(&zones, &policies, &networks);
+
+        // 10.0.20.0/24 is inside 10.0.0.0/16 but more specific
+        assert_eq!(evaluator.network_of(ip("10.0.20.5")), Some("iot"));
+        assert_eq!(evaluator.zone_of(ip("10.0.5.5")).unwrap().id, "internal");
+        assert!(evaluator.zone_of(ip("8.8.8.8")).is_none());
+    }
+
+    #[test]
+    fn test_evaluate_internet_destination() {
+        let (zones, policies, networks) = fixtures();
+        let evaluator = FirewallEvaluator::new(&zones, &policies, &networks);
+
+        let outbound = evaluator.evaluate(&Packet::tcp(ip("10.0.0.5"), ip("8.8.8.8"), 443));
+        assert!(outbound.destination_zone.is_none());
+        assert!(outbound.is_allowed());
+        assert!(!outbound.uncertain);
+
+        let inbound = evaluator.evaluate(&Packet::tcp(ip("8.8.8.8"), ip("10.0.0.5"), 443));
+        assert_eq!(inbound.action, FirewallActionType::Block);
+
+        let blocked = evaluator.zone_default(Some("internal"), None, FirewallActionType::Block);
+        let outbound = blocked.evaluate(&Packet::tcp(ip("10.0.0.5"), ip("8.8.8.8"), 443));
+        assert_eq!(outbound.action, FirewallActionType::Block);
+    }
+
+    #[test]
+    fn test_evaluate_unresolved_ipv6_zone() {
+        let (zones, policies, mut networks) = fixtures();
+        let evaluator = FirewallEvaluator::new(&zones, &policies, &networks);
+        let packet = Packet::tcp(ip("fd00::5"), ip("2001:db8::1"), 443);
+        assert!(!evaluator.evaluate(&packet).uncertain);
+
+        networks[0].ipv6_configuration = Some(Ipv6Mode::Slaac);
+        let evaluator = FirewallEvaluator::new(&zones, &policies, &networks);
+        assert!(evaluator.evaluate(&packet).uncertain);
+        let ipv4 = Packet::tcp(ip("10.0.0.5"), ip("8.8.8.8"), 443);
+        assert!(!evaluator.evaluate(&ipv4).uncertain);
+    }
+
+    #[test]
+    fn test_evaluate_first_match_in_order() {
+        let (zones, policies, networks) = fixtures();
+        let evaluator = FirewallEvaluator::new(&zones, &policies, &networks);
+
+        let https = evaluator.evaluate(&Packet::tcp(ip("10.0.20.5"), ip("10.0.30.8"), 443));
+        assert!(https.is_allowed());
+        assert_eq!(https.policy.unwrap().id, "iot-https");
+
+        let ssh = evaluator.evaluate(&Packet::tcp(ip("10.0.20.5"), ip("10.0.30.8"), 22));
+        assert_eq!(ssh.action, FirewallActionType::Block);
+        assert_eq!(ssh.policy.unwrap().id, "iot-block");
+
+        let udp = evaluator.evaluate(&Packet::udp(ip("10.0.20.5"), ip("10.0.30.8"), 443));
+        assert_eq!(udp.policy.unwrap().id, "iot-block");
+
+        // No policy from servers to LAN: inter-zone default
+        let back = evaluator.evaluate(&Packet::ping(ip("10.0.30.8"), ip("10.0.0.9")));
+        assert!(back.policy.is_none());
+        assert_eq!(back.action, FirewallActionType::Block);
+
+        // Same zone: allowed by default
+        let local = evaluator.evaluate(&Packet::ping(ip("10.0.0.8"), ip("10.0.0.9")));
+        assert!(local.is_allowed());
+    }
+
+    #[test]
+    fn test_evaluate_unresolved_traffic_list() {
+        let (zones, mut policies, networks) = fixtures();
+        policies[0].destination_ports = vec![PortMatch::TrafficList("web".to_string())];
+
+        let evaluator = FirewallEvaluator::new(&zones, &policies, &networks);
+        let verdict = evaluator.evaluate(&Packet::tcp(ip("10.0.20.5"), ip("10.0.30.8"), 443));
+        assert_eq!(verdict.policy.unwrap().id, "iot-block");
+        assert!(verdict.uncertain);
+
+        let lists: Vec<TrafficList> = serde_json::from_value(json!([
+            {"id": "web", "name": "Web", "listType": "PORT", "entries": ["80", "443"]}
+        ]))
+        .unwrap();
+        let evaluator = evaluator.traffic_lists(&lists);
+        let verdict = evaluator.evaluate(&Packet::tcp(ip("10.0.20.5"), ip("10.0.30.8"), 443));
+        assert_eq!(verdict.policy.unwrap().id, "iot-https");
+        assert!(!verdict.uncertain);
+    }
+
+    #[test]
+    fn test_zone_matrix_and_diff() {
+        let (zones, mut policies, networks) = fixtures();
+        let before = FirewallEvaluator::new(&zones, &policies, &networks).zone_matrix();
+
+        let iot_servers = before.get("iot", "servers").unwrap();
+        assert_eq!(iot_servers.effective_action, FirewallActionType::Block);
+        assert_eq!(iot_servers.deciding_policy_id.as_deref(), Some("iot-block"));
+        assert_eq!(iot_servers.conditional_policy_ids, vec!["iot-https"]);
+
+        let internal_servers = before.get("internal", "servers").unwrap();
+        assert_eq!(internal_servers.default_action, FirewallActionType::Block);
+        assert_eq!(internal_servers.effective_action, FirewallActionType::Allow);
+        assert_eq!(
+            before.get("servers", "servers").unwrap().effective_action,
+            FirewallActionType::Allow
+        );
+
+        // Disabling the LAN policy only changes the internal -> servers cell
+        policies[2].enabled = false;
+        let after = FirewallEvaluator::new(&zones, &policies, &networks).zone_matrix();
+        let changes = before.diff(&after);
+        assert_eq!(changes.len(), 1);
+        let change = &changes[0];
+        assert_eq!(change.before.as_ref().unwrap().source_zone_id, "internal");
+        assert_eq!(
+            change.after.as_ref().unwrap().effective_action,
+            FirewallActionType::Block
+        );
+    }
+}
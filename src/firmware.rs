@@ -0,0 +1,740 @@
+//! Rolling firmware upgrades.
+//!
+//! [`RollingUpgrade`] upgrades a set of devices in waves so that a site never
+//! loses all of its access points or its uplink path at once. Each wave is
+//! upgraded in parallel, then polled until every device reports
+//! [`DeviceState::Online`] again after [`DeviceState::Updating`]. If any device
+//! in a wave fails or times out, the remaining waves are not started.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::firmware::{RollingUpgrade, UpgradeEvent, WaveStrategy};
+//! use rustifi::UnifiClient;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+//!
+//! let upgrade = RollingUpgrade::new("site-id")
+//!     .strategy(WaveStrategy::LeavesFirst)
+//!     .max_wave_size(10);
+//!
+//! let report = client
+//!     .rolling_upgrade(&upgrade, |event| {
+//!         if let UpgradeEvent::Completed { device_id, firmware_version } = event {
+//!             println!("{} now on {:?}", device_id, firmware_version);
+//!         }
+//!     })
+//!     .await?;
+//!
+//! println!("{} upgraded, {} failed", report.upgraded.len(), report.failed.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::devices::{ExecuteDeviceAction, GetDeviceDetails};
+use crate::error::Result;
+use crate::models::{DeviceDetails, DeviceState, ProductFamily};
+use crate::UnifiClient;
+use futures::future::join_all;
+use futures::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Default time between device state polls.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Default time the devices of a wave may take to come back online.
+pub const DEFAULT_DEVICE_TIMEOUT: Duration = Duration::from_secs(20 * 60);
+
+/// Maximum number of concurrent requests when fetching device details.
+const MAX_CONCURRENT_REQUESTS: usize = 10;
+
+/// How devices are grouped into waves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WaveStrategy {
+    /// One wave per product family, in the given order.
+    ///
+    /// Devices whose family is unknown or not listed go in a final wave.
+    ByDeviceType(Vec<ProductFamily>),
+    /// Devices furthest from the gateway first, following the uplink tree.
+    ///
+    /// A device is only upgraded after every selected device that uplinks
+    /// through it, so a switch never reboots while its APs are mid-upgrade.
+    LeavesFirst,
+}
+
+impl Default for WaveStrategy {
+    /// Access points, then switches, then gateways and consoles.
+    fn default() -> Self {
+        WaveStrategy::ByDeviceType(vec![
+            ProductFamily::AccessPoint,
+            ProductFamily::Switch,
+            ProductFamily::Gateway,
+            ProductFamily::Console,
+        ])
+    }
+}
+
+/// Why a device upgrade failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpgradeFailure {
+    /// The controller rejected the upgrade request.
+    Request(String),
+    /// The device did not come back online in time.
+    TimedOut { last_state: Option<DeviceState> },
+}
+
+impl fmt::Display for UpgradeFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UpgradeFailure::Request(message) => write!(f, "upgrade request failed: {}", message),
+            UpgradeFailure::TimedOut {
+                last_state: Some(state),
+            } => write!(f, "timed out in state {:?}", state),
+            UpgradeFailure::TimedOut { last_state: None } => write!(f, "timed out"),
+        }
+    }
+}
+
+/// Progress reported while a rolling upgrade runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpgradeEvent {
+    /// A wave is about to be upgraded. `index` is zero-based.
+    WaveStarted {
+        index: usize,
+        total: usize,
+        device_ids: Vec<String>,
+    },
+    /// The controller accepted the upgrade request for a device.
+    UpgradeRequested { device_id: String },
+    /// The device reported that it is updating.
+    Updating { device_id: String },
+    /// The device is back online after updating.
+    Completed {
+        device_id: String,
+        firmware_version: Option<String>,
+    },
+    /// The device upgrade failed.
+    Failed {
+        device_id: String,
+        reason: UpgradeFailure,
+    },
+    /// Every device in the wave completed.
+    WaveCompleted { index: usize },
+    /// A wave failed and the remaining devices were not upgraded.
+    Aborted { remaining_device_ids: Vec<String> },
+}
+
+/// Outcome of a rolling upgrade.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpgradeReport {
+    /// Devices that were upgraded and came back online.
+    pub upgraded: Vec<String>,
+    /// Devices whose upgrade failed.
+    pub failed: Vec<(String, UpgradeFailure)>,
+    /// Devices that were not upgraded because an earlier wave failed.
+    pub skipped: Vec<String>,
+}
+
+impl UpgradeReport {
+    /// Check if every selected device was upgraded.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// Configuration for a rolling firmware upgrade of one site.
+#[derive(Debug, Clone)]
+pub struct RollingUpgrade {
+    pub site_id: String,
+    pub strategy: WaveStrategy,
+    /// Split waves into chunks of at most this many devices.
+    pub max_wave_size: Option<usize>,
+    pub poll_interval: Duration,
+    pub device_timeout: Duration,
+}
+
+impl RollingUpgrade {
+    pub fn new(site_id: impl Into<String>) -> Self {
+        Self {
+            site_id: site_id.into(),
+            strategy: WaveStrategy::default(),
+            max_wave_size: None,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            device_timeout: DEFAULT_DEVICE_TIMEOUT,
+        }
+    }
+
+    pub fn strategy(mut self, strategy: WaveStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Limit how many devices are upgraded at the same time.
+    pub fn max_wave_size(mut self, size: usize) -> Self {
+        self.max_wave_size = Some(size.max(1));
+        self
+    }
+
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Set how long each wave may take to come back online.
+    pub fn device_timeout(mut self, timeout: Duration) -> Self {
+        self.device_timeout = timeout;
+        self
+    }
+
+    /// Group devices into waves, in the order they will be upgraded.
+    ///
+    /// Devices within a wave are sorted by name. Uplinks are only followed
+    /// through `devices`; use [`plan_with_topology`](Self::plan_with_topology)
+    /// when some devices on the path are not being upgraded.
+    pub fn plan<'a>(&self, devices: &'a [DeviceDetails]) -> Vec<Vec<&'a DeviceDetails>> {
+        self.plan_with_topology(devices, devices)
+    }
+
+    /// Group devices into waves, following uplinks through every device in
+    /// `topology`, including ones that are not being upgraded.
+    ///
+    /// With [`WaveStrategy::LeavesFirst`], a device waits for every selected
+    /// device below it, even when unselected devices sit in between.
+    pub fn plan_with_topology<'a>(
+        &self,
+        devices: &'a [DeviceDetails],
+        topology: &[DeviceDetails],
+    ) -> Vec<Vec<&'a DeviceDetails>> {
+        let mut waves = match &self.strategy {
+            WaveStrategy::ByDeviceType(order) => waves_by_type(devices, order),
+            WaveStrategy::LeavesFirst => waves_leaves_first(devices, topology),
+        };
+
+        for wave in &mut waves {
+            wave.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.cmp(&b.id)));
+        }
+
+        match self.max_wave_size {
+            Some(size) => waves
+                .into_iter()
+                .flat_map(|wave| {
+                    wave.chunks(size)
+                        .map(<[&DeviceDetails]>::to_vec)
+                        .collect::<Vec<_>>()
+                })
+                .collect(),
+            None => waves,
+        }
+    }
+
+    /// Upgrade `devices` wave by wave, reporting progress to `on_event`.
+    ///
+    /// Devices that do not report `firmware_updatable` are still upgraded; filter
+    /// them beforehand if needed ([`UnifiClient::rolling_upgrade`] does).
+    pub async fn run<F>(
+        &self,
+        client: &UnifiClient,
+        devices: &[DeviceDetails],
+        on_event: F,
+    ) -> UpgradeReport
+    where
+        F: FnMut(&UpgradeEvent),
+    {
+        self.run_with_topology(client, devices, devices, on_event)
+            .await
+    }
+
+    /// Upgrade `devices` wave by wave, planning waves with
+    /// [`plan_with_topology`](Self::plan_with_topology).
+    pub async fn run_with_topology<F>(
+        &self,
+        client: &UnifiClient,
+        devices: &[DeviceDetails],
+        topology: &[DeviceDetails],
+        mut on_event: F,
+    ) -> UpgradeReport
+    where
+        F: FnMut(&UpgradeEvent),
+    {
+        let waves = self.plan_with_topology(devices, topology);
+        let total = waves.len();
+        let mut report = UpgradeReport::default();
+
+        for (index, wave) in waves.iter().enumerate() {
+            on_event(&UpgradeEvent::WaveStarted {
+                index,
+                total,
+                device_ids: wave.iter().map(|d| d.id.clone()).collect(),
+            });
+
+            let failed_before = report.failed.len();
+            self.run_wave(client, wave, &mut report, &mut on_event)
+                .await;
+
+            if report.failed.len() > failed_before {
+                let remaining: Vec<String> = waves[index + 1..]
+                    .iter()
+                    .flatten()
+                    .map(|d| d.id.clone())
+                    .collect();
+                if !remaining.is_empty() {
+                    on_event(&UpgradeEvent::Aborted {
+                        remaining_device_ids: remaining.clone(),
+                    });
+                }
+                report.skipped = remaining;
+                break;
+            }
+
+            on_event(&UpgradeEvent::WaveCompleted { index });
+        }
+
+        report
+    }
+
+    async fn run_wave<F>(
+        &self,
+        client: &UnifiClient,
+        wave: &[&DeviceDetails],
+        report: &mut UpgradeReport,
+        on_event: &mut F,
+    ) where
+        F: FnMut(&UpgradeEvent),
+    {
+        let requests: Vec<ExecuteDeviceAction> = wave
+            .iter()
+            .map(|d| ExecuteDeviceAction::upgrade(&self.site_id, &d.id))
+            .collect();
+        let results = join_all(requests.iter().map(|r| client.execute(r))).await;
+
+        let mut pending: Vec<(&DeviceDetails, UpgradeTracker)> = Vec::new();
+        for (device, result) in wave.iter().zip(results) {
+            match result {
+                Ok(_) => {
+                    on_event(&UpgradeEvent::UpgradeRequested {
+                        device_id: device.id.clone(),
+                    });
+                    pending.push((device, UpgradeTracker::new(device)));
+                }
+                Err(e) => fail(
+                    report,
+                    on_event,
+                    &device.id,
+                    UpgradeFailure::Request(e.to_string()),
+                ),
+            }
+        }
+
+        let deadline = Instant::now() + self.device_timeout;
+        while !pending.is_empty() {
+            if Instant::now() >= deadline {
+                for (device, tracker) in pending.drain(..) {
+                    let reason = UpgradeFailure::TimedOut {
+                        last_state: tracker.last_state,
+                    };
+                    fail(report, on_event, &device.id, reason);
+                }
+                break;
+            }
+
+            tokio::time::sleep(self.poll_interval).await;
+
+            let polls: Vec<GetDeviceDetails> = pending
+                .iter()
+                .map(|(d, _)| GetDeviceDetails::new(&self.site_id, &d.id))
+                .collect();
+            let results = join_all(polls.iter().map(|p| client.execute(p))).await;
+
+            let mut still_pending = Vec::new();
+            for ((device, mut tracker), result) in pending.into_iter().zip(results) {
+                // The controller may be briefly unreachable while a gateway
+                // reboots; keep polling until the deadline.
+                let Ok(details) = result else {
+                    still_pending.push((device, tracker));
+                    continue;
+                };
+
+                match tracker.observe(&details) {
+                    Progress::StartedUpdating => {
+                        on_event(&UpgradeEvent::Updating {
+                            device_id: device.id.clone(),
+                        });
+                        still_pending.push((device, tracker));
+                    }
+                    Progress::Waiting => still_pending.push((device, tracker)),
+                    Progress::Done => {
+                        on_event(&UpgradeEvent::Completed {
+                            device_id: device.id.clone(),
+                            firmware_version: details.firmware_version.clone(),
+                        });
+                        report.upgraded.push(device.id.clone());
+                    }
+                }
+            }
+            pending = still_pending;
+        }
+    }
+}
+
+fn fail<F>(report: &mut UpgradeReport, on_event: &mut F, device_id: &str, reason: UpgradeFailure)
+where
+    F: FnMut(&UpgradeEvent),
+{
+    on_event(&UpgradeEvent::Failed {
+        device_id: device_id.to_string(),
+        reason: reason.clone(),
+    });
+    report.failed.push((device_id.to_string(), reason));
+}
+
+fn waves_by_type<'a>(
+    devices: &'a [DeviceDetails],
+    order: &[ProductFamily],
+) -> Vec<Vec<&'a DeviceDetails>> {
+    let mut waves: Vec<Vec<&DeviceDetails>> = vec![Vec::new(); order.len() + 1];
+    for device in devices {
+        let slot = device
+            .product_family()
+            .and_then(|family| order.iter().position(|f| *f == family))
+            .unwrap_or(order.len());
+        waves[slot].push(device);
+    }
+    waves.retain(|wave| !wave.is_empty());
+    waves
+}
+
+fn waves_leaves_first<'a>(
+    devices: &'a [DeviceDetails],
+    topology: &[DeviceDetails],
+) -> Vec<Vec<&'a DeviceDetails>> {
+    let selected: HashSet<&str> = devices.iter().map(|d| d.id.as_str()).collect();
+    let mut parents: HashMap<&str, &str> = HashMap::new();
+    for device in topology.iter().chain(devices) {
+        if let Some(uplink) = &device.uplink {
+            parents.insert(&device.id, &uplink.device_id);
+        }
+    }
+
+    // Link each device to its nearest selected ancestor, walking through
+    // devices that are not being upgraded
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    for device in devices {
+        let mut seen = HashSet::from([device.id.as_str()]);
+        let mut current = device.id.as_str();
+        while let Some(&parent) = parents.get(current) {
+            if !seen.insert(parent) {
+                break;
+            }
+            if selected.contains(parent) {
+                children.entry(parent).or_default().push(&device.id);
+                break;
+            }
+            current = parent;
+        }
+    }
+
+    let mut heights: HashMap<&str, usize> = HashMap::new();
+    let mut waves: Vec<Vec<&DeviceDetails>> = Vec::new();
+    for device in devices {
+        let height = subtree_height(&device.id, &children, &mut heights, &mut HashSet::new());
+        if waves.len() <= height {
+            waves.resize(height + 1, Vec::new());
+        }
+        waves[height].push(device);
+    }
+    waves
+}
+
+/// Height of a device in the uplink tree; leaves have height 0.
+///
+/// `visiting` guards against uplink cycles reported during topology changes.
+fn subtree_height<'a>(
+    id: &'a str,
+    children: &HashMap<&'a str, Vec<&'a str>>,
+    heights: &mut HashMap<&'a str, usize>,
+    visiting: &mut HashSet<&'a str>,
+) -> usize {
+    if let Some(height) = heights.get(id) {
+        return *height;
+    }
+    if !visiting.insert(id) {
+        return 0;
+    }
+
+    let height = children
+        .get(id)
+        .map(|kids| {
+            kids.iter()
+                .map(|kid| subtree_height(kid, children, heights, visiting) + 1)
+                .max()
+                .unwrap_or(0)
+        })
+        .unwrap_or(0);
+
+    visiting.remove(id);
+    heights.insert(id, height);
+    height
+}
+
+/// Result of observing a device's state during an upgrade.
+#[derive(Debug, PartialEq, Eq)]
+enum Progress {
+    Waiting,
+    StartedUpdating,
+    Done,
+}
+
+/// Tracks a device from upgrade request back to online.
+#[derive(Debug)]
+struct UpgradeTracker {
+    initial_firmware: Option<String>,
+    seen_updating: bool,
+    last_state: Option<DeviceState>,
+}
+
+impl UpgradeTracker {
+    fn new(device: &DeviceDetails) -> Self {
+        Self {
+            initial_firmware: device.firmware_version.clone(),
+            seen_updating: false,
+            last_state: None,
+        }
+    }
+
+    /// A device is done once it is online after updating.
+    ///
+    /// The updating state can be missed between polls, so a changed firmware
+    /// version while online also counts as done.
+    fn observe(&mut self, details: &DeviceDetails) -> Progress {
+        let state = details.device_state();
+        self.last_state = Some(state.clone());
+
+        if state == DeviceState::Updating {
+            if self.seen_updating {
+                return Progress::Waiting;
+            }
+            self.seen_updating = true;
+            return Progress::StartedUpdating;
+        }
+
+        let firmware_changed = details.firmware_version != self.initial_firmware;
+        if state == DeviceState::Online && (self.seen_updating || firmware_changed) {
+            Progress::Done
+        } else {
+            Progress::Waiting
+        }
+    }
+}
+
+/// Extension methods for UnifiClient to run rolling firmware upgrades.
+impl UnifiClient {
+    /// Upgrade every device on the site that has a firmware update available.
+    ///
+    /// Device details are fetched to find uplinks and product families, then
+    /// the devices are upgraded with [`RollingUpgrade::run_with_topology`].
+    /// With [`WaveStrategy::LeavesFirst`], details of every device are fetched
+    /// so uplinks through devices without an update are followed too.
+    pub async fn rolling_upgrade<F>(
+        &self,
+        upgrade: &RollingUpgrade,
+        on_event: F,
+    ) -> Result<UpgradeReport>
+    where
+        F: FnMut(&UpgradeEvent),
+    {
+        let site_id = upgrade.site_id.as_str();
+        let needs_topology = upgrade.strategy == WaveStrategy::LeavesFirst;
+        let devices = self.fetch_all_devices(site_id).await?;
+        let updatable: HashSet<&str> = devices
+            .iter()
+            .filter(|d| d.firmware_updatable == Some(true))
+            .map(|d| d.id.as_str())
+            .collect();
+        let fetched: Vec<&str> = devices
+            .iter()
+            .map(|d| d.id.as_str())
+            .filter(|id| needs_topology || updatable.contains(id))
+            .collect();
+
+        let details: Vec<Result<DeviceDetails>> = stream::iter(fetched)
+            .map(|id| async move { self.execute(&GetDeviceDetails::new(site_id, id)).await })
+            .buffered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await;
+        let topology = details.into_iter().collect::<Result<Vec<_>>>()?;
+        let selected: Vec<DeviceDetails> = topology
+            .iter()
+            .filter(|d| updatable.contains(d.id.as_str()))
+            .cloned()
+            .collect();
+
+        Ok(upgrade
+            .run_with_topology(self, &selected, &topology, on_event)
+            .await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn device(id: &str, model: &str, uplink: Option<&str>) -> DeviceDetails {
+        let mut value = json!({
+            "id": id,
+            "macAddress": "00:11:22:33:44:55",
+            "ipAddress": "192.168.1.2",
+            "name": id,
+            "model": model,
+            "supported": true,
+            "state": "ONLINE",
+            "firmwareVersion": "7.0.0",
+            "firmwareUpdatable": true,
+            "configurationId": "cfg"
+        });
+        if let Some(parent) = uplink {
+            value["uplink"] = json!({"deviceId": parent});
+        }
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn ids(waves: &[Vec<&DeviceDetails>]) -> Vec<Vec<String>> {
+        waves
+            .iter()
+            .map(|wave| wave.iter().map(|d| d.id.clone()).collect())
+            .collect()
+    }
+
+    fn topology() -> Vec<DeviceDetails> {
+        vec![
+            device("gw", "UDM-Pro", None),
+            device("core", "USW-Pro-24-PoE", Some("gw")),
+            device("edge", "USW-Lite-8-PoE", Some("core")),
+            device("ap-1", "U6-Pro", Some("edge")),
+            device("ap-2", "U6-Lite", Some("core")),
+            device("mystery", "XYZ-1", Some("gw")),
+        ]
+    }
+
+    #[test]
+    fn test_plan_by_device_type() {
+        let devices = topology();
+        let waves = RollingUpgrade::new("site").plan(&devices);
+
+        assert_eq!(
+            ids(&waves),
+            vec![
+                vec!["ap-1", "ap-2"],
+                vec!["core", "edge"],
+                vec!["gw"],
+                vec!["mystery"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_leaves_first() {
+        let devices = topology();
+        let waves = RollingUpgrade::new("site")
+            .strategy(WaveStrategy::LeavesFirst)
+            .plan(&devices);
+
+        assert_eq!(
+            ids(&waves),
+            vec![
+                vec!["ap-1", "ap-2", "mystery"],
+                vec!["edge"],
+                vec!["core"],
+                vec!["gw"]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_plan_leaves_first_through_unselected_devices() {
+        let topology = topology();
+        // "core" has no update, but ap-2 and edge still uplink through it
+        let selected: Vec<DeviceDetails> = topology
+            .iter()
+            .filter(|d| d.id != "core")
+            .cloned()
+            .collect();
+        let upgrade = RollingUpgrade::new("site").strategy(WaveStrategy::LeavesFirst);
+
+        assert_eq!(
+            ids(&upgrade.plan_with_topology(&selected, &topology)),
+            vec![vec!["ap-1", "ap-2", "mystery"], vec!["edge"], vec!["gw"]]
+        );
+
+        // Without the topology the chain breaks at "core", and the gateway
+        // reboots alongside a switch that uplinks through it
+        assert_eq!(
+            ids(&upgrade.plan(&selected)),
+            vec![vec!["ap-1", "ap-2", "mystery"], vec!["edge", "gw"]]
+        );
+    }
+
+    #[test]
+    fn test_plan_max_wave_size_and_cycles() {
+        let devices = vec![
+            device("a", "U6-Pro", Some("b")),
+            device("b", "U6-Pro", Some("a")),
+            device("c", "U6-Pro", None),
+        ];
+        let upgrade = RollingUpgrade::new("site").max_wave_size(2);
+        assert_eq!(
+            ids(&upgrade.plan(&devices)),
+            vec![vec!["a", "b"], vec!["c"]]
+        );
+
+        let leaves = upgrade.strategy(WaveStrategy::LeavesFirst).plan(&devices);
+        assert_eq!(leaves.iter().map(Vec::len).sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_tracker_requires_updating_then_online() {
+        let initial = device("ap", "U6-Pro", None);
+        let mut tracker = UpgradeTracker::new(&initial);
+
+        // Still online on the old firmware: the upgrade has not started yet
+        assert_eq!(tracker.observe(&initial), Progress::Waiting);
+
+        let mut updating = initial.clone();
+        updating.state = "UPDATING".to_string();
+        assert_eq!(tracker.observe(&updating), Progress::StartedUpdating);
+        assert_eq!(tracker.observe(&updating), Progress::Waiting);
+
+        let mut offline = initial.clone();
+        offline.state = "OFFLINE".to_string();
+        assert_eq!(tracker.observe(&offline), Progress::Waiting);
+        assert_eq!(tracker.last_state, Some(DeviceState::Offline));
+
+        assert_eq!(tracker.observe(&initial), Progress::Done);
+    }
+
+    #[test]
+    fn test_tracker_accepts_firmware_change_without_updating() {
+        let initial = device("ap", "U6-Pro", None);
+        let mut tracker = UpgradeTracker::new(&initial);
+
+        let mut upgraded = initial.clone();
+        upgraded.firmware_version = Some("7.1.0".to_string());
+        assert_eq!(tracker.observe(&upgraded), Progress::Done);
+    }
+
+    #[test]
+    fn test_upgrade_failure_display() {
+        assert_eq!(
+            UpgradeFailure::TimedOut {
+                last_state: Some(DeviceState::Updating)
+            }
+            .to_string(),
+            "timed out in state Updating"
+        );
+        assert!(UpgradeReport::default().is_success());
+    }
+}
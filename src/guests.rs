@@ -0,0 +1,188 @@
+//! Hotspot guest authorization helpers.
+//!
+//! Guest clients on a hotspot network must be authorized before they get
+//! network access. This module wraps the guest actions of
+//! [`ExecuteClientAction`] and lists the guests that are currently authorized.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::api::clients::GuestAccessLimits;
+//! use rustifi::models::Minutes;
+//! use rustifi::UnifiClient;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+//!
+//! let limits = GuestAccessLimits::new()
+//!     .time_limit(Minutes::from_hours(4).unwrap())
+//!     .rx_rate_limit("10Mbps".parse()?);
+//! client.authorize_guest("site-id", "client-id", limits).await?;
+//!
+//! for guest in client.authorized_guests("site-id").await? {
+//!     println!(
+//!         "{}: {:?} left, {:?} quota left",
+//!         guest.client.display_name(),
+//!         guest.remaining_time,
+//!         guest.remaining_quota
+//!     );
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::clients::{ExecuteClientAction, GetClient, GuestAccessLimits};
+use crate::error::{Error, Result};
+use crate::models::{Client, DataSize, GuestAuthorization, Minutes};
+use crate::UnifiClient;
+use chrono::{DateTime, Duration, Utc};
+
+/// A guest client with an active authorization.
+#[derive(Debug, Clone)]
+pub struct AuthorizedGuest {
+    pub client: Client,
+    pub authorization: GuestAuthorization,
+    /// Access time left, or `None` if the authorization does not expire.
+    pub remaining_time: Option<Duration>,
+    /// Data quota left, or `None` if the authorization has no data limit.
+    pub remaining_quota: Option<DataSize>,
+}
+
+/// Select the guests whose authorization is still active as of `now`.
+///
+/// Guests whose authorization has expired or whose quota is exhausted are
+/// skipped. The result is sorted by remaining time, soonest to expire first.
+pub fn authorized_guests_at(clients: &[Client], now: DateTime<Utc>) -> Vec<AuthorizedGuest> {
+    let mut guests: Vec<AuthorizedGuest> = clients
+        .iter()
+        .filter(|client| client.is_authorized_guest())
+        .filter_map(|client| {
+            let authorization = client.guest_authorization()?.clone();
+            if authorization.is_expired_at(now) || authorization.is_quota_exhausted() {
+                return None;
+            }
+            Some(AuthorizedGuest {
+                remaining_time: authorization.remaining_at(now),
+                remaining_quota: authorization.remaining_quota(),
+                client: client.clone(),
+                authorization,
+            })
+        })
+        .collect();
+
+    // Guests without an expiry sort last
+    guests.sort_by_key(|guest| guest.remaining_time.unwrap_or(Duration::MAX));
+    guests
+}
+
+/// Extension methods for UnifiClient to manage hotspot guests.
+impl UnifiClient {
+    /// Authorize a guest client with the given limits.
+    ///
+    /// Returns the authorization granted by the controller.
+    pub async fn authorize_guest(
+        &self,
+        site_id: &str,
+        client_id: &str,
+        limits: GuestAccessLimits,
+    ) -> Result<Option<GuestAuthorization>> {
+        let endpoint = ExecuteClientAction::authorize_guest(site_id, client_id, limits);
+        Ok(self.execute(&endpoint).await?.granted_authorization)
+    }
+
+    /// Revoke a guest client's authorization.
+    ///
+    /// Returns the authorization that was revoked, if the guest had one.
+    pub async fn unauthorize_guest(
+        &self,
+        site_id: &str,
+        client_id: &str,
+    ) -> Result<Option<GuestAuthorization>> {
+        let endpoint = ExecuteClientAction::unauthorize_guest(site_id, client_id);
+        Ok(self.execute(&endpoint).await?.revoked_authorization)
+    }
+
+    /// Extend an authorized guest's access by `extension`.
+    ///
+    /// The guest is re-authorized with its remaining time plus `extension`,
+    /// keeping its data and rate limits. Returns [`Error::InvalidState`] if the
+    /// client is not an authorized guest.
+    pub async fn extend_guest_authorization(
+        &self,
+        site_id: &str,
+        client_id: &str,
+        extension: Minutes,
+    ) -> Result<Option<GuestAuthorization>> {
+        let client = self
+            .execute(&GetClient::new(site_id, client_id))
+            .await?
+            .data
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::NotFound(format!("Client {} not found", client_id)))?;
+
+        let current = client.guest_authorization().ok_or_else(|| {
+            Error::InvalidState(format!("Client {} is not an authorized guest", client_id))
+        })?;
+        let limits = GuestAccessLimits::extending(current, extension, Utc::now())
+            .ok_or_else(|| Error::InvalidState("Guest time limit overflows".to_string()))?;
+
+        self.authorize_guest(site_id, client_id, limits).await
+    }
+
+    /// List the guests that are currently authorized on a site.
+    ///
+    /// See [`authorized_guests_at`] for how guests are selected and ordered.
+    pub async fn authorized_guests(&self, site_id: &str) -> Result<Vec<AuthorizedGuest>> {
+        let clients = self.fetch_all_clients(site_id).await?;
+        Ok(authorized_guests_at(&clients, Utc::now()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn guest(id: &str, authorization: serde_json::Value) -> Client {
+        serde_json::from_value(json!({
+            "id": id,
+            "type": "WIRELESS",
+            "access": {"type": "GUEST", "authorized": true, "authorization": authorization}
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_authorized_guests_at() {
+        let clients = vec![
+            guest("later", json!({"expiresAt": "2024-01-01T16:00:00Z"})),
+            guest("expired", json!({"expiresAt": "2024-01-01T11:00:00Z"})),
+            guest(
+                "soon",
+                json!({
+                    "expiresAt": "2024-01-01T12:30:00Z",
+                    "dataUsageLimitMBytes": 100,
+                    "usage": {"bytes": 25000000}
+                }),
+            ),
+            guest(
+                "exhausted",
+                json!({"dataUsageLimitMBytes": 100, "usage": {"bytes": 100000000}}),
+            ),
+            guest("unlimited", json!({"authorizationMethod": "API"})),
+            serde_json::from_value(json!({"id": "wired", "type": "WIRED"})).unwrap(),
+        ];
+
+        let now: DateTime<Utc> = "2024-01-01T12:00:00Z".parse().unwrap();
+        let guests = authorized_guests_at(&clients, now);
+
+        let ids: Vec<&str> = guests.iter().map(|g| g.client.id.as_str()).collect();
+        assert_eq!(ids, vec!["soon", "later", "unlimited"]);
+
+        assert_eq!(guests[0].remaining_time, Some(Duration::minutes(30)));
+        assert_eq!(guests[0].remaining_quota, DataSize::from_mb(75));
+        assert_eq!(guests[1].remaining_quota, None);
+        assert_eq!(guests[2].remaining_time, None);
+    }
+}
@@ -0,0 +1,467 @@
+//! Classic API client endpoints (`stat/sta`, `rest/user`, `cmd/stamgr`).
+
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::models::{IpAddress, MacAddress};
+use crate::response::ApiResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A currently connected client as reported by `stat/sta`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ActiveClient {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub mac: MacAddress,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Vendor name derived from the MAC prefix by the controller.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oui: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<IpAddress>,
+    /// Name of the network the client is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vlan: Option<u16>,
+    #[serde(default)]
+    pub is_wired: bool,
+    #[serde(default)]
+    pub is_guest: bool,
+    /// SSID of the WLAN for wireless clients.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub essid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ap_mac: Option<MacAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sw_mac: Option<MacAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sw_port: Option<u32>,
+    /// Radio type ("ng" for 2.4 GHz, "na" for 5 GHz, "6e" for 6 GHz).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radio: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rssi: Option<i32>,
+    /// Signal strength in dBm.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<i32>,
+    /// Transmit rate in kbps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_rate: Option<u64>,
+    /// Receive rate in kbps.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rx_rate: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rx_bytes: Option<u64>,
+    /// Connection uptime in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uptime: Option<u64>,
+    /// Unix timestamp when the client was first seen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_seen: Option<i64>,
+    /// Unix timestamp when the client was last seen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_fixedip: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_ip: Option<IpAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Whether a guest client has been authorized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorized: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<bool>,
+    /// ID of the matching `rest/user` entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+impl ActiveClient {
+    /// Get the best available name: alias, then hostname, then MAC address.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .or_else(|| self.hostname.clone())
+            .unwrap_or_else(|| self.mac.to_string())
+    }
+
+    /// Check if the client is connected over Wi-Fi.
+    pub fn is_wireless(&self) -> bool {
+        !self.is_wired
+    }
+}
+
+/// A client the site has seen, as stored in `rest/user`.
+///
+/// Unmodeled fields are kept in `extra` so the object can be sent back unchanged.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct KnownClient {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub mac: MacAddress,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oui: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_fixedip: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_ip: Option<IpAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usergroup_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_guest: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_seen: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl KnownClient {
+    /// Check if the client has a DHCP reservation.
+    pub fn has_fixed_ip(&self) -> bool {
+        self.use_fixedip.unwrap_or(false) && self.fixed_ip.is_some()
+    }
+
+    /// Check if the client is blocked.
+    pub fn is_blocked(&self) -> bool {
+        self.blocked.unwrap_or(false)
+    }
+}
+
+/// Fetch the currently connected clients on a site.
+/// Endpoint: GET /api/s/{site}/stat/sta
+#[derive(Debug, Clone)]
+pub struct GetActiveClients {
+    pub site: String,
+}
+
+impl GetActiveClients {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self { site: site.into() }
+    }
+}
+
+impl Endpoint for GetActiveClients {
+    const PATH: &'static str = "api/s/{site}/stat/sta";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<ActiveClient>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/stat/sta", self.site)
+    }
+}
+
+/// Fetch every known client on a site.
+/// Endpoint: GET /api/s/{site}/rest/user
+#[derive(Debug, Clone)]
+pub struct GetKnownClients {
+    pub site: String,
+}
+
+impl GetKnownClients {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self { site: site.into() }
+    }
+}
+
+impl Endpoint for GetKnownClients {
+    const PATH: &'static str = "api/s/{site}/rest/user";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<KnownClient>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/user", self.site)
+    }
+}
+
+/// Request body for updating a known client.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct KnownClientRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub use_fixedip: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixed_ip: Option<IpAddress>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usergroup_id: Option<String>,
+}
+
+impl KnownClientRequest {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the client alias.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Set the admin note.
+    pub fn note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Reserve a fixed IP address for the client on a network.
+    pub fn fixed_ip(mut self, network_id: impl Into<String>, ip: IpAddress) -> Self {
+        self.use_fixedip = Some(true);
+        self.network_id = Some(network_id.into());
+        self.fixed_ip = Some(ip);
+        self
+    }
+
+    /// Remove the client's fixed IP reservation.
+    pub fn clear_fixed_ip(mut self) -> Self {
+        self.use_fixedip = Some(false);
+        self
+    }
+
+    /// Assign the client to a user group (bandwidth profile).
+    pub fn usergroup_id(mut self, usergroup_id: impl Into<String>) -> Self {
+        self.usergroup_id = Some(usergroup_id.into());
+        self
+    }
+}
+
+/// Update a known client.
+/// Endpoint: PUT /api/s/{site}/rest/user/{id}
+#[derive(Debug, Clone)]
+pub struct UpdateKnownClient {
+    pub site: String,
+    pub id: String,
+    pub request: KnownClientRequest,
+}
+
+impl UpdateKnownClient {
+    pub fn new(
+        site: impl Into<String>,
+        id: impl Into<String>,
+        request: KnownClientRequest,
+    ) -> Self {
+        Self {
+            site: site.into(),
+            id: id.into(),
+            request,
+        }
+    }
+}
+
+impl Endpoint for UpdateKnownClient {
+    const PATH: &'static str = "api/s/{site}/rest/user/{id}";
+    const METHOD: HttpMethod = HttpMethod::Put;
+    type Response = ApiResponse<Vec<KnownClient>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/user/{}", self.site, self.id)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.request)?))
+    }
+}
+
+/// Commands accepted by the station manager.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "kebab-case")]
+pub enum ClientCommand {
+    /// Block a client from the network.
+    BlockSta { mac: MacAddress },
+    /// Unblock a previously blocked client.
+    UnblockSta { mac: MacAddress },
+    /// Disconnect a client, forcing it to reconnect.
+    KickSta { mac: MacAddress },
+    /// Remove clients from the known client list.
+    ForgetSta { macs: Vec<MacAddress> },
+    /// Authorize a guest client.
+    AuthorizeGuest {
+        mac: MacAddress,
+        /// Authorization duration in minutes.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        minutes: Option<u32>,
+        /// Upload limit in kbps.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        up: Option<u64>,
+        /// Download limit in kbps.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        down: Option<u64>,
+        /// Data quota in megabytes.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        bytes: Option<u64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        ap_mac: Option<MacAddress>,
+    },
+    /// Revoke a guest client's authorization.
+    UnauthorizeGuest { mac: MacAddress },
+}
+
+/// Send a command to the station manager.
+/// Endpoint: POST /api/s/{site}/cmd/stamgr
+#[derive(Debug, Clone)]
+pub struct StationManagerCommand {
+    pub site: String,
+    pub command: ClientCommand,
+}
+
+impl StationManagerCommand {
+    pub fn new(site: impl Into<String>, command: ClientCommand) -> Self {
+        Self {
+            site: site.into(),
+            command,
+        }
+    }
+}
+
+impl Endpoint for StationManagerCommand {
+    const PATH: &'static str = "api/s/{site}/cmd/stamgr";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<Value>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/cmd/stamgr", self.site)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.command)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_active_client_deserialization() {
+        let json_data = json!({
+            "_id": "5f1a2b3c4d5e6f7a8b9c0d1e",
+            "mac": "AA:BB:CC:DD:EE:FF",
+            "hostname": "laptop",
+            "ip": "192.168.1.50",
+            "network": "LAN",
+            "network_id": "net-1",
+            "is_wired": false,
+            "is_guest": false,
+            "essid": "Home",
+            "ap_mac": "94:2a:6f:26:c6:ca",
+            "radio": "na",
+            "channel": 36,
+            "rssi": 45,
+            "signal": -51,
+            "tx_rate": 866700,
+            "rx_rate": 780000,
+            "tx_bytes": 123456,
+            "rx_bytes": 654321,
+            "uptime": 3600,
+            "first_seen": 1700000000,
+            "last_seen": 1700003600
+        });
+
+        let client: ActiveClient = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(client.mac.to_string(), "aa:bb:cc:dd:ee:ff");
+        assert_eq!(client.display_name(), "laptop");
+        assert!(client.is_wireless());
+        assert_eq!(client.signal, Some(-51));
+        assert_eq!(client.essid.as_deref(), Some("Home"));
+    }
+
+    #[test]
+    fn test_known_client_keeps_unmodeled_fields() {
+        let fixture = json!({
+            "_id": "user-1",
+            "mac": "aa:bb:cc:dd:ee:ff",
+            "name": "Printer",
+            "use_fixedip": true,
+            "fixed_ip": "192.168.1.20",
+            "network_id": "net-1",
+            "site_id": "site-1",
+            "noted": true
+        });
+
+        let client: KnownClient = serde_json::from_value(fixture.clone()).unwrap();
+
+        assert!(client.has_fixed_ip());
+        assert_eq!(client.extra.get("site_id"), Some(&json!("site-1")));
+        assert_eq!(serde_json::to_value(&client).unwrap(), fixture);
+    }
+
+    #[test]
+    fn test_update_known_client_body() {
+        let ip: IpAddress = "192.168.1.20".parse().unwrap();
+        let endpoint = UpdateKnownClient::new(
+            "default",
+            "user-1",
+            KnownClientRequest::new()
+                .name("Printer")
+                .fixed_ip("net-1", ip),
+        );
+
+        assert_eq!(endpoint.build_path(), "api/s/default/rest/user/user-1");
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "name": "Printer",
+                "use_fixedip": true,
+                "network_id": "net-1",
+                "fixed_ip": "192.168.1.20"
+            }))
+        );
+    }
+
+    #[test]
+    fn test_client_command_bodies() {
+        let mac = MacAddress::new("aa:bb:cc:dd:ee:ff").unwrap();
+
+        assert_eq!(
+            serde_json::to_value(ClientCommand::KickSta { mac }).unwrap(),
+            json!({"cmd": "kick-sta", "mac": "aa:bb:cc:dd:ee:ff"})
+        );
+        assert_eq!(
+            serde_json::to_value(ClientCommand::ForgetSta { macs: vec![mac] }).unwrap(),
+            json!({"cmd": "forget-sta", "macs": ["aa:bb:cc:dd:ee:ff"]})
+        );
+        assert_eq!(
+            serde_json::to_value(ClientCommand::AuthorizeGuest {
+                mac,
+                minutes: Some(60),
+                up: None,
+                down: None,
+                bytes: None,
+                ap_mac: None,
+            })
+            .unwrap(),
+            json!({"cmd": "authorize-guest", "mac": "aa:bb:cc:dd:ee:ff", "minutes": 60})
+        );
+        assert_eq!(
+            StationManagerCommand::new("default", ClientCommand::BlockSta { mac }).build_path(),
+            "api/s/default/cmd/stamgr"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+//! Classic API device endpoints (`stat/device`, `cmd/devmgr`).
+
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::models::{Device, MacAddress};
+use crate::response::ApiResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Fetch every adopted device on a site.
+/// Endpoint: GET /api/s/{site}/stat/device
+#[derive(Debug, Clone)]
+pub struct GetDevices {
+    pub site: String,
+}
+
+impl GetDevices {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self { site: site.into() }
+    }
+}
+
+impl Endpoint for GetDevices {
+    const PATH: &'static str = "api/s/{site}/stat/device";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<Device>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/stat/device", self.site)
+    }
+}
+
+/// Fetch a single device by MAC address.
+/// Endpoint: GET /api/s/{site}/stat/device/{mac}
+#[derive(Debug, Clone)]
+pub struct GetDevice {
+    pub site: String,
+    pub mac: MacAddress,
+}
+
+impl GetDevice {
+    pub fn new(site: impl Into<String>, mac: MacAddress) -> Self {
+        Self {
+            site: site.into(),
+            mac,
+        }
+    }
+}
+
+impl Endpoint for GetDevice {
+    const PATH: &'static str = "api/s/{site}/stat/device/{mac}";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<Device>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/stat/device/{}", self.site, self.mac)
+    }
+}
+
+/// Commands accepted by the device manager.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "kebab-case")]
+pub enum DeviceCommand {
+    /// Adopt a pending device.
+    Adopt { mac: MacAddress },
+    /// Reboot a device.
+    Restart { mac: MacAddress },
+    /// Push the current configuration to a device.
+    ForceProvision { mac: MacAddress },
+    /// Upgrade a device to the latest firmware.
+    Upgrade { mac: MacAddress },
+    /// Upgrade a device from a firmware URL.
+    UpgradeExternal { mac: MacAddress, url: String },
+    /// Flash the device's locate LED.
+    SetLocate { mac: MacAddress },
+    /// Stop flashing the device's locate LED.
+    UnsetLocate { mac: MacAddress },
+    /// Power-cycle PoE on a switch port.
+    PowerCycle { mac: MacAddress, port_idx: u32 },
+    /// Start an RF spectrum scan on an access point.
+    SpectrumScan { mac: MacAddress },
+}
+
+impl DeviceCommand {
+    /// Get the MAC address of the target device.
+    pub fn mac(&self) -> &MacAddress {
+        match self {
+            DeviceCommand::Adopt { mac }
+            | DeviceCommand::Restart { mac }
+            | DeviceCommand::ForceProvision { mac }
+            | DeviceCommand::Upgrade { mac }
+            | DeviceCommand::UpgradeExternal { mac, .. }
+            | DeviceCommand::SetLocate { mac }
+            | DeviceCommand::UnsetLocate { mac }
+            | DeviceCommand::PowerCycle { mac, .. }
+            | DeviceCommand::SpectrumScan { mac } => mac,
+        }
+    }
+}
+
+/// Send a command to the device manager.
+/// Endpoint: POST /api/s/{site}/cmd/devmgr
+#[derive(Debug, Clone)]
+pub struct DeviceManagerCommand {
+    pub site: String,
+    pub command: DeviceCommand,
+}
+
+impl DeviceManagerCommand {
+    pub fn new(site: impl Into<String>, command: DeviceCommand) -> Self {
+        Self {
+            site: site.into(),
+            command,
+        }
+    }
+}
+
+impl Endpoint for DeviceManagerCommand {
+    const PATH: &'static str = "api/s/{site}/cmd/devmgr";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<Value>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/cmd/devmgr", self.site)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.command)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn mac() -> MacAddress {
+        MacAddress::new("aa:bb:cc:dd:ee:ff").unwrap()
+    }
+
+    #[test]
+    fn test_device_paths() {
+        assert_eq!(
+            GetDevices::new("default").build_path(),
+            "api/s/default/stat/device"
+        );
+        assert_eq!(
+            GetDevice::new("default", mac()).build_path(),
+            "api/s/default/stat/device/aa:bb:cc:dd:ee:ff"
+        );
+    }
+
+    #[test]
+    fn test_device_command_body() {
+        let endpoint = DeviceManagerCommand::new("default", DeviceCommand::Restart { mac: mac() });
+
+        assert_eq!(endpoint.build_path(), "api/s/default/cmd/devmgr");
+        assert_eq!(DeviceManagerCommand::METHOD, HttpMethod::Post);
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({"cmd": "restart", "mac": "aa:bb:cc:dd:ee:ff"}))
+        );
+    }
+
+    #[test]
+    fn test_power_cycle_command_body() {
+        let command = DeviceCommand::PowerCycle {
+            mac: mac(),
+            port_idx: 7,
+        };
+
+        assert_eq!(command.mac(), &mac());
+        assert_eq!(
+            serde_json::to_value(&command).unwrap(),
+            json!({"cmd": "power-cycle", "mac": "aa:bb:cc:dd:ee:ff", "port_idx": 7})
+        );
+        assert_eq!(
+            serde_json::to_value(DeviceCommand::ForceProvision { mac: mac() }).unwrap()["cmd"],
+            "force-provision"
+        );
+    }
+}
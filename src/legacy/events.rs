@@ -0,0 +1,297 @@
+//! Classic API event and alarm endpoints (`stat/event`, `list/alarm`, `cmd/evtmgr`).
+
+use super::datetime_from_millis;
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::models::MacAddress;
+use crate::response::ApiResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// A controller event (e.g. `EVT_WU_Connected`, `EVT_SW_Lost_Contact`).
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Event {
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Event key (e.g. "EVT_AP_Upgraded").
+    pub key: String,
+    #[serde(default)]
+    pub msg: String,
+    /// Event time in milliseconds since the Unix epoch.
+    pub time: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subsystem: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<String>,
+    /// Client involved in the event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<MacAddress>,
+    /// Guest involved in the event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guest: Option<MacAddress>,
+    /// Access point involved in the event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ap: Option<MacAddress>,
+    /// Switch involved in the event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sw: Option<MacAddress>,
+    /// Gateway involved in the event.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gw: Option<MacAddress>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Event {
+    /// Get the event time.
+    pub fn datetime(&self) -> Option<DateTime<Utc>> {
+        datetime_from_millis(self.time)
+    }
+
+    /// Get the MAC address of the device (AP, switch or gateway) involved.
+    pub fn device_mac(&self) -> Option<&MacAddress> {
+        self.ap.as_ref().or(self.sw.as_ref()).or(self.gw.as_ref())
+    }
+
+    /// Get the MAC address of the client (user or guest) involved.
+    pub fn client_mac(&self) -> Option<&MacAddress> {
+        self.user.as_ref().or(self.guest.as_ref())
+    }
+}
+
+/// An alarm raised by the controller.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Alarm {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub key: String,
+    #[serde(default)]
+    pub msg: String,
+    /// Alarm time in milliseconds since the Unix epoch.
+    pub time: i64,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subsystem: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub site_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Alarm {
+    /// Get the alarm time.
+    pub fn datetime(&self) -> Option<DateTime<Utc>> {
+        datetime_from_millis(self.time)
+    }
+}
+
+/// Fetch recent events, newest first.
+/// Endpoint: POST /api/s/{site}/stat/event
+#[derive(Debug, Clone)]
+pub struct GetEvents {
+    pub site: String,
+    /// Look back this many hours.
+    pub within_hours: Option<u32>,
+    pub start: Option<usize>,
+    pub limit: Option<usize>,
+}
+
+impl GetEvents {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self {
+            site: site.into(),
+            within_hours: None,
+            start: None,
+            limit: None,
+        }
+    }
+
+    pub fn within_hours(mut self, hours: u32) -> Self {
+        self.within_hours = Some(hours);
+        self
+    }
+
+    pub fn start(mut self, start: usize) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+impl Endpoint for GetEvents {
+    const PATH: &'static str = "api/s/{site}/stat/event";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<Event>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/stat/event", self.site)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        let mut body = Map::new();
+        body.insert("_sort".to_string(), json!("-time"));
+        if let Some(hours) = self.within_hours {
+            body.insert("within".to_string(), json!(hours));
+        }
+        if let Some(start) = self.start {
+            body.insert("_start".to_string(), json!(start));
+        }
+        if let Some(limit) = self.limit {
+            body.insert("_limit".to_string(), json!(limit));
+        }
+        Ok(Some(Value::Object(body)))
+    }
+}
+
+/// Fetch alarms, optionally filtered by archive state.
+/// Endpoint: GET /api/s/{site}/list/alarm
+#[derive(Debug, Clone)]
+pub struct GetAlarms {
+    pub site: String,
+    pub archived: Option<bool>,
+}
+
+impl GetAlarms {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self {
+            site: site.into(),
+            archived: None,
+        }
+    }
+
+    /// Only return alarms that are (`true`) or are not (`false`) archived.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+impl Endpoint for GetAlarms {
+    const PATH: &'static str = "api/s/{site}/list/alarm";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<Alarm>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/list/alarm", self.site)
+    }
+
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        match self.archived {
+            Some(archived) => vec![("archived", archived.to_string())],
+            None => vec![],
+        }
+    }
+}
+
+/// Commands accepted by the event manager.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "kebab-case")]
+pub enum EventCommand {
+    /// Archive a single alarm.
+    ArchiveAlarm {
+        #[serde(rename = "_id")]
+        id: String,
+    },
+    /// Archive every alarm on the site.
+    ArchiveAllAlarms,
+}
+
+/// Send a command to the event manager.
+/// Endpoint: POST /api/s/{site}/cmd/evtmgr
+#[derive(Debug, Clone)]
+pub struct EventManagerCommand {
+    pub site: String,
+    pub command: EventCommand,
+}
+
+impl EventManagerCommand {
+    pub fn new(site: impl Into<String>, command: EventCommand) -> Self {
+        Self {
+            site: site.into(),
+            command,
+        }
+    }
+}
+
+impl Endpoint for EventManagerCommand {
+    const PATH: &'static str = "api/s/{site}/cmd/evtmgr";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<Value>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/cmd/evtmgr", self.site)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.command)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_deserialization() {
+        let json_data = json!({
+            "_id": "evt-1",
+            "key": "EVT_WU_Connected",
+            "msg": "User[aa:bb:cc:dd:ee:01] has connected to AP[aa:bb:cc:dd:ee:02]",
+            "time": 1700000000000_i64,
+            "subsystem": "wlan",
+            "user": "aa:bb:cc:dd:ee:01",
+            "ap": "aa:bb:cc:dd:ee:02",
+            "ssid": "Home"
+        });
+
+        let event: Event = serde_json::from_value(json_data.clone()).unwrap();
+
+        assert_eq!(
+            event.datetime().unwrap().to_rfc3339(),
+            "2023-11-14T22:13:20+00:00"
+        );
+        assert_eq!(event.client_mac().unwrap().to_string(), "aa:bb:cc:dd:ee:01");
+        assert_eq!(event.device_mac().unwrap().to_string(), "aa:bb:cc:dd:ee:02");
+        assert_eq!(serde_json::to_value(&event).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_get_events_body() {
+        let endpoint = GetEvents::new("default").within_hours(24).limit(100);
+
+        assert_eq!(endpoint.build_path(), "api/s/default/stat/event");
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({"_sort": "-time", "within": 24, "_limit": 100}))
+        );
+    }
+
+    #[test]
+    fn test_alarm_archive() {
+        let endpoint = GetAlarms::new("default").archived(false);
+        assert_eq!(
+            endpoint.query_params(),
+            vec![("archived", "false".to_string())]
+        );
+
+        let command = EventManagerCommand::new(
+            "default",
+            EventCommand::ArchiveAlarm {
+                id: "alarm-1".to_string(),
+            },
+        );
+        assert_eq!(
+            command.request_body().unwrap(),
+            Some(json!({"cmd": "archive-alarm", "_id": "alarm-1"}))
+        );
+        assert_eq!(
+            serde_json::to_value(EventCommand::ArchiveAllAlarms).unwrap(),
+            json!({"cmd": "archive-all-alarms"})
+        );
+    }
+}
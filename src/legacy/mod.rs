@@ -0,0 +1,260 @@
+//! Classic UniFi Network controller API.
+//!
+//! The Integration API (`/v1/sites/...`) does not yet cover everything the
+//! controller can manage. This module targets the older, undocumented Network
+//! API used by the controller web UI:
+//!
+//! - `stat/device`, `cmd/devmgr` for devices ([`devices`])
+//! - `stat/sta`, `rest/user`, `cmd/stamgr` for clients ([`clients`])
+//! - `list/wlanconf`, `rest/wlanconf` for WLANs ([`wlans`])
+//! - `rest/networkconf` for networks ([`networks`])
+//! - `rest/device`, `rest/portconf` for switch ports and port profiles ([`ports`])
+//! - `stat/health`, `stat/rogueap` for site health and RF neighbors ([`stats`])
+//! - `stat/event`, `list/alarm`, `cmd/evtmgr` for events and alarms ([`events`])
+//! - `stat/report/{interval}.{scope}` for historical time series ([`reports`])
+//!
+//! Paths are relative to the client's base path and take the site *name*
+//! (e.g. `"default"`), not the Integration API site ID. On UniFi OS consoles use
+//! a base path of `/proxy/network`; on standalone controllers use `/`.
+//!
+//! Every response uses the `{"meta": {"rc": "ok"}, "data": [...]}` envelope
+//! ([`ApiResponse`]). The helpers on [`UnifiClient`] check the result code and
+//! return [`Error::Api`](crate::Error::Api) when it is not `"ok"`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::legacy::devices::DeviceCommand;
+//! use rustifi::models::MacAddress;
+//! use rustifi::UnifiClient;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! let client =
+//!     UnifiClient::with_base_path_and_key("https://192.168.1.1", "/proxy/network", "api-key")?;
+//!
+//! for device in client.legacy_devices("default").await? {
+//!     println!("{} ({})", device.name, device.mac);
+//! }
+//!
+//! let mac = MacAddress::new("aa:bb:cc:dd:ee:ff")?;
+//! client
+//!     .legacy_device_command("default", DeviceCommand::Restart { mac })
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+pub mod clients;
+pub mod devices;
+pub mod events;
+pub mod networks;
+pub mod ports;
+pub mod reports;
+pub mod stats;
+pub mod wlans;
+
+pub use clients::{ActiveClient, ClientCommand, KnownClient};
+pub use devices::DeviceCommand;
+pub use events::{Alarm, Event, EventCommand};
+pub use networks::NetworkConf;
+pub use ports::{LinkSpeed, PoeMode, PortForward, PortOverride, PortProfile, PortSettings};
+pub use reports::{ReportAttribute, ReportEntry, ReportInterval, ReportScope};
+pub use stats::{RogueAp, SubsystemHealth};
+pub use wlans::WlanConf;
+
+use crate::api::endpoint::Endpoint;
+use crate::error::{Error, Result};
+use crate::models::{Device, MacAddress};
+use crate::response::ApiResponse;
+use crate::UnifiClient;
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Convert a classic API timestamp (milliseconds since the Unix epoch).
+pub(crate) fn datetime_from_millis(millis: i64) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp_millis(millis)
+}
+
+/// Extension methods for UnifiClient to use the classic controller API.
+impl UnifiClient {
+    /// Execute a classic API endpoint and unwrap its `{meta, data}` envelope.
+    ///
+    /// Returns [`Error::Api`](crate::Error::Api) if `meta.rc` is not `"ok"`.
+    pub async fn execute_legacy<E, T>(&self, endpoint: &E) -> Result<T>
+    where
+        E: Endpoint<Response = ApiResponse<T>>,
+        T: for<'a> serde::Deserialize<'a>,
+    {
+        self.execute(endpoint).await?.into_result()
+    }
+
+    /// Fetch every adopted device on a site (`stat/device`).
+    pub async fn legacy_devices(&self, site: &str) -> Result<Vec<Device>> {
+        self.execute_legacy(&devices::GetDevices::new(site)).await
+    }
+
+    /// Fetch the currently connected clients on a site (`stat/sta`).
+    pub async fn legacy_active_clients(&self, site: &str) -> Result<Vec<ActiveClient>> {
+        self.execute_legacy(&clients::GetActiveClients::new(site))
+            .await
+    }
+
+    /// Fetch every client the site has seen, including offline ones (`rest/user`).
+    pub async fn legacy_known_clients(&self, site: &str) -> Result<Vec<KnownClient>> {
+        self.execute_legacy(&clients::GetKnownClients::new(site))
+            .await
+    }
+
+    /// Fetch the WLAN configurations of a site (`list/wlanconf`).
+    pub async fn legacy_wlans(&self, site: &str) -> Result<Vec<WlanConf>> {
+        self.execute_legacy(&wlans::GetWlanConfs::new(site)).await
+    }
+
+    /// Fetch the network configurations of a site (`rest/networkconf`).
+    pub async fn legacy_networks(&self, site: &str) -> Result<Vec<NetworkConf>> {
+        self.execute_legacy(&networks::GetNetworkConfs::new(site))
+            .await
+    }
+
+    /// Fetch the port profiles of a site (`rest/portconf`).
+    pub async fn legacy_port_profiles(&self, site: &str) -> Result<Vec<PortProfile>> {
+        self.execute_legacy(&ports::GetPortProfiles::new(site))
+            .await
+    }
+
+    /// Change the settings of individual switch ports (`rest/device`).
+    ///
+    /// The changes are merged into the switch's current port overrides; ports
+    /// and fields not mentioned are left as they are. Returns the resulting
+    /// overrides.
+    ///
+    /// ```no_run
+    /// use rustifi::legacy::{PoeMode, PortOverride, PortSettings};
+    /// use rustifi::models::MacAddress;
+    /// use rustifi::UnifiClient;
+    ///
+    /// # async fn example(client: UnifiClient) -> rustifi::Result<()> {
+    /// let mac = MacAddress::new("aa:bb:cc:dd:ee:ff")?;
+    /// let changes = vec![
+    ///     PortOverride::new(3).settings(PortSettings::new().enabled(false)),
+    ///     PortOverride::new(4).settings(
+    ///         PortSettings::new()
+    ///             .native_network("iot-network-id")
+    ///             .poe_mode(PoeMode::Off),
+    ///     ),
+    /// ];
+    /// client.legacy_configure_ports("default", mac, changes).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn legacy_configure_ports(
+        &self,
+        site: &str,
+        mac: MacAddress,
+        changes: Vec<PortOverride>,
+    ) -> Result<Vec<PortOverride>> {
+        let mut device = self
+            .execute_legacy(&ports::GetDevicePorts::new(site, mac))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::NotFound(format!("Device {} not found", mac)))?;
+
+        device.apply(changes);
+        let endpoint = ports::UpdateDevicePorts::new(site, &device.id, device.port_overrides);
+        let updated = self.execute_legacy(&endpoint).await?;
+        Ok(updated
+            .into_iter()
+            .next()
+            .map(|device| device.port_overrides)
+            .unwrap_or(endpoint.port_overrides))
+    }
+
+    /// Send a device manager command (`cmd/devmgr`).
+    pub async fn legacy_device_command(
+        &self,
+        site: &str,
+        command: DeviceCommand,
+    ) -> Result<Vec<Value>> {
+        self.execute_legacy(&devices::DeviceManagerCommand::new(site, command))
+            .await
+    }
+
+    /// Send a station manager command (`cmd/stamgr`).
+    pub async fn legacy_client_command(
+        &self,
+        site: &str,
+        command: ClientCommand,
+    ) -> Result<Vec<Value>> {
+        self.execute_legacy(&clients::StationManagerCommand::new(site, command))
+            .await
+    }
+
+    /// Fetch the health of every site subsystem (`stat/health`).
+    pub async fn legacy_health(&self, site: &str) -> Result<Vec<SubsystemHealth>> {
+        self.execute_legacy(&stats::GetHealth::new(site)).await
+    }
+
+    /// Fetch events from the last `within_hours` hours, newest first (`stat/event`).
+    pub async fn legacy_events(&self, site: &str, within_hours: u32) -> Result<Vec<Event>> {
+        self.execute_legacy(&events::GetEvents::new(site).within_hours(within_hours))
+            .await
+    }
+
+    /// Fetch alarms, optionally filtered by archive state (`list/alarm`).
+    pub async fn legacy_alarms(&self, site: &str, archived: Option<bool>) -> Result<Vec<Alarm>> {
+        let mut endpoint = events::GetAlarms::new(site);
+        endpoint.archived = archived;
+        self.execute_legacy(&endpoint).await
+    }
+
+    /// Archive a single alarm (`cmd/evtmgr`).
+    pub async fn legacy_archive_alarm(&self, site: &str, alarm_id: &str) -> Result<()> {
+        let command = EventCommand::ArchiveAlarm {
+            id: alarm_id.to_string(),
+        };
+        self.execute_legacy(&events::EventManagerCommand::new(site, command))
+            .await
+            .map(|_| ())
+    }
+
+    /// Archive every alarm on a site (`cmd/evtmgr`).
+    pub async fn legacy_archive_all_alarms(&self, site: &str) -> Result<()> {
+        self.execute_legacy(&events::EventManagerCommand::new(
+            site,
+            EventCommand::ArchiveAllAlarms,
+        ))
+        .await
+        .map(|_| ())
+    }
+
+    /// Fetch neighboring and rogue access points seen in the last `within_hours` hours (`stat/rogueap`).
+    pub async fn legacy_rogue_aps(&self, site: &str, within_hours: u32) -> Result<Vec<RogueAp>> {
+        self.execute_legacy(&stats::GetRogueAps::new(site).within_hours(within_hours))
+            .await
+    }
+
+    /// Fetch a time-series report (`stat/report`).
+    ///
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    /// use rustifi::legacy::reports::GetReport;
+    /// use rustifi::legacy::{ReportAttribute, ReportInterval, ReportScope};
+    /// use rustifi::UnifiClient;
+    ///
+    /// # async fn example(client: UnifiClient) -> rustifi::Result<()> {
+    /// let end = Utc::now();
+    /// let report = GetReport::new("default", ReportInterval::Daily, ReportScope::Site)
+    ///     .attributes([ReportAttribute::WanRxBytes, ReportAttribute::WanTxBytes])
+    ///     .range(end - Duration::days(30), end);
+    ///
+    /// for entry in client.legacy_report(&report).await? {
+    ///     println!("{:?}: {:?}", entry.datetime(), entry.wan_rx_bytes);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn legacy_report(&self, report: &reports::GetReport) -> Result<Vec<ReportEntry>> {
+        self.execute_legacy(report).await
+    }
+}
@@ -0,0 +1,252 @@
+//! Classic API network endpoints (`rest/networkconf`).
+
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::response::ApiResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Network configuration.
+///
+/// Unmodeled fields are kept in `extra` so the object can be sent back unchanged.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct NetworkConf {
+    #[serde(rename = "_id", default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub name: String,
+    /// Network purpose ("corporate", "guest", "wan", "vlan-only", "remote-user-vpn", ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vlan_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vlan: Option<u16>,
+    /// Gateway address and prefix in CIDR notation (e.g. "192.168.1.1/24").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_subnet: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub networkgroup: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dhcpd_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dhcpd_start: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dhcpd_stop: Option<String>,
+    /// DHCP lease time in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dhcpd_leasetime: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub igmp_snooping: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl NetworkConf {
+    /// Create a new network configuration with a name and purpose.
+    pub fn new(name: impl Into<String>, purpose: impl Into<String>) -> Self {
+        Self {
+            id: String::new(),
+            name: name.into(),
+            purpose: Some(purpose.into()),
+            enabled: Some(true),
+            vlan_enabled: None,
+            vlan: None,
+            ip_subnet: None,
+            networkgroup: None,
+            dhcpd_enabled: None,
+            dhcpd_start: None,
+            dhcpd_stop: None,
+            dhcpd_leasetime: None,
+            domain_name: None,
+            igmp_snooping: None,
+            extra: Map::new(),
+        }
+    }
+
+    /// Check if this is a WAN network.
+    pub fn is_wan(&self) -> bool {
+        self.purpose.as_deref() == Some("wan")
+    }
+
+    /// Get the VLAN ID, if VLAN tagging is enabled.
+    pub fn vlan_id(&self) -> Option<u16> {
+        if self.vlan_enabled.unwrap_or(self.vlan.is_some()) {
+            self.vlan
+        } else {
+            None
+        }
+    }
+}
+
+/// Fetch the network configurations of a site.
+/// Endpoint: GET /api/s/{site}/rest/networkconf
+#[derive(Debug, Clone)]
+pub struct GetNetworkConfs {
+    pub site: String,
+}
+
+impl GetNetworkConfs {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self { site: site.into() }
+    }
+}
+
+impl Endpoint for GetNetworkConfs {
+    const PATH: &'static str = "api/s/{site}/rest/networkconf";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<NetworkConf>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/networkconf", self.site)
+    }
+}
+
+/// Create a network.
+/// Endpoint: POST /api/s/{site}/rest/networkconf
+#[derive(Debug, Clone)]
+pub struct CreateNetworkConf {
+    pub site: String,
+    pub network: NetworkConf,
+}
+
+impl CreateNetworkConf {
+    pub fn new(site: impl Into<String>, network: NetworkConf) -> Self {
+        Self {
+            site: site.into(),
+            network,
+        }
+    }
+}
+
+impl Endpoint for CreateNetworkConf {
+    const PATH: &'static str = "api/s/{site}/rest/networkconf";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<NetworkConf>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/networkconf", self.site)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.network)?))
+    }
+}
+
+/// Update a network.
+/// Endpoint: PUT /api/s/{site}/rest/networkconf/{id}
+#[derive(Debug, Clone)]
+pub struct UpdateNetworkConf {
+    pub site: String,
+    pub network: NetworkConf,
+}
+
+impl UpdateNetworkConf {
+    pub fn new(site: impl Into<String>, network: NetworkConf) -> Self {
+        Self {
+            site: site.into(),
+            network,
+        }
+    }
+}
+
+impl Endpoint for UpdateNetworkConf {
+    const PATH: &'static str = "api/s/{site}/rest/networkconf/{id}";
+    const METHOD: HttpMethod = HttpMethod::Put;
+    type Response = ApiResponse<Vec<NetworkConf>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/networkconf/{}", self.site, self.network.id)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.network)?))
+    }
+}
+
+/// Delete a network.
+/// Endpoint: DELETE /api/s/{site}/rest/networkconf/{id}
+#[derive(Debug, Clone)]
+pub struct DeleteNetworkConf {
+    pub site: String,
+    pub id: String,
+}
+
+impl DeleteNetworkConf {
+    pub fn new(site: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            site: site.into(),
+            id: id.into(),
+        }
+    }
+}
+
+impl Endpoint for DeleteNetworkConf {
+    const PATH: &'static str = "api/s/{site}/rest/networkconf/{id}";
+    const METHOD: HttpMethod = HttpMethod::Delete;
+    type Response = ApiResponse<Vec<Value>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/networkconf/{}", self.site, self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_network_conf_deserialization() {
+        let json_data = json!({
+            "meta": {"rc": "ok"},
+            "data": [{
+                "_id": "net-1",
+                "name": "IoT",
+                "purpose": "corporate",
+                "vlan_enabled": true,
+                "vlan": 20,
+                "ip_subnet": "10.0.20.1/24",
+                "dhcpd_enabled": true,
+                "dhcpd_start": "10.0.20.100",
+                "dhcpd_stop": "10.0.20.200",
+                "dhcpd_leasetime": 86400,
+                "site_id": "site-1"
+            }]
+        });
+
+        let response: ApiResponse<Vec<NetworkConf>> = serde_json::from_value(json_data).unwrap();
+        let networks = response.into_result().unwrap();
+
+        assert_eq!(networks[0].vlan_id(), Some(20));
+        assert!(!networks[0].is_wan());
+        assert_eq!(networks[0].extra.get("site_id"), Some(&json!("site-1")));
+    }
+
+    #[test]
+    fn test_create_network_conf_omits_id() {
+        let mut network = NetworkConf::new("Guests", "guest");
+        network.vlan = Some(30);
+        network.vlan_enabled = Some(true);
+
+        let endpoint = CreateNetworkConf::new("default", network);
+        assert_eq!(endpoint.build_path(), "api/s/default/rest/networkconf");
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "name": "Guests",
+                "purpose": "guest",
+                "enabled": true,
+                "vlan_enabled": true,
+                "vlan": 30
+            }))
+        );
+
+        assert_eq!(
+            DeleteNetworkConf::new("default", "net-1").build_path(),
+            "api/s/default/rest/networkconf/net-1"
+        );
+    }
+}
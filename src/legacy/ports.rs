@@ -0,0 +1,591 @@
+//! Classic API switch port endpoints (`rest/device`, `rest/portconf`).
+//!
+//! Per-port settings are stored on the switch as a list of *port overrides*.
+//! Each override may assign a port profile ([`PortProfile`]) and override
+//! individual [`PortSettings`] on top of it. The controller replaces the whole
+//! list on update, so [`DevicePorts::apply`] merges changes into the current
+//! overrides before they are sent back.
+
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::models::units::{self, Bandwidth};
+use crate::models::MacAddress;
+use crate::response::ApiResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// PoE output mode of a switch port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PoeMode {
+    /// 802.3af/at/bt negotiated PoE.
+    Auto,
+    /// PoE disabled.
+    Off,
+    /// Passive 24V PoE.
+    #[serde(rename = "pasv24")]
+    Passive24V,
+    /// Pass through the PoE input (PoE-powered switches only).
+    Passthrough,
+}
+
+/// VLAN forwarding mode of a switch port.
+///
+/// The controller also uses this field for the port's admin state: a
+/// `Disabled` port does not forward any traffic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PortForward {
+    /// Native network untagged, every other network tagged.
+    All,
+    /// Native network only.
+    Native,
+    /// Native network untagged, selected networks tagged.
+    Customize,
+    /// Port disabled.
+    Disabled,
+}
+
+/// Link speed and duplex of a switch port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkSpeed {
+    /// Autonegotiate speed and duplex.
+    Auto,
+    /// Fixed speed and duplex.
+    Fixed { speed: Bandwidth, full_duplex: bool },
+}
+
+/// Settings shared by port overrides and port profiles.
+///
+/// Every field is optional; unset fields are inherited from the assigned port
+/// profile or the controller default.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct PortSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forward: Option<PortForward>,
+    /// ID of the untagged (native) network.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_networkconf_id: Option<String>,
+    /// IDs of the tagged networks, used when `forward` is `Customize`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tagged_networkconf_ids: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poe_mode: Option<PoeMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub autoneg: Option<bool>,
+    /// Fixed link speed (sent in Mbps), used when `autoneg` is false.
+    #[serde(
+        default,
+        with = "units::serde::mbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub speed: Option<Bandwidth>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub full_duplex: Option<bool>,
+}
+
+impl PortSettings {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable the port.
+    ///
+    /// Enabling a disabled port sets it to forward all networks.
+    pub fn enabled(mut self, enabled: bool) -> Self {
+        if !enabled {
+            self.forward = Some(PortForward::Disabled);
+        } else if self.forward.is_none_or(|f| f == PortForward::Disabled) {
+            self.forward = Some(PortForward::All);
+        }
+        self
+    }
+
+    /// Set the untagged (native) network.
+    pub fn native_network(mut self, networkconf_id: impl Into<String>) -> Self {
+        self.native_networkconf_id = Some(networkconf_id.into());
+        self
+    }
+
+    /// Tag only the given networks on the port.
+    pub fn tagged_networks<I, S>(mut self, networkconf_ids: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forward = Some(PortForward::Customize);
+        self.tagged_networkconf_ids = Some(networkconf_ids.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Carry only the native network.
+    pub fn native_only(mut self) -> Self {
+        self.forward = Some(PortForward::Native);
+        self.tagged_networkconf_ids = None;
+        self
+    }
+
+    pub fn poe_mode(mut self, mode: PoeMode) -> Self {
+        self.poe_mode = Some(mode);
+        self
+    }
+
+    pub fn link_speed(mut self, link_speed: LinkSpeed) -> Self {
+        match link_speed {
+            LinkSpeed::Auto => {
+                self.autoneg = Some(true);
+                self.speed = None;
+                self.full_duplex = None;
+            }
+            LinkSpeed::Fixed { speed, full_duplex } => {
+                self.autoneg = Some(false);
+                self.speed = Some(speed);
+                self.full_duplex = Some(full_duplex);
+            }
+        }
+        self
+    }
+
+    /// Check if the port is administratively enabled.
+    ///
+    /// Returns `None` if the admin state is inherited.
+    pub fn is_enabled(&self) -> Option<bool> {
+        self.forward.map(|f| f != PortForward::Disabled)
+    }
+
+    /// Get the configured link speed, if it is set.
+    pub fn get_link_speed(&self) -> Option<LinkSpeed> {
+        match (self.autoneg, self.speed) {
+            (Some(true), _) => Some(LinkSpeed::Auto),
+            (_, Some(speed)) => Some(LinkSpeed::Fixed {
+                speed,
+                full_duplex: self.full_duplex.unwrap_or(true),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Overlay the fields set in `other`.
+    pub fn merge(&mut self, other: PortSettings) {
+        if other.forward.is_some() {
+            self.forward = other.forward;
+        }
+        if other.native_networkconf_id.is_some() {
+            self.native_networkconf_id = other.native_networkconf_id;
+        }
+        if other.tagged_networkconf_ids.is_some() {
+            self.tagged_networkconf_ids = other.tagged_networkconf_ids;
+        }
+        if other.poe_mode.is_some() {
+            self.poe_mode = other.poe_mode;
+        }
+        if other.autoneg.is_some() {
+            self.autoneg = other.autoneg;
+            // Speed and duplex are only meaningful together with autoneg
+            self.speed = other.speed;
+            self.full_duplex = other.full_duplex;
+        }
+    }
+}
+
+/// Per-port configuration of a switch.
+///
+/// Unmodeled fields are kept in `extra` so the object can be sent back unchanged.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct PortOverride {
+    /// 1-based port number.
+    pub port_idx: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// ID of the assigned port profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub portconf_id: Option<String>,
+    #[serde(flatten)]
+    pub settings: PortSettings,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl PortOverride {
+    pub fn new(port_idx: u32) -> Self {
+        Self {
+            port_idx,
+            name: None,
+            portconf_id: None,
+            settings: PortSettings::default(),
+            extra: Map::new(),
+        }
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Assign a port profile.
+    pub fn profile(mut self, portconf_id: impl Into<String>) -> Self {
+        self.portconf_id = Some(portconf_id.into());
+        self
+    }
+
+    pub fn settings(mut self, settings: PortSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Overlay the fields set in `other`.
+    pub fn merge(&mut self, other: PortOverride) {
+        if other.name.is_some() {
+            self.name = other.name;
+        }
+        if other.portconf_id.is_some() {
+            self.portconf_id = other.portconf_id;
+        }
+        self.settings.merge(other.settings);
+        self.extra.extend(other.extra);
+    }
+}
+
+/// The port configuration of a switch.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct DevicePorts {
+    #[serde(rename = "_id")]
+    pub id: String,
+    pub mac: MacAddress,
+    #[serde(default)]
+    pub port_overrides: Vec<PortOverride>,
+}
+
+impl DevicePorts {
+    /// Get the override for a port, if it has one.
+    pub fn port(&self, port_idx: u32) -> Option<&PortOverride> {
+        self.port_overrides.iter().find(|p| p.port_idx == port_idx)
+    }
+
+    /// Merge changes into the current overrides, adding overrides for ports
+    /// that have none. The list is kept sorted by port number.
+    pub fn apply(&mut self, changes: impl IntoIterator<Item = PortOverride>) {
+        for change in changes {
+            match self
+                .port_overrides
+                .iter_mut()
+                .find(|p| p.port_idx == change.port_idx)
+            {
+                Some(existing) => existing.merge(change),
+                None => self.port_overrides.push(change),
+            }
+        }
+        self.port_overrides.sort_by_key(|p| p.port_idx);
+    }
+}
+
+/// Fetch the port configuration of a switch.
+/// Endpoint: GET /api/s/{site}/stat/device/{mac}
+#[derive(Debug, Clone)]
+pub struct GetDevicePorts {
+    pub site: String,
+    pub mac: MacAddress,
+}
+
+impl GetDevicePorts {
+    pub fn new(site: impl Into<String>, mac: MacAddress) -> Self {
+        Self {
+            site: site.into(),
+            mac,
+        }
+    }
+}
+
+impl Endpoint for GetDevicePorts {
+    const PATH: &'static str = "api/s/{site}/stat/device/{mac}";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<DevicePorts>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/stat/device/{}", self.site, self.mac)
+    }
+}
+
+/// Replace the port overrides of a switch.
+/// Endpoint: PUT /api/s/{site}/rest/device/{id}
+#[derive(Debug, Clone)]
+pub struct UpdateDevicePorts {
+    pub site: String,
+    /// Device `_id` (not the MAC address).
+    pub device_id: String,
+    pub port_overrides: Vec<PortOverride>,
+}
+
+impl UpdateDevicePorts {
+    pub fn new(
+        site: impl Into<String>,
+        device_id: impl Into<String>,
+        port_overrides: Vec<PortOverride>,
+    ) -> Self {
+        Self {
+            site: site.into(),
+            device_id: device_id.into(),
+            port_overrides,
+        }
+    }
+}
+
+impl Endpoint for UpdateDevicePorts {
+    const PATH: &'static str = "api/s/{site}/rest/device/{id}";
+    const METHOD: HttpMethod = HttpMethod::Put;
+    type Response = ApiResponse<Vec<DevicePorts>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/device/{}", self.site, self.device_id)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(json!({ "port_overrides": self.port_overrides })))
+    }
+}
+
+/// Port profile, a reusable set of port settings.
+///
+/// Unmodeled fields are kept in `extra` so the object can be sent back unchanged.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct PortProfile {
+    #[serde(rename = "_id", default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub settings: PortSettings,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl PortProfile {
+    pub fn new(name: impl Into<String>, settings: PortSettings) -> Self {
+        Self {
+            id: String::new(),
+            name: name.into(),
+            settings,
+            extra: Map::new(),
+        }
+    }
+}
+
+/// Fetch the port profiles of a site.
+/// Endpoint: GET /api/s/{site}/rest/portconf
+#[derive(Debug, Clone)]
+pub struct GetPortProfiles {
+    pub site: String,
+}
+
+impl GetPortProfiles {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self { site: site.into() }
+    }
+}
+
+impl Endpoint for GetPortProfiles {
+    const PATH: &'static str = "api/s/{site}/rest/portconf";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<PortProfile>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/portconf", self.site)
+    }
+}
+
+/// Create a port profile.
+/// Endpoint: POST /api/s/{site}/rest/portconf
+#[derive(Debug, Clone)]
+pub struct CreatePortProfile {
+    pub site: String,
+    pub profile: PortProfile,
+}
+
+impl CreatePortProfile {
+    pub fn new(site: impl Into<String>, profile: PortProfile) -> Self {
+        Self {
+            site: site.into(),
+            profile,
+        }
+    }
+}
+
+impl Endpoint for CreatePortProfile {
+    const PATH: &'static str = "api/s/{site}/rest/portconf";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<PortProfile>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/portconf", self.site)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.profile)?))
+    }
+}
+
+/// Update a port profile.
+/// Endpoint: PUT /api/s/{site}/rest/portconf/{id}
+#[derive(Debug, Clone)]
+pub struct UpdatePortProfile {
+    pub site: String,
+    pub profile: PortProfile,
+}
+
+impl UpdatePortProfile {
+    pub fn new(site: impl Into<String>, profile: PortProfile) -> Self {
+        Self {
+            site: site.into(),
+            profile,
+        }
+    }
+}
+
+impl Endpoint for UpdatePortProfile {
+    const PATH: &'static str = "api/s/{site}/rest/portconf/{id}";
+    const METHOD: HttpMethod = HttpMethod::Put;
+    type Response = ApiResponse<Vec<PortProfile>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/portconf/{}", self.site, self.profile.id)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.profile)?))
+    }
+}
+
+/// Delete a port profile.
+/// Endpoint: DELETE /api/s/{site}/rest/portconf/{id}
+#[derive(Debug, Clone)]
+pub struct DeletePortProfile {
+    pub site: String,
+    pub id: String,
+}
+
+impl DeletePortProfile {
+    pub fn new(site: impl Into<String>, id: impl Into<String>) -> Self {
+        Self {
+            site: site.into(),
+            id: id.into(),
+        }
+    }
+}
+
+impl Endpoint for DeletePortProfile {
+    const PATH: &'static str = "api/s/{site}/rest/portconf/{id}";
+    const METHOD: HttpMethod = HttpMethod::Delete;
+    type Response = ApiResponse<Vec<Value>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/portconf/{}", self.site, self.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_port_override_round_trip() {
+        let json_data = json!({
+            "port_idx": 3,
+            "name": "Camera",
+            "forward": "customize",
+            "native_networkconf_id": "net-1",
+            "tagged_networkconf_ids": ["net-2", "net-3"],
+            "poe_mode": "pasv24",
+            "autoneg": false,
+            "speed": 100,
+            "full_duplex": true,
+            "stormctrl_enabled": false
+        });
+
+        let port: PortOverride = serde_json::from_value(json_data.clone()).unwrap();
+        assert_eq!(port.settings.poe_mode, Some(PoeMode::Passive24V));
+        assert_eq!(port.settings.is_enabled(), Some(true));
+        assert_eq!(
+            port.settings.get_link_speed(),
+            Some(LinkSpeed::Fixed {
+                speed: Bandwidth::from_mbps(100).unwrap(),
+                full_duplex: true
+            })
+        );
+        assert_eq!(port.extra.len(), 1);
+        assert_eq!(serde_json::to_value(&port).unwrap(), json_data);
+    }
+
+    #[test]
+    fn test_device_ports_apply() {
+        let mut device: DevicePorts = serde_json::from_value(json!({
+            "_id": "dev-1",
+            "mac": "aa:bb:cc:dd:ee:ff",
+            "model": "US8P150",
+            "port_overrides": [
+                {"port_idx": 5, "name": "Uplink", "portconf_id": "all"},
+                {"port_idx": 2, "poe_mode": "off", "autoneg": false, "speed": 100}
+            ]
+        }))
+        .unwrap();
+
+        device.apply([
+            PortOverride::new(2).settings(
+                PortSettings::new()
+                    .enabled(false)
+                    .link_speed(LinkSpeed::Auto),
+            ),
+            PortOverride::new(1).settings(PortSettings::new().poe_mode(PoeMode::Auto)),
+        ]);
+
+        let endpoint = UpdateDevicePorts::new("default", &device.id, device.port_overrides);
+        assert_eq!(endpoint.build_path(), "api/s/default/rest/device/dev-1");
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "port_overrides": [
+                    {"port_idx": 1, "poe_mode": "auto"},
+                    {"port_idx": 2, "forward": "disabled", "poe_mode": "off", "autoneg": true},
+                    {"port_idx": 5, "name": "Uplink", "portconf_id": "all"}
+                ]
+            }))
+        );
+    }
+
+    #[test]
+    fn test_port_settings_enabled() {
+        let disabled = PortSettings::new().native_only().enabled(false);
+        assert_eq!(disabled.is_enabled(), Some(false));
+        assert_eq!(disabled.enabled(true).forward, Some(PortForward::All));
+
+        let tagged = PortSettings::new().tagged_networks(["net-2"]).enabled(true);
+        assert_eq!(tagged.forward, Some(PortForward::Customize));
+        assert_eq!(PortSettings::new().is_enabled(), None);
+    }
+
+    #[test]
+    fn test_create_port_profile() {
+        let profile = PortProfile::new(
+            "Cameras",
+            PortSettings::new()
+                .native_network("net-4")
+                .native_only()
+                .poe_mode(PoeMode::Auto),
+        );
+
+        let endpoint = CreatePortProfile::new("default", profile);
+        assert_eq!(endpoint.build_path(), "api/s/default/rest/portconf");
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "name": "Cameras",
+                "forward": "native",
+                "native_networkconf_id": "net-4",
+                "poe_mode": "auto"
+            }))
+        );
+
+        assert_eq!(
+            DeletePortProfile::new("default", "pc-1").build_path(),
+            "api/s/default/rest/portconf/pc-1"
+        );
+    }
+}
@@ -0,0 +1,381 @@
+//! Classic API time-series reports (`stat/report/{interval}.{scope}`).
+
+use super::datetime_from_millis;
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::models::MacAddress;
+use crate::response::ApiResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+use std::fmt;
+
+/// Sampling interval of a report.
+///
+/// The controller only retains a limited history per interval (by default about
+/// a day of 5-minute samples, a week of hourly samples and a year of daily samples).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportInterval {
+    FiveMinutes,
+    Hourly,
+    Daily,
+}
+
+impl ReportInterval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportInterval::FiveMinutes => "5minutes",
+            ReportInterval::Hourly => "hourly",
+            ReportInterval::Daily => "daily",
+        }
+    }
+}
+
+impl fmt::Display for ReportInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// What a report is aggregated over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportScope {
+    /// The whole site.
+    Site,
+    /// Individual clients.
+    User,
+    /// Individual access points.
+    Ap,
+}
+
+impl ReportScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportScope::Site => "site",
+            ReportScope::User => "user",
+            ReportScope::Ap => "ap",
+        }
+    }
+
+    /// Attributes requested when none are selected explicitly.
+    pub fn default_attributes(&self) -> &'static [ReportAttribute] {
+        match self {
+            ReportScope::Site => &[
+                ReportAttribute::Bytes,
+                ReportAttribute::WanTxBytes,
+                ReportAttribute::WanRxBytes,
+                ReportAttribute::WlanBytes,
+                ReportAttribute::NumSta,
+                ReportAttribute::LanNumSta,
+                ReportAttribute::WlanNumSta,
+            ],
+            ReportScope::User => &[ReportAttribute::RxBytes, ReportAttribute::TxBytes],
+            ReportScope::Ap => &[ReportAttribute::Bytes, ReportAttribute::NumSta],
+        }
+    }
+}
+
+impl fmt::Display for ReportScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An attribute that can be selected in a report.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReportAttribute {
+    #[serde(rename = "bytes")]
+    Bytes,
+    #[serde(rename = "rx_bytes")]
+    RxBytes,
+    #[serde(rename = "tx_bytes")]
+    TxBytes,
+    #[serde(rename = "wan-rx_bytes")]
+    WanRxBytes,
+    #[serde(rename = "wan-tx_bytes")]
+    WanTxBytes,
+    #[serde(rename = "wlan_bytes")]
+    WlanBytes,
+    #[serde(rename = "num_sta")]
+    NumSta,
+    #[serde(rename = "lan-num_sta")]
+    LanNumSta,
+    #[serde(rename = "wlan-num_sta")]
+    WlanNumSta,
+    #[serde(rename = "cpu")]
+    Cpu,
+    #[serde(rename = "mem")]
+    Mem,
+    #[serde(rename = "loadavg_5")]
+    LoadAvg5,
+}
+
+impl ReportAttribute {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReportAttribute::Bytes => "bytes",
+            ReportAttribute::RxBytes => "rx_bytes",
+            ReportAttribute::TxBytes => "tx_bytes",
+            ReportAttribute::WanRxBytes => "wan-rx_bytes",
+            ReportAttribute::WanTxBytes => "wan-tx_bytes",
+            ReportAttribute::WlanBytes => "wlan_bytes",
+            ReportAttribute::NumSta => "num_sta",
+            ReportAttribute::LanNumSta => "lan-num_sta",
+            ReportAttribute::WlanNumSta => "wlan-num_sta",
+            ReportAttribute::Cpu => "cpu",
+            ReportAttribute::Mem => "mem",
+            ReportAttribute::LoadAvg5 => "loadavg_5",
+        }
+    }
+}
+
+impl fmt::Display for ReportAttribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A single sample of a report.
+///
+/// Only the selected attributes are present.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ReportEntry {
+    /// Start of the sample in milliseconds since the Unix epoch.
+    pub time: i64,
+    /// ID of the reported object (site ID, client MAC or AP MAC).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user: Option<MacAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ap: Option<MacAddress>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rx_bytes: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_bytes: Option<f64>,
+    #[serde(
+        rename = "wan-rx_bytes",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub wan_rx_bytes: Option<f64>,
+    #[serde(
+        rename = "wan-tx_bytes",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub wan_tx_bytes: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wlan_bytes: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_sta: Option<f64>,
+    #[serde(
+        rename = "lan-num_sta",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub lan_num_sta: Option<f64>,
+    #[serde(
+        rename = "wlan-num_sta",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub wlan_num_sta: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mem: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loadavg_5: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ReportEntry {
+    /// Get the start of the sample.
+    pub fn datetime(&self) -> Option<DateTime<Utc>> {
+        datetime_from_millis(self.time)
+    }
+
+    /// Get the value of an attribute.
+    pub fn get(&self, attribute: ReportAttribute) -> Option<f64> {
+        match attribute {
+            ReportAttribute::Bytes => self.bytes,
+            ReportAttribute::RxBytes => self.rx_bytes,
+            ReportAttribute::TxBytes => self.tx_bytes,
+            ReportAttribute::WanRxBytes => self.wan_rx_bytes,
+            ReportAttribute::WanTxBytes => self.wan_tx_bytes,
+            ReportAttribute::WlanBytes => self.wlan_bytes,
+            ReportAttribute::NumSta => self.num_sta,
+            ReportAttribute::LanNumSta => self.lan_num_sta,
+            ReportAttribute::WlanNumSta => self.wlan_num_sta,
+            ReportAttribute::Cpu => self.cpu,
+            ReportAttribute::Mem => self.mem,
+            ReportAttribute::LoadAvg5 => self.loadavg_5,
+        }
+    }
+}
+
+/// Fetch a time-series report.
+/// Endpoint: POST /api/s/{site}/stat/report/{interval}.{scope}
+///
+/// Without a time range the controller returns its default window for the
+/// interval (e.g. the last 12 hours for 5-minute reports).
+#[derive(Debug, Clone)]
+pub struct GetReport {
+    pub site: String,
+    pub interval: ReportInterval,
+    pub scope: ReportScope,
+    pub attributes: Vec<ReportAttribute>,
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    /// Restrict `user` and `ap` reports to these MAC addresses.
+    pub macs: Vec<MacAddress>,
+}
+
+impl GetReport {
+    pub fn new(site: impl Into<String>, interval: ReportInterval, scope: ReportScope) -> Self {
+        Self {
+            site: site.into(),
+            interval,
+            scope,
+            attributes: Vec::new(),
+            start: None,
+            end: None,
+            macs: Vec::new(),
+        }
+    }
+
+    /// Select the attributes to report. Defaults to [`ReportScope::default_attributes`].
+    pub fn attributes(mut self, attributes: impl IntoIterator<Item = ReportAttribute>) -> Self {
+        self.attributes = attributes.into_iter().collect();
+        self
+    }
+
+    /// Report samples between `start` and `end`.
+    pub fn range(mut self, start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        self.start = Some(start);
+        self.end = Some(end);
+        self
+    }
+
+    pub fn start(mut self, start: DateTime<Utc>) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    pub fn end(mut self, end: DateTime<Utc>) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    pub fn mac(mut self, mac: MacAddress) -> Self {
+        self.macs.push(mac);
+        self
+    }
+}
+
+impl Endpoint for GetReport {
+    const PATH: &'static str = "api/s/{site}/stat/report/{interval}.{scope}";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<ReportEntry>>;
+
+    fn build_path(&self) -> String {
+        format!(
+            "api/s/{}/stat/report/{}.{}",
+            self.site, self.interval, self.scope
+        )
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        let attributes = if self.attributes.is_empty() {
+            self.scope.default_attributes()
+        } else {
+            &self.attributes
+        };
+        let mut attrs: Vec<&str> = attributes.iter().map(ReportAttribute::as_str).collect();
+        attrs.push("time");
+
+        let mut body = Map::new();
+        body.insert("attrs".to_string(), json!(attrs));
+        if let Some(start) = self.start {
+            body.insert("start".to_string(), json!(start.timestamp_millis()));
+        }
+        if let Some(end) = self.end {
+            body.insert("end".to_string(), json!(end.timestamp_millis()));
+        }
+        if !self.macs.is_empty() {
+            body.insert("macs".to_string(), serde_json::to_value(&self.macs)?);
+        }
+        Ok(Some(Value::Object(body)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_report_path_and_body() {
+        let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap();
+        let endpoint = GetReport::new("default", ReportInterval::Hourly, ReportScope::Ap)
+            .attributes([ReportAttribute::Bytes, ReportAttribute::Cpu])
+            .range(start, end)
+            .mac(MacAddress::new("aa:bb:cc:dd:ee:ff").unwrap());
+
+        assert_eq!(endpoint.build_path(), "api/s/default/stat/report/hourly.ap");
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({
+                "attrs": ["bytes", "cpu", "time"],
+                "start": 1704067200000_i64,
+                "end": 1704153600000_i64,
+                "macs": ["aa:bb:cc:dd:ee:ff"]
+            }))
+        );
+    }
+
+    #[test]
+    fn test_report_default_attributes() {
+        let endpoint = GetReport::new("default", ReportInterval::FiveMinutes, ReportScope::User);
+
+        assert_eq!(
+            endpoint.build_path(),
+            "api/s/default/stat/report/5minutes.user"
+        );
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({"attrs": ["rx_bytes", "tx_bytes", "time"]}))
+        );
+    }
+
+    #[test]
+    fn test_report_entry_deserialization() {
+        let json_data = json!({
+            "meta": {"rc": "ok"},
+            "data": [{
+                "time": 1704067200000_i64,
+                "oid": "site-1",
+                "site": "site-1",
+                "bytes": 1.5e9,
+                "wan-tx_bytes": 123456,
+                "wlan-num_sta": 17
+            }]
+        });
+
+        let response: ApiResponse<Vec<ReportEntry>> = serde_json::from_value(json_data).unwrap();
+        let entries = response.into_result().unwrap();
+
+        assert_eq!(
+            entries[0].datetime().unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()
+        );
+        assert_eq!(entries[0].get(ReportAttribute::Bytes), Some(1.5e9));
+        assert_eq!(entries[0].wan_tx_bytes, Some(123456.0));
+        assert_eq!(entries[0].get(ReportAttribute::WlanNumSta), Some(17.0));
+        assert_eq!(entries[0].get(ReportAttribute::Cpu), None);
+    }
+}
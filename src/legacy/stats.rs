@@ -0,0 +1,265 @@
+//! Classic API health and RF environment endpoints (`stat/health`, `stat/rogueap`).
+
+use super::datetime_from_millis;
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::models::MacAddress;
+use crate::response::ApiResponse;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// Subsystem reported by `stat/health`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Subsystem {
+    Wan,
+    Www,
+    Lan,
+    Wlan,
+    Vpn,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Health status of a subsystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    Ok,
+    Warning,
+    Error,
+    /// The subsystem is not configured (e.g. no VPN).
+    Unknown,
+    #[serde(other)]
+    Other,
+}
+
+/// Health of a single site subsystem.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct SubsystemHealth {
+    pub subsystem: Subsystem,
+    pub status: HealthStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_user: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_guest: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_ap: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_sw: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_gw: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_adopted: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_disconnected: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub num_pending: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wan_ip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub isp_name: Option<String>,
+    /// Internet latency in milliseconds (`www` subsystem).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency: Option<f64>,
+    /// Receive rate in bytes per second.
+    #[serde(
+        rename = "rx_bytes-r",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rx_bytes_rate: Option<f64>,
+    /// Transmit rate in bytes per second.
+    #[serde(
+        rename = "tx_bytes-r",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tx_bytes_rate: Option<f64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl SubsystemHealth {
+    /// Check if the subsystem is healthy.
+    pub fn is_ok(&self) -> bool {
+        self.status == HealthStatus::Ok
+    }
+}
+
+/// Fetch the health of every site subsystem.
+/// Endpoint: GET /api/s/{site}/stat/health
+#[derive(Debug, Clone)]
+pub struct GetHealth {
+    pub site: String,
+}
+
+impl GetHealth {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self { site: site.into() }
+    }
+}
+
+impl Endpoint for GetHealth {
+    const PATH: &'static str = "api/s/{site}/stat/health";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<SubsystemHealth>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/stat/health", self.site)
+    }
+}
+
+/// A neighboring access point seen by one of the site's APs.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct RogueAp {
+    pub bssid: MacAddress,
+    #[serde(default)]
+    pub essid: String,
+    /// MAC address of the site AP that heard this one.
+    pub ap_mac: MacAddress,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<u32>,
+    /// Frequency in MHz.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub freq: Option<u32>,
+    /// Radio ("ng", "na", "6e").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radio: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rssi: Option<i32>,
+    /// Signal strength in dBm.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal: Option<i32>,
+    /// Noise floor in dBm.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub noise: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security: Option<String>,
+    #[serde(default)]
+    pub is_rogue: bool,
+    #[serde(default)]
+    pub is_ubnt: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oui: Option<String>,
+    /// Last time the AP was seen, in seconds since the Unix epoch.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl RogueAp {
+    /// Get the last time the AP was seen.
+    pub fn last_seen_at(&self) -> Option<DateTime<Utc>> {
+        self.last_seen
+            .and_then(|secs| datetime_from_millis(secs.checked_mul(1000)?))
+    }
+}
+
+/// Fetch neighboring and rogue access points.
+/// Endpoint: POST /api/s/{site}/stat/rogueap
+#[derive(Debug, Clone)]
+pub struct GetRogueAps {
+    pub site: String,
+    /// Look back this many hours.
+    pub within_hours: Option<u32>,
+}
+
+impl GetRogueAps {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self {
+            site: site.into(),
+            within_hours: None,
+        }
+    }
+
+    pub fn within_hours(mut self, hours: u32) -> Self {
+        self.within_hours = Some(hours);
+        self
+    }
+}
+
+impl Endpoint for GetRogueAps {
+    const PATH: &'static str = "api/s/{site}/stat/rogueap";
+    const METHOD: HttpMethod = HttpMethod::Post;
+    type Response = ApiResponse<Vec<RogueAp>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/stat/rogueap", self.site)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(self.within_hours.map(|hours| json!({ "within": hours })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_health_deserialization() {
+        let json_data = json!({
+            "meta": {"rc": "ok"},
+            "data": [
+                {
+                    "subsystem": "wlan",
+                    "status": "ok",
+                    "num_user": 12,
+                    "num_ap": 3,
+                    "num_adopted": 3,
+                    "num_disconnected": 0,
+                    "rx_bytes-r": 1024.5,
+                    "tx_bytes-r": 2048
+                },
+                {
+                    "subsystem": "www",
+                    "status": "warning",
+                    "latency": 42,
+                    "speedtest_status": "Idle"
+                },
+                {"subsystem": "vpn", "status": "unknown"},
+                {"subsystem": "sdwan", "status": "ok"}
+            ]
+        });
+
+        let response: ApiResponse<Vec<SubsystemHealth>> =
+            serde_json::from_value(json_data).unwrap();
+        let health = response.into_result().unwrap();
+
+        assert_eq!(health[0].subsystem, Subsystem::Wlan);
+        assert!(health[0].is_ok());
+        assert_eq!(health[0].rx_bytes_rate, Some(1024.5));
+        assert_eq!(health[1].status, HealthStatus::Warning);
+        assert_eq!(health[1].latency, Some(42.0));
+        assert_eq!(health[2].status, HealthStatus::Unknown);
+        assert_eq!(health[3].subsystem, Subsystem::Unknown);
+    }
+
+    #[test]
+    fn test_rogue_ap() {
+        let json_data = json!({
+            "bssid": "11:22:33:44:55:66",
+            "essid": "Neighbor",
+            "ap_mac": "aa:bb:cc:dd:ee:ff",
+            "channel": 36,
+            "freq": 5180,
+            "radio": "na",
+            "signal": -71,
+            "is_rogue": false,
+            "last_seen": 1700000000
+        });
+
+        let rogue: RogueAp = serde_json::from_value(json_data).unwrap();
+        assert_eq!(rogue.bssid.to_string(), "11:22:33:44:55:66");
+        assert_eq!(rogue.last_seen_at().unwrap().timestamp(), 1700000000);
+
+        let endpoint = GetRogueAps::new("default").within_hours(12);
+        assert_eq!(endpoint.build_path(), "api/s/default/stat/rogueap");
+        assert_eq!(
+            endpoint.request_body().unwrap(),
+            Some(json!({"within": 12}))
+        );
+    }
+}
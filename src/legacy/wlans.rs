@@ -0,0 +1,154 @@
+//! Classic API WLAN endpoints (`list/wlanconf`, `rest/wlanconf`).
+
+use crate::api::endpoint::{Endpoint, HttpMethod};
+use crate::response::ApiResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// WLAN (SSID) configuration.
+///
+/// Unmodeled fields are kept in `extra` so the object can be sent back unchanged.
+#[derive(Clone, Deserialize, Serialize, PartialEq)]
+pub struct WlanConf {
+    #[serde(rename = "_id", default, skip_serializing_if = "String::is_empty")]
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub enabled: bool,
+    /// Security mode ("open", "wpapsk", "wpaeap").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security: Option<String>,
+    /// WPA version ("wpa2", "wpa3", ...).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wpa_mode: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x_passphrase: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub networkconf_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usergroup_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_guest: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hide_ssid: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wlan_band: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl WlanConf {
+    /// Check if the WLAN is open (no authentication).
+    pub fn is_open(&self) -> bool {
+        self.security.as_deref() == Some("open")
+    }
+}
+
+impl fmt::Debug for WlanConf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WlanConf")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("enabled", &self.enabled)
+            .field("security", &self.security)
+            .field("wpa_mode", &self.wpa_mode)
+            .field(
+                "x_passphrase",
+                &self.x_passphrase.as_ref().map(|_| "<redacted>"),
+            )
+            .field("networkconf_id", &self.networkconf_id)
+            .field("usergroup_id", &self.usergroup_id)
+            .field("is_guest", &self.is_guest)
+            .field("hide_ssid", &self.hide_ssid)
+            .field("wlan_band", &self.wlan_band)
+            .field("extra", &self.extra)
+            .finish()
+    }
+}
+
+/// Fetch the WLAN configurations of a site.
+/// Endpoint: GET /api/s/{site}/list/wlanconf
+#[derive(Debug, Clone)]
+pub struct GetWlanConfs {
+    pub site: String,
+}
+
+impl GetWlanConfs {
+    pub fn new(site: impl Into<String>) -> Self {
+        Self { site: site.into() }
+    }
+}
+
+impl Endpoint for GetWlanConfs {
+    const PATH: &'static str = "api/s/{site}/list/wlanconf";
+    const METHOD: HttpMethod = HttpMethod::Get;
+    type Response = ApiResponse<Vec<WlanConf>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/list/wlanconf", self.site)
+    }
+}
+
+/// Update a WLAN configuration.
+/// Endpoint: PUT /api/s/{site}/rest/wlanconf/{id}
+#[derive(Debug, Clone)]
+pub struct UpdateWlanConf {
+    pub site: String,
+    pub wlan: WlanConf,
+}
+
+impl UpdateWlanConf {
+    pub fn new(site: impl Into<String>, wlan: WlanConf) -> Self {
+        Self {
+            site: site.into(),
+            wlan,
+        }
+    }
+}
+
+impl Endpoint for UpdateWlanConf {
+    const PATH: &'static str = "api/s/{site}/rest/wlanconf/{id}";
+    const METHOD: HttpMethod = HttpMethod::Put;
+    type Response = ApiResponse<Vec<WlanConf>>;
+
+    fn build_path(&self) -> String {
+        format!("api/s/{}/rest/wlanconf/{}", self.site, self.wlan.id)
+    }
+
+    fn request_body(&self) -> Result<Option<Value>, serde_json::Error> {
+        Ok(Some(serde_json::to_value(&self.wlan)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_wlan_conf_round_trip_and_redaction() {
+        let fixture = json!({
+            "_id": "wlan-1",
+            "name": "Home",
+            "enabled": true,
+            "security": "wpapsk",
+            "wpa_mode": "wpa2",
+            "x_passphrase": "supersecret",
+            "networkconf_id": "net-1",
+            "minrate_ng_enabled": false
+        });
+
+        let wlan: WlanConf = serde_json::from_value(fixture.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&wlan).unwrap(), fixture);
+        assert!(!format!("{:?}", wlan).contains("supersecret"));
+
+        let endpoint = UpdateWlanConf::new("default", wlan);
+        assert_eq!(endpoint.build_path(), "api/s/default/rest/wlanconf/wlan-1");
+        assert_eq!(
+            GetWlanConfs::new("default").build_path(),
+            "api/s/default/list/wlanconf"
+        );
+    }
+}
@@ -1,21 +1,31 @@
 pub mod api;
 pub mod client;
 pub mod error;
+pub mod firewall;
+pub mod firmware;
+pub mod guests;
+pub mod legacy;
+pub mod lint;
 pub mod models;
+pub mod multisite;
 pub mod pagination;
+pub mod ppsk;
 pub mod response;
 pub mod stats;
+pub mod vouchers;
 pub mod wrappers;
 
 pub use client::{UnifiClient, REMOTE_API_URL};
 pub use error::{Error, Result};
+pub use guests::AuthorizedGuest;
+pub use multisite::{SiteResults, SiteTagged};
 pub use pagination::DEFAULT_PAGE_SIZE;
 pub use stats::{aggregate_clients_by_device, get_device_client_stats, DeviceClientStats};
 pub use wrappers::DeviceWithInfo;
 
 pub mod prelude {
     pub use crate::api::networks::{Network, NetworkRequest};
-    pub use crate::api::Endpoint;
+    pub use crate::api::{Endpoint, Paginated};
     pub use crate::client::UnifiClient;
     pub use crate::error::{Error, Result};
     pub use crate::models::{
@@ -0,0 +1,898 @@
+//! Firewall policy and ACL rule analysis.
+//!
+//! [`RuleLinter`] reviews a rule base for mistakes that the controller accepts
+//! silently: rules that can never match because an earlier rule covers them,
+//! duplicates, allow/block conflicts, references to missing zones or traffic
+//! lists, and rules left disabled for a long time.
+//!
+//! Rule coverage is worked out from the rules alone. Network, traffic list,
+//! client group and device references are compared by ID, so two different
+//! lists with the same entries are treated as unrelated. Values this crate does
+//! not recognize are compared as written.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::lint::Severity;
+//! use rustifi::UnifiClient;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+//!
+//! let report = client.lint_firewall_policies("site-id").await?;
+//! for finding in report.at_least(Severity::Warning) {
+//!     println!("{:?} {} {}: {}", finding.severity, finding.code, finding.rule_id, finding.message);
+//! }
+//! if let Some(reorder) = report.reorder_firewall("site-id") {
+//!     println!("Proposed order: {:?}", reorder.policy_ids);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::acl::{AclAction, AclRule, AclTarget, GetAclRules, ReorderAclRules};
+use crate::api::firewall::{GetFirewallPolicies, GetFirewallZones, ReorderFirewallPolicies};
+use crate::api::traffic::{GetTrafficLists, TrafficList};
+use crate::error::Result;
+use crate::models::firewall::{
+    AddressMatch, FirewallActionType, FirewallPolicy, FirewallZone, IpVersion, PortMatch, Protocol,
+};
+use crate::UnifiClient;
+use chrono::{DateTime, Duration, Utc};
+use std::fmt;
+use std::net::IpAddr;
+
+/// Disabled rules untouched for this long are reported by default.
+const DEFAULT_STALE_AFTER_DAYS: i64 = 90;
+
+/// How serious a finding is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Worth a look, but often intended.
+    Info,
+    /// Dead weight in the rule base.
+    Warning,
+    /// A rule that does not do what it says.
+    Error,
+}
+
+/// The kind of problem a finding reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintCode {
+    /// An earlier rule with a different action matches everything this rule
+    /// does, so this rule never takes effect.
+    Shadowed,
+    /// An earlier rule with the same action matches everything this rule does.
+    Redundant,
+    /// Same match and action as an earlier rule.
+    Duplicate,
+    /// Overlaps an earlier rule with a different action, and neither covers
+    /// the other. The outcome for the overlap depends on rule order.
+    Conflict,
+    /// References a zone or traffic list that does not exist.
+    MissingReference,
+    /// Disabled and unchanged for a long time.
+    StaleDisabled,
+}
+
+impl LintCode {
+    /// Get the stable identifier of the code, e.g. `"shadowed"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LintCode::Shadowed => "shadowed",
+            LintCode::Redundant => "redundant",
+            LintCode::Duplicate => "duplicate",
+            LintCode::Conflict => "conflict",
+            LintCode::MissingReference => "missing-reference",
+            LintCode::StaleDisabled => "stale-disabled",
+        }
+    }
+
+    pub fn severity(&self) -> Severity {
+        match self {
+            LintCode::Shadowed | LintCode::MissingReference => Severity::Error,
+            LintCode::Redundant | LintCode::Duplicate => Severity::Warning,
+            LintCode::Conflict | LintCode::StaleDisabled => Severity::Info,
+        }
+    }
+}
+
+impl fmt::Display for LintCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A problem found in a rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub code: LintCode,
+    /// ID of the rule the finding is about.
+    pub rule_id: String,
+    /// ID of the earlier rule involved, for shadowing, duplicates and
+    /// conflicts.
+    pub related_rule_id: Option<String>,
+    pub message: String,
+}
+
+impl Finding {
+    fn new(code: LintCode, rule_id: &str, message: String) -> Self {
+        Self {
+            severity: code.severity(),
+            code,
+            rule_id: rule_id.to_string(),
+            related_rule_id: None,
+            message,
+        }
+    }
+
+    fn related(mut self, rule_id: &str) -> Self {
+        self.related_rule_id = Some(rule_id.to_string());
+        self
+    }
+}
+
+/// The kind of rules a report covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RuleKind {
+    FirewallPolicy,
+    AclRule,
+}
+
+/// The result of linting a rule base.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintReport {
+    pub kind: RuleKind,
+    /// Findings in rule order.
+    pub findings: Vec<Finding>,
+    /// Rule IDs in an order that puts shadowed rules ahead of the rules
+    /// covering them, or `None` if no rule needs to move. Moving a rule
+    /// changes what traffic it decides, so review before applying.
+    pub proposed_order: Option<Vec<String>>,
+}
+
+impl LintReport {
+    /// Check if the report has no findings.
+    pub fn is_clean(&self) -> bool {
+        self.findings.is_empty()
+    }
+
+    /// Get the findings of at least the given severity.
+    pub fn at_least(&self, severity: Severity) -> impl Iterator<Item = &Finding> {
+        self.findings.iter().filter(move |f| f.severity >= severity)
+    }
+
+    /// Get the findings about one rule.
+    pub fn for_rule<'a>(&'a self, rule_id: &'a str) -> impl Iterator<Item = &'a Finding> {
+        self.findings.iter().filter(move |f| f.rule_id == rule_id)
+    }
+
+    /// Get the most serious severity in the report.
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.findings.iter().map(|f| f.severity).max()
+    }
+
+    /// Build the endpoint that applies the proposed order, if this report
+    /// covers firewall policies.
+    pub fn reorder_firewall(&self, site_id: &str) -> Option<ReorderFirewallPolicies> {
+        match self.kind {
+            RuleKind::FirewallPolicy => self
+                .proposed_order
+                .as_ref()
+                .map(|ids| ReorderFirewallPolicies::new(site_id, ids.clone())),
+            RuleKind::AclRule => None,
+        }
+    }
+
+    /// Build the endpoint that applies the proposed order, if this report
+    /// covers ACL rules.
+    pub fn reorder_acl(&self, site_id: &str) -> Option<ReorderAclRules> {
+        match self.kind {
+            RuleKind::AclRule => self
+                .proposed_order
+                .as_ref()
+                .map(|ids| ReorderAclRules::new(site_id, ids.clone())),
+            RuleKind::FirewallPolicy => None,
+        }
+    }
+}
+
+/// Lints firewall policies and ACL rules.
+///
+/// Missing zones are only reported when zones are supplied, and missing
+/// traffic lists only when traffic lists are.
+#[derive(Debug, Clone)]
+pub struct RuleLinter<'a> {
+    zones: Option<&'a [FirewallZone]>,
+    traffic_lists: Option<&'a [TrafficList]>,
+    stale_after: Duration,
+    now: DateTime<Utc>,
+}
+
+impl Default for RuleLinter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> RuleLinter<'a> {
+    pub fn new() -> Self {
+        Self {
+            zones: None,
+            traffic_lists: None,
+            stale_after: Duration::days(DEFAULT_STALE_AFTER_DAYS),
+            now: Utc::now(),
+        }
+    }
+
+    /// Check zone references against these zones.
+    pub fn zones(mut self, zones: &'a [FirewallZone]) -> Self {
+        self.zones = Some(zones);
+        self
+    }
+
+    /// Check traffic list references against these lists.
+    pub fn traffic_lists(mut self, lists: &'a [TrafficList]) -> Self {
+        self.traffic_lists = Some(lists);
+        self
+    }
+
+    /// Report disabled rules unchanged for at least this long. Defaults to
+    /// 90 days.
+    pub fn stale_after(mut self, age: Duration) -> Self {
+        self.stale_after = age;
+        self
+    }
+
+    /// Evaluate rule age as of this time instead of now.
+    pub fn now(mut self, now: DateTime<Utc>) -> Self {
+        self.now = now;
+        self
+    }
+
+    /// Lint firewall policies.
+    pub fn lint_firewall(&self, policies: &[FirewallPolicy]) -> LintReport {
+        let ordered = by_order(policies, |p| p.order);
+        let mut findings = Vec::new();
+
+        for policy in &ordered {
+            self.check_references(policy, &mut findings);
+            if !policy.enabled {
+                self.check_stale(&policy.id, policy.updated_at.as_deref(), &mut findings);
+            }
+        }
+
+        let enabled: Vec<&FirewallPolicy> = ordered.iter().copied().filter(|p| p.enabled).collect();
+        let moves = compare_rules(&enabled, &mut findings, |policy| FirewallMatch {
+            policy,
+            blocks: blocks(&policy.action.action_type),
+        });
+
+        LintReport {
+            kind: RuleKind::FirewallPolicy,
+            findings,
+            proposed_order: propose_order(&ordered, |p| &p.id, &moves),
+        }
+    }
+
+    /// Lint ACL rules.
+    pub fn lint_acl(&self, rules: &[AclRule]) -> LintReport {
+        let ordered = by_order(rules, |r| r.order);
+        let mut findings = Vec::new();
+
+        for rule in &ordered {
+            if !rule.enabled {
+                self.check_stale(&rule.id, rule.updated_at.as_deref(), &mut findings);
+            }
+        }
+
+        let enabled: Vec<&AclRule> = ordered.iter().copied().filter(|r| r.enabled).collect();
+        let moves = compare_rules(&enabled, &mut findings, |rule| AclMatch { rule });
+
+        LintReport {
+            kind: RuleKind::AclRule,
+            findings,
+            proposed_order: propose_order(&ordered, |r| &r.id, &moves),
+        }
+    }
+
+    fn check_references(&self, policy: &FirewallPolicy, findings: &mut Vec<Finding>) {
+        if let Some(zones) = self.zones {
+            for zone_id in [&policy.source_zone_id, &policy.destination_zone_id]
+                .into_iter()
+                .flatten()
+            {
+                if !zones.iter().any(|z| &z.id == zone_id) {
+                    findings.push(Finding::new(
+                        LintCode::MissingReference,
+                        &policy.id,
+                        format!("references missing zone '{}'", zone_id),
+                    ));
+                }
+            }
+        }
+
+        if let Some(lists) = self.traffic_lists {
+            let addresses = policy
+                .source_addresses
+                .iter()
+                .chain(&policy.destination_addresses)
+                .filter_map(|m| match m {
+                    AddressMatch::TrafficList(id) => Some(id),
+                    _ => None,
+                });
+            let ports = policy
+                .source_ports
+                .iter()
+                .chain(&policy.destination_ports)
+                .filter_map(|m| match m {
+                    PortMatch::TrafficList(id) => Some(id),
+                    _ => None,
+                });
+            for list_id in addresses.chain(ports) {
+                if !lists.iter().any(|l| &l.id == list_id) {
+                    findings.push(Finding::new(
+                        LintCode::MissingReference,
+                        &policy.id,
+                        format!("references missing traffic list '{}'", list_id),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn check_stale(
+        &self,
+        rule_id: &str,
+        updated_at: Option<&DateTime<Utc>>,
+        findings: &mut Vec<Finding>,
+    ) {
+        if let Some(updated_at) = updated_at {
+            let age = self.now - *updated_at;
+            if age >= self.stale_after {
+                findings.push(Finding::new(
+                    LintCode::StaleDisabled,
+                    rule_id,
+                    format!("disabled and unchanged for {} days", age.num_days()),
+                ));
+            }
+        }
+    }
+}
+
+/// The match conditions of a rule, for comparison with other rules.
+trait RuleMatch {
+    fn id(&self) -> &str;
+    /// Whether the rule blocks traffic, as opposed to allowing it.
+    fn blocks(&self) -> bool;
+    /// Check if this rule matches everything `other` matches.
+    fn covers(&self, other: &Self) -> bool;
+    /// Check if some traffic could match both rules.
+    fn overlaps(&self, other: &Self) -> bool;
+
+    fn same_action(&self, other: &Self) -> bool {
+        self.blocks() == other.blocks()
+    }
+
+    fn equals(&self, other: &Self) -> bool {
+        self.covers(other) && other.covers(self)
+    }
+}
+
+/// Compare every rule with the rules before it. Returns the shadowed rules
+/// that should move, with the rule they should move ahead of.
+fn compare_rules<'r, T, M: RuleMatch>(
+    rules: &[&'r T],
+    findings: &mut Vec<Finding>,
+    to_match: impl Fn(&'r T) -> M,
+) -> Vec<(String, String)> {
+    let matches: Vec<M> = rules.iter().map(|r| to_match(r)).collect();
+    let mut moves = Vec::new();
+
+    for (i, rule) in matches.iter().enumerate() {
+        let earlier = &matches[..i];
+        if let Some(first) = earlier.iter().find(|e| e.covers(rule)) {
+            let finding = if first.equals(rule) && first.same_action(rule) {
+                Finding::new(
+                    LintCode::Duplicate,
+                    rule.id(),
+                    format!("duplicates '{}'", first.id()),
+                )
+            } else if first.same_action(rule) {
+                Finding::new(
+                    LintCode::Redundant,
+                    rule.id(),
+                    format!("already matched by '{}' with the same action", first.id()),
+                )
+            } else {
+                if !first.equals(rule) {
+                    moves.push((rule.id().to_string(), first.id().to_string()));
+                }
+                Finding::new(
+                    LintCode::Shadowed,
+                    rule.id(),
+                    format!(
+                        "never takes effect; '{}' matches the same traffic first",
+                        first.id()
+                    ),
+                )
+            };
+            findings.push(finding.related(first.id()));
+            continue;
+        }
+
+        for other in earlier {
+            if !other.same_action(rule) && other.overlaps(rule) && !rule.covers(other) {
+                findings.push(
+                    Finding::new(
+                        LintCode::Conflict,
+                        rule.id(),
+                        format!(
+                            "partly overlaps '{}', which has the opposite action",
+                            other.id()
+                        ),
+                    )
+                    .related(other.id()),
+                );
+            }
+        }
+    }
+    moves
+}
+
+/// Move each shadowed rule just ahead of the rule shadowing it.
+fn propose_order<T>(
+    ordered: &[&T],
+    id: impl Fn(&T) -> &String,
+    moves: &[(String, String)],
+) -> Option<Vec<String>> {
+    if moves.is_empty() {
+        return None;
+    }
+    let mut order: Vec<String> = ordered.iter().map(|r| id(r).clone()).collect();
+    for (rule, before) in moves {
+        let Some(from) = order.iter().position(|id| id == rule) else {
+            continue;
+        };
+        let moved = order.remove(from);
+        let to = order.iter().position(|id| id == before).unwrap_or(from);
+        order.insert(to, moved);
+    }
+    Some(order)
+}
+
+/// Sort rules by `order`, with unordered rules last.
+fn by_order<T>(rules: &[T], order: impl Fn(&T) -> Option<i32>) -> Vec<&T> {
+    let mut ordered: Vec<&T> = rules.iter().collect();
+    ordered.sort_by_key(|r| order(r).unwrap_or(i32::MAX));
+    ordered
+}
+
+fn blocks(action: &FirewallActionType) -> bool {
+    !matches!(action, FirewallActionType::Allow)
+}
+
+struct FirewallMatch<'a> {
+    policy: &'a FirewallPolicy,
+    blocks: bool,
+}
+
+impl RuleMatch for FirewallMatch<'_> {
+    fn id(&self) -> &str {
+        &self.policy.id
+    }
+
+    fn blocks(&self) -> bool {
+        self.blocks
+    }
+
+    fn covers(&self, other: &Self) -> bool {
+        let (a, b) = (self.policy, other.policy);
+        option_covers(&a.source_zone_id, &b.source_zone_id)
+            && option_covers(&a.destination_zone_id, &b.destination_zone_id)
+            && protocols_cover(&a.protocol, &b.protocol)
+            && list_covers(&a.source_addresses, &b.source_addresses, address_covers)
+            && list_covers(
+                &a.destination_addresses,
+                &b.destination_addresses,
+                address_covers,
+            )
+            && list_covers(&a.source_ports, &b.source_ports, port_covers)
+            && list_covers(&a.destination_ports, &b.destination_ports, port_covers)
+            && list_covers(&a.connection_states, &b.connection_states, |x, y| x == y)
+            && versions_cover(&a.ip_version, &b.ip_version)
+            && (a.schedule.is_empty() || a.schedule == b.schedule)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        let (a, b) = (self.policy, other.policy);
+        option_overlaps(&a.source_zone_id, &b.source_zone_id)
+            && option_overlaps(&a.destination_zone_id, &b.destination_zone_id)
+            && protocols_overlap(&a.protocol, &b.protocol)
+            && list_overlaps(&a.source_addresses, &b.source_addresses, address_overlaps)
+            && list_overlaps(
+                &a.destination_addresses,
+                &b.destination_addresses,
+                address_overlaps,
+            )
+            && list_overlaps(&a.source_ports, &b.source_ports, port_overlaps)
+            && list_overlaps(&a.destination_ports, &b.destination_ports, port_overlaps)
+            && list_overlaps(&a.connection_states, &b.connection_states, |x, y| x == y)
+            && (versions_cover(&a.ip_version, &b.ip_version)
+                || versions_cover(&b.ip_version, &a.ip_version))
+    }
+}
+
+struct AclMatch<'a> {
+    rule: &'a AclRule,
+}
+
+impl RuleMatch for AclMatch<'_> {
+    fn id(&self) -> &str {
+        &self.rule.id
+    }
+
+    fn blocks(&self) -> bool {
+        self.rule.action == AclAction::Block
+    }
+
+    fn covers(&self, other: &Self) -> bool {
+        let (a, b) = (self.rule, other.rule);
+        target_covers(&a.source, &b.source) && target_covers(&a.destination, &b.destination)
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        let (a, b) = (self.rule, other.rule);
+        target_overlaps(&a.source, &b.source) && target_overlaps(&a.destination, &b.destination)
+    }
+}
+
+/// `None` matches any target.
+fn target_covers(a: &Option<AclTarget>, b: &Option<AclTarget>) -> bool {
+    match (a, b) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(a), Some(b)) => a.covers(b),
+    }
+}
+
+fn target_overlaps(a: &Option<AclTarget>, b: &Option<AclTarget>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a.covers(b) || b.covers(a),
+        _ => true,
+    }
+}
+
+/// `None` matches anything; otherwise the values must be equal.
+fn option_covers<T: PartialEq>(a: &Option<T>, b: &Option<T>) -> bool {
+    a.is_none() || a == b
+}
+
+fn option_overlaps<T: PartialEq>(a: &Option<T>, b: &Option<T>) -> bool {
+    a.is_none() || b.is_none() || a == b
+}
+
+/// An empty list matches anything; otherwise every entry of `b` must be
+/// covered by an entry of `a`.
+fn list_covers<T>(a: &[T], b: &[T], covers: impl Fn(&T, &T) -> bool) -> bool {
+    a.is_empty() || (!b.is_empty() && b.iter().all(|y| a.iter().any(|x| covers(x, y))))
+}
+
+fn list_overlaps<T>(a: &[T], b: &[T], overlaps: impl Fn(&T, &T) -> bool) -> bool {
+    a.is_empty() || b.is_empty() || a.iter().any(|x| b.iter().any(|y| overlaps(x, y)))
+}
+
+/// Get the protocol numbers a known protocol matches, or `None` for any.
+fn protocol_numbers(protocol: &Option<Protocol>) -> Option<Vec<u8>> {
+    match protocol.as_ref()? {
+        Protocol::Tcp => Some(vec![6]),
+        Protocol::Udp => Some(vec![17]),
+        Protocol::TcpUdp => Some(vec![6, 17]),
+        Protocol::Icmp => Some(vec![1]),
+        Protocol::Icmpv6 => Some(vec![58]),
+        Protocol::Any => None,
+        Protocol::Number(n) => Some(vec![*n]),
+        // Callers compare unknown protocols by value instead
+        Protocol::Unknown(_) => Some(Vec::new()),
+    }
+}
+
+fn is_unknown_protocol(protocol: &Option<Protocol>) -> bool {
+    matches!(protocol, Some(Protocol::Unknown(_)))
+}
+
+fn matches_any_protocol(protocol: &Option<Protocol>) -> bool {
+    matches!(protocol, None | Some(Protocol::Any))
+}
+
+fn protocols_cover(a: &Option<Protocol>, b: &Option<Protocol>) -> bool {
+    if is_unknown_protocol(a) || is_unknown_protocol(b) {
+        return matches_any_protocol(a) || a == b;
+    }
+    match (protocol_numbers(a), protocol_numbers(b)) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(a), Some(b)) => b.iter().all(|n| a.contains(n)),
+    }
+}
+
+fn protocols_overlap(a: &Option<Protocol>, b: &Option<Protocol>) -> bool {
+    if is_unknown_protocol(a) || is_unknown_protocol(b) {
+        return matches_any_protocol(a) || matches_any_protocol(b) || a == b;
+    }
+    match (protocol_numbers(a), protocol_numbers(b)) {
+        (Some(a), Some(b)) => a.iter().any(|n| b.contains(n)),
+        _ => true,
+    }
+}
+
+/// Unknown versions only cover themselves.
+fn versions_cover(a: &Option<IpVersion>, b: &Option<IpVersion>) -> bool {
+    match (a, b) {
+        (None | Some(IpVersion::Both), _) => true,
+        (Some(a), Some(b)) => a == b,
+        (Some(_), None) => false,
+    }
+}
+
+/// Get the first and last address of a literal match, as integers tagged
+/// with whether they are IPv4.
+fn address_bounds(m: &AddressMatch) -> Option<(bool, u128, u128)> {
+    let bits = |ip: IpAddr| match ip {
+        IpAddr::V4(ip) => u128::from(u32::from(ip)),
+        IpAddr::V6(ip) => u128::from(ip),
+    };
+    match m {
+        AddressMatch::Host(host) => Some((host.is_ipv4(), bits(*host), bits(*host))),
+        AddressMatch::Cidr(cidr) => {
+            let width = if cidr.address().is_ipv4() { 32 } else { 128 };
+            let host_bits = width - u32::from(cidr.prefix_length());
+            let host_mask = u128::MAX.checked_shr(128 - host_bits).unwrap_or(0);
+            let start = bits(cidr.address()) & !host_mask;
+            Some((cidr.address().is_ipv4(), start, start | host_mask))
+        }
+        AddressMatch::Range(range) => Some((
+            range.start().is_ipv4(),
+            bits(range.start()),
+            bits(range.end()),
+        )),
+        AddressMatch::Network(_) | AddressMatch::TrafficList(_) | AddressMatch::Unknown(_) => None,
+    }
+}
+
+fn address_covers(a: &AddressMatch, b: &AddressMatch) -> bool {
+    match (address_bounds(a), address_bounds(b)) {
+        (Some((a4, a_start, a_end)), Some((b4, b_start, b_end))) => {
+            a4 == b4 && a_start <= b_start && b_end <= a_end
+        }
+        _ => a == b,
+    }
+}
+
+fn address_overlaps(a: &AddressMatch, b: &AddressMatch) -> bool {
+    match (address_bounds(a), address_bounds(b)) {
+        (Some((a4, a_start, a_end)), Some((b4, b_start, b_end))) => {
+            a4 == b4 && a_start <= b_end && b_start <= a_end
+        }
+        _ => a == b,
+    }
+}
+
+fn port_bounds(m: &PortMatch) -> Option<(u16, u16)> {
+    match m {
+        PortMatch::Single(port) => Some((*port, *port)),
+        PortMatch::Range(start, end) => Some((*start, *end)),
+        PortMatch::TrafficList(_) | PortMatch::Unknown(_) => None,
+    }
+}
+
+fn port_covers(a: &PortMatch, b: &PortMatch) -> bool {
+    match (port_bounds(a), port_bounds(b)) {
+        (Some((a_start, a_end)), Some((b_start, b_end))) => a_start <= b_start && b_end <= a_end,
+        _ => a == b,
+    }
+}
+
+fn port_overlaps(a: &PortMatch, b: &PortMatch) -> bool {
+    match (port_bounds(a), port_bounds(b)) {
+        (Some((a_start, a_end)), Some((b_start, b_end))) => a_start <= b_end && b_start <= a_end,
+        _ => a == b,
+    }
+}
+
+/// Extension methods for UnifiClient to lint firewall and ACL rules.
+impl UnifiClient {
+    /// Fetch and lint the firewall policies of a site, checking references
+    /// against the site's zones and traffic lists.
+    pub async fn lint_firewall_policies(&self, site_id: &str) -> Result<LintReport> {
+        let (policies, zones, lists) = futures::try_join!(
+            self.fetch_all(GetFirewallPolicies::new(site_id)),
+            self.fetch_all(GetFirewallZones::new(site_id)),
+            self.fetch_all(GetTrafficLists::new(site_id)),
+        )?;
+        Ok(RuleLinter::new()
+            .zones(&zones)
+            .traffic_lists(&lists)
+            .lint_firewall(&policies))
+    }
+
+    /// Fetch and lint the ACL rules of a site.
+    pub async fn lint_acl_rules(&self, site_id: &str) -> Result<LintReport> {
+        let rules = self.fetch_all(GetAclRules::new(site_id)).await?;
+        Ok(RuleLinter::new().lint_acl(&rules))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::Endpoint;
+    use serde_json::{json, Value};
+
+    fn policies(rules: Value) -> Vec<FirewallPolicy> {
+        serde_json::from_value(rules).unwrap()
+    }
+
+    fn codes(report: &LintReport) -> Vec<(LintCode, &str)> {
+        report
+            .findings
+            .iter()
+            .map(|f| (f.code, f.rule_id.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_firewall_shadowed_redundant_duplicate() {
+        let policies = policies(json!([
+            {"id": "block-iot", "enabled": true, "order": 1, "action": {"type": "BLOCK"},
+             "sourceZoneId": "iot"},
+            {"id": "allow-iot-https", "enabled": true, "order": 2, "action": {"type": "ALLOW"},
+             "sourceZoneId": "iot", "protocol": "tcp", "destinationPorts": ["443"]},
+            {"id": "drop-iot-dns", "enabled": true, "order": 3, "action": {"type": "DROP"},
+             "sourceZoneId": "iot", "protocol": "udp", "destinationPorts": ["53"]},
+            {"id": "block-iot-again", "enabled": true, "order": 4, "action": {"type": "BLOCK"},
+             "sourceZoneId": "iot"}
+        ]));
+        let report = RuleLinter::new().lint_firewall(&policies);
+
+        assert_eq!(
+            codes(&report),
+            vec![
+                (LintCode::Shadowed, "allow-iot-https"),
+                (LintCode::Redundant, "drop-iot-dns"),
+                (LintCode::Duplicate, "block-iot-again"),
+            ]
+        );
+        assert_eq!(report.findings[0].severity, Severity::Error);
+        assert_eq!(
+            report.findings[0].related_rule_id.as_deref(),
+            Some("block-iot")
+        );
+        assert_eq!(report.max_severity(), Some(Severity::Error));
+
+        // The exception moves ahead of the rule hiding it
+        assert_eq!(
+            report.proposed_order.as_deref().unwrap(),
+            [
+                "allow-iot-https",
+                "block-iot",
+                "drop-iot-dns",
+                "block-iot-again"
+            ]
+        );
+        let reorder = report.reorder_firewall("site-1").unwrap();
+        assert_eq!(reorder.policy_ids[0], "allow-iot-https");
+        assert!(report.reorder_acl("site-1").is_none());
+    }
+
+    #[test]
+    fn test_lint_firewall_exception_before_general_rule_is_clean() {
+        let policies = policies(json!([
+            {"id": "allow-lan-web", "enabled": true, "order": 1, "action": {"type": "ALLOW"},
+             "protocol": "tcp", "destinationAddresses": ["10.0.30.0/24"],
+             "destinationPorts": ["80", "443"]},
+            {"id": "block-lan-servers", "enabled": true, "order": 2, "action": {"type": "BLOCK"},
+             "destinationAddresses": ["10.0.0.0/16"]},
+            {"id": "allow-admin", "enabled": true, "order": 3, "action": {"type": "ALLOW"},
+             "sourceAddresses": ["10.0.5.10"], "destinationAddresses": ["10.0.30.0/24"]}
+        ]));
+        let report = RuleLinter::new().lint_firewall(&policies);
+
+        // allow-admin is hidden for its whole destination range
+        assert_eq!(codes(&report), vec![(LintCode::Shadowed, "allow-admin")]);
+    }
+
+    #[test]
+    fn test_lint_firewall_conflict() {
+        let policies = policies(json!([
+            {"id": "allow-web", "enabled": true, "order": 1, "action": {"type": "ALLOW"},
+             "protocol": "tcp", "destinationPorts": ["80-443"]},
+            {"id": "reject-high", "enabled": true, "order": 2, "action": {"type": "REJECT"},
+             "protocol": "tcp_udp", "destinationPorts": ["400-500"]},
+            {"id": "block-ssh", "enabled": true, "order": 3, "action": {"type": "BLOCK"},
+             "protocol": "tcp", "destinationPorts": ["22"]}
+        ]));
+        let report = RuleLinter::new().lint_firewall(&policies);
+
+        assert_eq!(codes(&report), vec![(LintCode::Conflict, "reject-high")]);
+        assert_eq!(report.findings[0].severity, Severity::Info);
+        assert!(report.proposed_order.is_none());
+    }
+
+    #[test]
+    fn test_lint_firewall_references_and_stale() {
+        let zones: Vec<FirewallZone> =
+            serde_json::from_value(json!([{"id": "internal", "networks": []}])).unwrap();
+        let lists: Vec<TrafficList> = serde_json::from_value(json!([
+            {"id": "web", "name": "Web", "listType": "PORT", "entries": ["443"]}
+        ]))
+        .unwrap();
+        let policies = policies(json!([
+            {"id": "old", "enabled": false, "order": 1, "action": {"type": "ALLOW"},
+             "updatedAt": "2024-01-01T00:00:00Z"},
+            {"id": "recent", "enabled": false, "order": 2, "action": {"type": "ALLOW"},
+             "updatedAt": "2024-05-20T00:00:00Z"},
+            {"id": "dangling", "enabled": true, "order": 3, "action": {"type": "ALLOW"},
+             "sourceZoneId": "internal", "destinationZoneId": "dmz", "protocol": "tcp",
+             "destinationPorts": ["list:web", "list:gone"]}
+        ]));
+        let report = RuleLinter::new()
+            .zones(&zones)
+            .traffic_lists(&lists)
+            .now("2024-06-01T00:00:00Z".parse().unwrap())
+            .lint_firewall(&policies);
+
+        assert_eq!(
+            codes(&report),
+            vec![
+                (LintCode::StaleDisabled, "old"),
+                (LintCode::MissingReference, "dangling"),
+                (LintCode::MissingReference, "dangling"),
+            ]
+        );
+        assert!(report.findings[1].message.contains("'dmz'"));
+        assert!(report.findings[2].message.contains("'gone'"));
+        assert_eq!(report.at_least(Severity::Warning).count(), 2);
+    }
+
+    #[test]
+    fn test_lint_acl() {
+        let rules: Vec<AclRule> = serde_json::from_value(json!([
+            {"id": "block-guest", "name": "Block guest", "action": "BLOCK", "enabled": true,
+             "order": 1, "sourceType": "NETWORK", "sourceValue": "guest"},
+            {"id": "allow-printer", "name": "Printer", "action": "ALLOW", "enabled": true,
+             "order": 2, "sourceType": "NETWORK", "sourceValue": "guest",
+             "destinationType": "IP", "destinationValue": "10.0.0.20"},
+            {"id": "allow-to-dns", "name": "DNS", "action": "ALLOW", "enabled": true,
+             "order": 3, "destinationType": "IP", "destinationValue": "10.0.0.53"},
+            {"id": "block-servers", "name": "Servers", "action": "BLOCK", "enabled": true,
+             "order": 4, "destinationType": "IP", "destinationValue": "10.0.0.48/28"}
+        ]))
+        .unwrap();
+        let report = RuleLinter::new().lint_acl(&rules);
+
+        assert_eq!(
+            codes(&report),
+            vec![
+                (LintCode::Shadowed, "allow-printer"),
+                (LintCode::Conflict, "allow-to-dns"),
+            ]
+        );
+        assert_eq!(
+            report.proposed_order.as_deref().unwrap(),
+            [
+                "allow-printer",
+                "block-guest",
+                "allow-to-dns",
+                "block-servers"
+            ]
+        );
+
+        // ACL reports reorder through the ACL endpoint only
+        let reorder = report.reorder_acl("site-1").unwrap();
+        assert_eq!(reorder.build_path(), "sites/site-1/acl-rules/order");
+        assert_eq!(reorder.rule_ids[0], "allow-printer");
+        assert!(report.reorder_firewall("site-1").is_none());
+    }
+}
@@ -0,0 +1,892 @@
+//! Catalog of UniFi hardware and its capabilities.
+//!
+//! Devices report a short model code (e.g. `US24PRO`, `U7PG2`) or, on newer
+//! controllers, the product SKU (e.g. `U6-Pro`). [`lookup`] resolves either
+//! form to a [`HardwareModel`] describing the product name, family, radios,
+//! ports, PoE budget and end-of-life status.
+//!
+//! # Example
+//!
+//! ```
+//! use rustifi::models::catalog;
+//!
+//! let switch = catalog::lookup("US24PRO").unwrap();
+//! assert_eq!(switch.to_string(), "USW-Pro-24-PoE, 400W budget");
+//! assert_eq!(switch.port_count(), 26);
+//! ```
+
+use crate::models::device_details::PortConnector;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use PortConnector::{Rj45, Sfp, Sfp28, SfpPlus};
+use RadioBand::{Band2_4GHz as B2, Band5GHz as B5, Band6GHz as B6};
+
+/// Broad product family of a UniFi device.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ProductFamily {
+    AccessPoint,
+    Switch,
+    Gateway,
+    /// Consoles host the UniFi applications; Dream Machines also route.
+    Console,
+}
+
+impl fmt::Display for ProductFamily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProductFamily::AccessPoint => write!(f, "Access Point"),
+            ProductFamily::Switch => write!(f, "Switch"),
+            ProductFamily::Gateway => write!(f, "Gateway"),
+            ProductFamily::Console => write!(f, "Console"),
+        }
+    }
+}
+
+/// Wi-Fi frequency band.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RadioBand {
+    Band2_4GHz,
+    Band5GHz,
+    Band6GHz,
+}
+
+impl RadioBand {
+    /// Get the band for a frequency in GHz, as reported by the API (2.4, 5 or 6).
+    pub fn from_ghz(ghz: f64) -> Option<Self> {
+        match ghz {
+            g if (2.3..2.6).contains(&g) => Some(RadioBand::Band2_4GHz),
+            g if (4.9..5.9).contains(&g) => Some(RadioBand::Band5GHz),
+            g if (5.9..7.2).contains(&g) => Some(RadioBand::Band6GHz),
+            _ => None,
+        }
+    }
+
+    /// Get the nominal frequency in GHz.
+    pub fn ghz(&self) -> f64 {
+        match self {
+            RadioBand::Band2_4GHz => 2.4,
+            RadioBand::Band5GHz => 5.0,
+            RadioBand::Band6GHz => 6.0,
+        }
+    }
+}
+
+/// Serialized as the frequency in GHz (`2.4`, `5` or `6`).
+impl Serialize for RadioBand {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            RadioBand::Band2_4GHz => serializer.serialize_f64(2.4),
+            RadioBand::Band5GHz => serializer.serialize_u8(5),
+            RadioBand::Band6GHz => serializer.serialize_u8(6),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RadioBand {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let ghz = f64::deserialize(deserializer)?;
+        RadioBand::from_ghz(ghz)
+            .ok_or_else(|| de::Error::custom(format!("Unknown radio band: {} GHz", ghz)))
+    }
+}
+
+impl fmt::Display for RadioBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RadioBand::Band2_4GHz => write!(f, "2.4 GHz"),
+            RadioBand::Band5GHz => write!(f, "5 GHz"),
+            RadioBand::Band6GHz => write!(f, "6 GHz"),
+        }
+    }
+}
+
+/// A radio in a wireless device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadioSpec {
+    pub band: RadioBand,
+    pub spatial_streams: u8,
+}
+
+/// A group of identical physical ports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PortGroup {
+    pub count: u8,
+    pub connector: PortConnector,
+    pub speed_mbps: u32,
+    /// Whether these ports can supply PoE.
+    pub poe: bool,
+}
+
+/// Capabilities of a UniFi hardware model.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HardwareModel {
+    /// Model code reported by the controller (e.g. "US24PRO").
+    pub code: &'static str,
+    /// Product SKU (e.g. "USW-Pro-24-PoE").
+    pub sku: &'static str,
+    /// Marketing name (e.g. "Switch Pro 24 PoE").
+    pub name: &'static str,
+    pub family: ProductFamily,
+    pub radios: &'static [RadioSpec],
+    pub ports: &'static [PortGroup],
+    /// Total PoE power budget in watts, if the device supplies PoE.
+    pub poe_budget_watts: Option<u16>,
+    pub end_of_life: bool,
+}
+
+impl HardwareModel {
+    /// Check if the device has Wi-Fi radios.
+    pub fn is_wireless(&self) -> bool {
+        !self.radios.is_empty()
+    }
+
+    /// Get the supported radio bands.
+    pub fn radio_bands(&self) -> Vec<RadioBand> {
+        self.radios.iter().map(|r| r.band).collect()
+    }
+
+    /// Check if the device has a radio on the given band.
+    pub fn supports_band(&self, band: RadioBand) -> bool {
+        self.radios.iter().any(|r| r.band == band)
+    }
+
+    /// Get the total number of spatial streams across all radios.
+    pub fn spatial_streams(&self) -> u8 {
+        self.radios.iter().map(|r| r.spatial_streams).sum()
+    }
+
+    /// Get the total number of physical network ports.
+    pub fn port_count(&self) -> u32 {
+        self.ports.iter().map(|p| u32::from(p.count)).sum()
+    }
+
+    /// Get the number of ports that can supply PoE.
+    pub fn poe_port_count(&self) -> u32 {
+        self.ports
+            .iter()
+            .filter(|p| p.poe)
+            .map(|p| u32::from(p.count))
+            .sum()
+    }
+
+    /// Get the fastest port speed in Mbps.
+    pub fn max_port_speed_mbps(&self) -> Option<u32> {
+        self.ports.iter().map(|p| p.speed_mbps).max()
+    }
+
+    /// Check if the device can supply PoE to other devices.
+    pub fn supplies_poe(&self) -> bool {
+        self.poe_budget_watts.is_some()
+    }
+
+    /// Check if `model` matches this entry's code or SKU (case-insensitive).
+    pub fn matches(&self, model: &str) -> bool {
+        self.code.eq_ignore_ascii_case(model) || self.sku.eq_ignore_ascii_case(model)
+    }
+}
+
+impl fmt::Display for HardwareModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.sku)?;
+        if let Some(watts) = self.poe_budget_watts {
+            write!(f, ", {}W budget", watts)?;
+        }
+        Ok(())
+    }
+}
+
+/// Look up a model by controller model code or product SKU.
+pub fn lookup(model: &str) -> Option<&'static HardwareModel> {
+    let model = model.trim();
+    CATALOG.iter().find(|m| m.matches(model))
+}
+
+/// Get every model in the catalog.
+pub fn all() -> &'static [HardwareModel] {
+    CATALOG
+}
+
+/// Iterate over the models in a product family.
+pub fn by_family(family: ProductFamily) -> impl Iterator<Item = &'static HardwareModel> {
+    CATALOG.iter().filter(move |m| m.family == family)
+}
+
+const fn radio(band: RadioBand, spatial_streams: u8) -> RadioSpec {
+    RadioSpec {
+        band,
+        spatial_streams,
+    }
+}
+
+const fn ports(count: u8, connector: PortConnector, speed_mbps: u32, poe: bool) -> PortGroup {
+    PortGroup {
+        count,
+        connector,
+        speed_mbps,
+        poe,
+    }
+}
+
+const NO_RADIOS: &[RadioSpec] = &[];
+const WIFI5_2X2: &[RadioSpec] = &[radio(B2, 2), radio(B5, 2)];
+const WIFI5_3X3: &[RadioSpec] = &[radio(B2, 3), radio(B5, 3)];
+const WIFI5_4X4: &[RadioSpec] = &[radio(B2, 4), radio(B5, 4)];
+const WIFI_2X2_4X4: &[RadioSpec] = &[radio(B2, 2), radio(B5, 4)];
+const ONE_GBE: &[PortGroup] = &[ports(1, Rj45, 1000, false)];
+const TWO_GBE: &[PortGroup] = &[ports(2, Rj45, 1000, false)];
+
+static CATALOG: &[HardwareModel] = &[
+    // Access points
+    HardwareModel {
+        code: "U7LT",
+        sku: "UAP-AC-Lite",
+        name: "Access Point AC Lite",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_2X2,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7LR",
+        sku: "UAP-AC-LR",
+        name: "Access Point AC Long-Range",
+        family: ProductFamily::AccessPoint,
+        radios: &[radio(B2, 3), radio(B5, 2)],
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7PG2",
+        sku: "UAP-AC-Pro",
+        name: "Access Point AC Pro",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_3X3,
+        ports: TWO_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7HD",
+        sku: "UAP-AC-HD",
+        name: "Access Point AC HD",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_4X4,
+        ports: TWO_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7SHD",
+        sku: "UAP-AC-SHD",
+        name: "Access Point AC SHD",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_4X4,
+        ports: TWO_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7NHD",
+        sku: "UAP-nanoHD",
+        name: "Access Point nanoHD",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI_2X2_4X4,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7MSH",
+        sku: "UAP-AC-M",
+        name: "Access Point AC Mesh",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_2X2,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7MP",
+        sku: "UAP-AC-M-Pro",
+        name: "Access Point AC Mesh Pro",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_3X3,
+        ports: TWO_GBE,
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "U7IW",
+        sku: "UAP-AC-IW",
+        name: "Access Point AC In-Wall",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_2X2,
+        ports: &[ports(3, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "UHDIW",
+        sku: "UAP-IW-HD",
+        name: "Access Point In-Wall HD",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI_2X2_4X4,
+        ports: &[ports(5, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "UCXG",
+        sku: "UAP-XG",
+        name: "Access Point XG",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_4X4,
+        ports: &[ports(1, Rj45, 10000, false), ports(1, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "UXSDM",
+        sku: "UWB-XG",
+        name: "WiFi BaseStation XG",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_4X4,
+        ports: &[ports(1, Rj45, 10000, false), ports(1, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "UAL6",
+        sku: "U6-Lite",
+        name: "U6 Lite",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_2X2,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UALR6",
+        sku: "U6-LR",
+        name: "U6 Long-Range",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI5_4X4,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UAP6MP",
+        sku: "U6-Pro",
+        name: "U6 Pro",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI_2X2_4X4,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "U6M",
+        sku: "U6-Mesh",
+        name: "U6 Mesh",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI_2X2_4X4,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UAIW6",
+        sku: "U6-IW",
+        name: "U6 In-Wall",
+        family: ProductFamily::AccessPoint,
+        radios: WIFI_2X2_4X4,
+        ports: &[ports(1, Rj45, 1000, false), ports(4, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UAPA6A4",
+        sku: "U7-Pro-XGS",
+        name: "U7 Pro XGS",
+        family: ProductFamily::AccessPoint,
+        radios: &[radio(B2, 2), radio(B5, 4), radio(B6, 4)],
+        ports: &[ports(1, Rj45, 10000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UAPA6B0",
+        sku: "U7-Pro-Outdoor",
+        name: "U7 Pro Outdoor",
+        family: ProductFamily::AccessPoint,
+        radios: &[radio(B2, 2), radio(B5, 2), radio(B6, 2)],
+        ports: &[ports(1, Rj45, 2500, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UAPA6B1",
+        sku: "E7-Campus",
+        name: "E7 Campus",
+        family: ProductFamily::AccessPoint,
+        radios: &[radio(B2, 4), radio(B5, 4), radio(B6, 4)],
+        ports: &[
+            ports(1, Rj45, 10000, false),
+            ports(1, SfpPlus, 10000, false),
+        ],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    // Switches
+    HardwareModel {
+        code: "USMINI",
+        sku: "USW-Flex-Mini",
+        name: "Switch Flex Mini",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(5, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USF5P",
+        sku: "USW-Flex",
+        name: "Switch Flex",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(1, Rj45, 1000, false), ports(4, Rj45, 1000, true)],
+        poe_budget_watts: Some(46),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL8LP",
+        sku: "USW-Lite-8-PoE",
+        name: "Switch Lite 8 PoE",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(4, Rj45, 1000, true), ports(4, Rj45, 1000, false)],
+        poe_budget_watts: Some(52),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL16LP",
+        sku: "USW-Lite-16-PoE",
+        name: "Switch Lite 16 PoE",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(8, Rj45, 1000, true), ports(8, Rj45, 1000, false)],
+        poe_budget_watts: Some(45),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL16P",
+        sku: "USW-16-PoE",
+        name: "Switch 16 PoE",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(8, Rj45, 1000, true),
+            ports(8, Rj45, 1000, false),
+            ports(2, Sfp, 1000, false),
+        ],
+        poe_budget_watts: Some(42),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL24",
+        sku: "USW-24",
+        name: "Switch 24",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(24, Rj45, 1000, false), ports(2, Sfp, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL24P",
+        sku: "USW-24-PoE",
+        name: "Switch 24 PoE",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(16, Rj45, 1000, true),
+            ports(8, Rj45, 1000, false),
+            ports(2, Sfp, 1000, false),
+        ],
+        poe_budget_watts: Some(95),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL48",
+        sku: "USW-48",
+        name: "Switch 48",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(48, Rj45, 1000, false), ports(4, Sfp, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL48P",
+        sku: "USW-48-PoE",
+        name: "Switch 48 PoE",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(32, Rj45, 1000, true),
+            ports(16, Rj45, 1000, false),
+            ports(4, Sfp, 1000, false),
+        ],
+        poe_budget_watts: Some(195),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "US24PRO2",
+        sku: "USW-Pro-24",
+        name: "Switch Pro 24",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(24, Rj45, 1000, false),
+            ports(2, SfpPlus, 10000, false),
+        ],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "US24PRO",
+        sku: "USW-Pro-24-PoE",
+        name: "Switch Pro 24 PoE",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(24, Rj45, 1000, true), ports(2, SfpPlus, 10000, false)],
+        poe_budget_watts: Some(400),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "US48PRO2",
+        sku: "USW-Pro-48",
+        name: "Switch Pro 48",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(48, Rj45, 1000, false),
+            ports(4, SfpPlus, 10000, false),
+        ],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "US48PRO",
+        sku: "USW-Pro-48-PoE",
+        name: "Switch Pro 48 PoE",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(48, Rj45, 1000, true), ports(4, SfpPlus, 10000, false)],
+        poe_budget_watts: Some(600),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USL8A",
+        sku: "USW-Aggregation",
+        name: "Switch Aggregation",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(8, SfpPlus, 10000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "USAGGPRO",
+        sku: "USW-Pro-Aggregation",
+        name: "Switch Pro Aggregation",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(28, SfpPlus, 10000, false),
+            ports(4, Sfp28, 25000, false),
+        ],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "US8",
+        sku: "US-8",
+        name: "Switch 8",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(8, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US8P150",
+        sku: "US-8-150W",
+        name: "Switch 8 150W",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(8, Rj45, 1000, true), ports(2, Sfp, 1000, false)],
+        poe_budget_watts: Some(150),
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US16P150",
+        sku: "US-16-150W",
+        name: "Switch 16 150W",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(16, Rj45, 1000, true), ports(2, Sfp, 1000, false)],
+        poe_budget_watts: Some(150),
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US24",
+        sku: "US-24",
+        name: "Switch 24",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(24, Rj45, 1000, false), ports(2, Sfp, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US24P250",
+        sku: "US-24-250W",
+        name: "Switch 24 250W",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(24, Rj45, 1000, true), ports(2, Sfp, 1000, false)],
+        poe_budget_watts: Some(250),
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US24P500",
+        sku: "US-24-500W",
+        name: "Switch 24 500W",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[ports(24, Rj45, 1000, true), ports(2, Sfp, 1000, false)],
+        poe_budget_watts: Some(500),
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US48",
+        sku: "US-48",
+        name: "Switch 48",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(48, Rj45, 1000, false),
+            ports(2, Sfp, 1000, false),
+            ports(2, SfpPlus, 10000, false),
+        ],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US48P500",
+        sku: "US-48-500W",
+        name: "Switch 48 500W",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(48, Rj45, 1000, true),
+            ports(2, Sfp, 1000, false),
+            ports(2, SfpPlus, 10000, false),
+        ],
+        poe_budget_watts: Some(500),
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "US48P750",
+        sku: "US-48-750W",
+        name: "Switch 48 750W",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(48, Rj45, 1000, true),
+            ports(2, Sfp, 1000, false),
+            ports(2, SfpPlus, 10000, false),
+        ],
+        poe_budget_watts: Some(750),
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "USXG",
+        sku: "US-16-XG",
+        name: "Switch 16 XG",
+        family: ProductFamily::Switch,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(12, SfpPlus, 10000, false),
+            ports(4, Rj45, 10000, false),
+        ],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    // Gateways
+    HardwareModel {
+        code: "UGW3",
+        sku: "USG",
+        name: "Security Gateway",
+        family: ProductFamily::Gateway,
+        radios: NO_RADIOS,
+        ports: &[ports(3, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "UGW4",
+        sku: "USG-Pro-4",
+        name: "Security Gateway Pro 4",
+        family: ProductFamily::Gateway,
+        radios: NO_RADIOS,
+        ports: &[ports(4, Rj45, 1000, false), ports(2, Sfp, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: true,
+    },
+    HardwareModel {
+        code: "UXG",
+        sku: "UXG-Pro",
+        name: "Next-Generation Gateway Pro",
+        family: ProductFamily::Gateway,
+        radios: NO_RADIOS,
+        ports: &[ports(2, Rj45, 1000, false), ports(2, SfpPlus, 10000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    // Consoles
+    HardwareModel {
+        code: "UDM",
+        sku: "UDM",
+        name: "Dream Machine",
+        family: ProductFamily::Console,
+        radios: WIFI_2X2_4X4,
+        ports: &[ports(5, Rj45, 1000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UDR",
+        sku: "UDR",
+        name: "Dream Router",
+        family: ProductFamily::Console,
+        radios: WIFI5_2X2,
+        ports: &[ports(2, Rj45, 1000, true), ports(3, Rj45, 1000, false)],
+        poe_budget_watts: Some(10),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UDMPRO",
+        sku: "UDM-Pro",
+        name: "Dream Machine Pro",
+        family: ProductFamily::Console,
+        radios: NO_RADIOS,
+        ports: &[ports(9, Rj45, 1000, false), ports(2, SfpPlus, 10000, false)],
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UDMPROSE",
+        sku: "UDM-SE",
+        name: "Dream Machine Special Edition",
+        family: ProductFamily::Console,
+        radios: NO_RADIOS,
+        ports: &[
+            ports(8, Rj45, 1000, true),
+            ports(1, Rj45, 2500, false),
+            ports(2, SfpPlus, 10000, false),
+        ],
+        poe_budget_watts: Some(180),
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UCKG2",
+        sku: "UCK-G2",
+        name: "Cloud Key Gen2",
+        family: ProductFamily::Console,
+        radios: NO_RADIOS,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+    HardwareModel {
+        code: "UCKP",
+        sku: "UCK-G2-Plus",
+        name: "Cloud Key Gen2 Plus",
+        family: ProductFamily::Console,
+        radios: NO_RADIOS,
+        ports: ONE_GBE,
+        poe_budget_watts: None,
+        end_of_life: false,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_codes_and_skus_are_unique() {
+        let mut seen = HashSet::new();
+        for model in all() {
+            assert!(
+                seen.insert(model.code.to_ascii_lowercase()),
+                "duplicate code {}",
+                model.code
+            );
+            if model.sku != model.code {
+                assert!(
+                    seen.insert(model.sku.to_ascii_lowercase()),
+                    "duplicate sku {}",
+                    model.sku
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup_by_code_and_sku() {
+        let by_code = lookup("UAP6MP").unwrap();
+        let by_sku = lookup("u6-pro").unwrap();
+
+        assert_eq!(by_code, by_sku);
+        assert_eq!(by_code.family, ProductFamily::AccessPoint);
+        assert_eq!(
+            by_code.radio_bands(),
+            vec![RadioBand::Band2_4GHz, RadioBand::Band5GHz]
+        );
+        assert_eq!(by_code.spatial_streams(), 6);
+        assert!(lookup("NOT_A_REAL_MODEL").is_none());
+    }
+
+    #[test]
+    fn test_switch_capabilities() {
+        let switch = lookup("US24PRO").unwrap();
+
+        assert_eq!(switch.to_string(), "USW-Pro-24-PoE, 400W budget");
+        assert_eq!(switch.port_count(), 26);
+        assert_eq!(switch.poe_port_count(), 24);
+        assert_eq!(switch.max_port_speed_mbps(), Some(10000));
+        assert!(!switch.is_wireless());
+        assert!(!switch.end_of_life);
+    }
+
+    #[test]
+    fn test_tri_band_access_point() {
+        let ap = lookup("UAPA6A4").unwrap();
+
+        assert!(ap.supports_band(RadioBand::Band6GHz));
+        assert_eq!(ap.to_string(), "U7-Pro-XGS");
+    }
+
+    #[test]
+    fn test_by_family() {
+        assert!(by_family(ProductFamily::Gateway).all(|m| m.family == ProductFamily::Gateway));
+        assert!(by_family(ProductFamily::Console).any(|m| m.code == "UDMPRO"));
+    }
+}
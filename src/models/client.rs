@@ -1,12 +1,16 @@
-use crate::models::common::IpAddress;
-use serde::Deserialize;
+use crate::models::catalog::RadioBand;
+use crate::models::common::{IpAddress, IsoDateTime, MacAddress};
+use crate::models::units::{self, Bandwidth, DataSize};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::net::{Ipv4Addr, Ipv6Addr};
 
 /// Client type from the new API format.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
 pub enum ClientType {
-    #[serde(alias = "WIRED")]
+    #[serde(rename = "WIRED", alias = "Wired")]
     Wired,
-    #[serde(alias = "WIRELESS")]
+    #[serde(rename = "WIRELESS", alias = "Wireless")]
     Wireless,
     #[serde(other)]
     #[default]
@@ -14,31 +18,176 @@ pub enum ClientType {
 }
 
 /// Access type for a client.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
 pub enum AccessType {
-    #[serde(alias = "DEFAULT")]
+    #[serde(rename = "DEFAULT", alias = "Default")]
     #[default]
     Default,
-    #[serde(alias = "BLOCKED")]
+    #[serde(rename = "BLOCKED", alias = "Blocked")]
     Blocked,
-    #[serde(alias = "ALLOWED")]
+    #[serde(rename = "ALLOWED", alias = "Allowed")]
     Allowed,
-    #[serde(alias = "GUEST")]
+    #[serde(rename = "GUEST", alias = "Guest")]
     Guest,
     #[serde(other)]
     Unknown,
 }
 
 /// Access configuration for a client.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
 pub struct ClientAccess {
     #[serde(default, rename = "type")]
     pub access_type: AccessType,
+
+    /// Whether a guest client is currently authorized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorized: Option<bool>,
+
+    /// The active guest authorization, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<GuestAuthorization>,
+}
+
+/// Usage accumulated under a guest authorization.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestUsage {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_sec: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rx_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_bytes: Option<u64>,
+    /// Total bytes in both directions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes: Option<u64>,
+}
+
+impl GuestUsage {
+    /// Get the total data used, falling back to rx + tx if no total was reported.
+    pub fn total(&self) -> Option<DataSize> {
+        let sum = match (self.rx_bytes, self.tx_bytes) {
+            (None, None) => None,
+            (rx, tx) => Some(rx.unwrap_or(0).saturating_add(tx.unwrap_or(0))),
+        };
+        self.bytes.or(sum).map(DataSize::from_bytes)
+    }
+}
+
+/// A guest authorization granted through the hotspot (voucher, captive portal or API).
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestAuthorization {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorized_at: Option<IsoDateTime>,
+
+    /// How the guest was authorized (e.g. "VOUCHER", "API", "OTHER").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization_method: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<IsoDateTime>,
+
+    /// Data quota (sent in megabytes).
+    #[serde(
+        default,
+        rename = "dataUsageLimitMBytes",
+        with = "units::serde::megabytes::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data_usage_limit: Option<DataSize>,
+
+    /// Download rate limit (sent in kbps).
+    #[serde(
+        default,
+        rename = "rxRateLimitKbps",
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rx_rate_limit: Option<Bandwidth>,
+
+    /// Upload rate limit (sent in kbps).
+    #[serde(
+        default,
+        rename = "txRateLimitKbps",
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tx_rate_limit: Option<Bandwidth>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage: Option<GuestUsage>,
+}
+
+impl GuestAuthorization {
+    /// Get the access time left as of `now`, or `None` if the authorization has no expiry.
+    ///
+    /// Returns a zero duration once the authorization has expired.
+    pub fn remaining_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.expires_at
+            .as_ref()
+            .map(|at| (at.as_datetime() - now).max(Duration::zero()))
+    }
+
+    /// Check if the authorization has expired as of `now`.
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at
+            .as_ref()
+            .map(|at| at.as_datetime() <= now)
+            .unwrap_or(false)
+    }
+
+    /// Get the data used so far.
+    pub fn data_used(&self) -> Option<DataSize> {
+        self.usage.as_ref().and_then(GuestUsage::total)
+    }
+
+    /// Get the quota left, or `None` if the authorization has no data limit.
+    ///
+    /// Returns zero once the quota is exhausted.
+    pub fn remaining_quota(&self) -> Option<DataSize> {
+        let limit = self.data_usage_limit?;
+        let used = self.data_used().unwrap_or(DataSize::ZERO);
+        Some(limit.checked_sub(used).unwrap_or(DataSize::ZERO))
+    }
+
+    /// Check if the data quota has been used up.
+    pub fn is_quota_exhausted(&self) -> bool {
+        self.remaining_quota()
+            .map(|left| left.is_zero())
+            .unwrap_or(false)
+    }
+}
+
+/// Signal quality bucket of a wireless client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SignalQuality {
+    /// Below -75 dBm.
+    Poor,
+    /// -75 to -66 dBm.
+    Fair,
+    /// -65 to -56 dBm.
+    Good,
+    /// -55 dBm or better.
+    Excellent,
+}
+
+impl SignalQuality {
+    /// Classify a signal strength in dBm.
+    pub fn from_dbm(dbm: i32) -> Self {
+        match dbm {
+            d if d >= -55 => SignalQuality::Excellent,
+            d if d >= -65 => SignalQuality::Good,
+            d if d >= -75 => SignalQuality::Fair,
+            _ => SignalQuality::Poor,
+        }
+    }
 }
 
 /// Client from the new site-scoped API.
 /// Endpoint: GET /v1/sites/{siteId}/clients
-#[derive(Clone, Debug, Deserialize, PartialEq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct Client {
     /// Unique client identifier.
@@ -49,26 +198,106 @@ pub struct Client {
     pub client_type: ClientType,
 
     /// Display name of the client.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
 
-    /// Timestamp when the client connected (ISO 8601 format).
-    #[serde(default)]
-    pub connected_at: Option<String>,
+    /// When the client connected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connected_at: Option<IsoDateTime>,
 
-    /// IP address assigned to the client.
-    #[serde(default)]
+    /// MAC address of the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mac_address: Option<MacAddress>,
+
+    /// Primary IP address assigned to the client (IPv4 or IPv6).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ip_address: Option<IpAddress>,
 
+    /// Additional IPv6 addresses held by a dual-stack client.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ipv6_addresses: Vec<IpAddress>,
+
     /// Access configuration (default, blocked, allowed, or guest).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub access: Option<ClientAccess>,
 
     /// The UUID of the device this client is connected to.
     /// For wireless clients, this is the access point.
     /// For wired clients, this is the switch or gateway.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uplink_device_id: Option<String>,
+
+    /// Hostname reported by the client (via DHCP).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+
+    /// ID of the network the client is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+
+    /// VLAN the client is on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vlan_id: Option<u16>,
+
+    /// ID of the WiFi broadcast (SSID) a wireless client is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wifi_broadcast_id: Option<String>,
+
+    /// SSID a wireless client is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssid: Option<String>,
+
+    /// Radio frequency in GHz (2.4, 5 or 6) of a wireless client.
+    #[serde(
+        default,
+        rename = "frequencyGHz",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub frequency_ghz: Option<f64>,
+
+    /// WiFi channel of a wireless client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel: Option<u32>,
+
+    /// Received signal strength indicator (signal above the noise floor).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rssi: Option<i32>,
+
+    /// Signal strength in dBm.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signal_dbm: Option<i32>,
+
+    /// Current transmit rate to the client (sent in kbps).
+    #[serde(
+        default,
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub tx_rate_kbps: Option<Bandwidth>,
+
+    /// Current receive rate from the client (sent in kbps).
+    #[serde(
+        default,
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub rx_rate_kbps: Option<Bandwidth>,
+
+    /// Bytes sent to the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tx_bytes: Option<u64>,
+
+    /// Bytes received from the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rx_bytes: Option<u64>,
+
+    /// Fixed (reserved) IP address assigned to the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fixed_ip_address: Option<IpAddress>,
+
+    /// Free-form note attached to the client.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
 }
 
 impl Client {
@@ -87,6 +316,20 @@ impl Client {
         self.connected_at.is_some()
     }
 
+    /// Get how long the client has been connected.
+    pub fn connected_for(&self) -> Option<Duration> {
+        self.connected_for_at(Utc::now())
+    }
+
+    /// Get how long the client has been connected as of `now`.
+    ///
+    /// Returns a zero duration if the connection time is in the future.
+    pub fn connected_for_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.connected_at
+            .as_ref()
+            .map(|at| (now - at.as_datetime()).max(Duration::zero()))
+    }
+
     /// Check if the client is blocked.
     pub fn is_blocked(&self) -> bool {
         self.access
@@ -95,6 +338,70 @@ impl Client {
             .unwrap_or(false)
     }
 
+    /// Get the best available name: display name, hostname, MAC address or ID.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .or_else(|| self.hostname.clone())
+            .or_else(|| self.mac_address.map(|mac| mac.to_string()))
+            .unwrap_or_else(|| self.id.clone())
+    }
+
+    /// Get the radio band of a wireless client.
+    pub fn radio_band(&self) -> Option<RadioBand> {
+        self.frequency_ghz.and_then(RadioBand::from_ghz)
+    }
+
+    /// Check if a wireless client is connected on the given band.
+    pub fn is_on_band(&self, band: RadioBand) -> bool {
+        self.radio_band() == Some(band)
+    }
+
+    /// Get the signal quality of a wireless client.
+    pub fn signal_quality(&self) -> Option<SignalQuality> {
+        self.signal_dbm.map(SignalQuality::from_dbm)
+    }
+
+    /// Check if a wireless client's signal is below `threshold_dbm`.
+    pub fn has_weak_signal(&self, threshold_dbm: i32) -> bool {
+        self.signal_dbm.map(|s| s < threshold_dbm).unwrap_or(false)
+    }
+
+    /// Get the transmit rate to the client in Mbps.
+    pub fn tx_rate_mbps(&self) -> Option<f64> {
+        self.tx_rate_kbps.map(|rate| rate.as_mbps_f64())
+    }
+
+    /// Get the receive rate from the client in Mbps.
+    pub fn rx_rate_mbps(&self) -> Option<f64> {
+        self.rx_rate_kbps.map(|rate| rate.as_mbps_f64())
+    }
+
+    /// Get the total data transferred in both directions.
+    ///
+    /// Returns `None` if the API reported neither counter.
+    pub fn total_bytes(&self) -> Option<DataSize> {
+        match (self.tx_bytes, self.rx_bytes) {
+            (None, None) => None,
+            (tx, rx) => Some(DataSize::from_bytes(
+                tx.unwrap_or(0).saturating_add(rx.unwrap_or(0)),
+            )),
+        }
+    }
+
+    /// Check if the client has a fixed IP address.
+    pub fn has_fixed_ip(&self) -> bool {
+        self.fixed_ip_address.is_some()
+    }
+
+    /// Get the client's note, if one is set and not blank.
+    pub fn note(&self) -> Option<&str> {
+        self.note
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+    }
+
     /// Check if the client is a guest.
     pub fn is_guest(&self) -> bool {
         self.access
@@ -103,10 +410,72 @@ impl Client {
             .unwrap_or(false)
     }
 
+    /// Get the client's guest authorization, if it is an authorized guest.
+    pub fn guest_authorization(&self) -> Option<&GuestAuthorization> {
+        let access = self.access.as_ref()?;
+        if access.authorized == Some(false) {
+            return None;
+        }
+        access.authorization.as_ref()
+    }
+
+    /// Check if the client is a guest with an active authorization.
+    pub fn is_authorized_guest(&self) -> bool {
+        self.is_guest() && self.guest_authorization().is_some()
+    }
+
     /// Get the device ID this client is connected to, if available.
     pub fn device_id(&self) -> Option<&str> {
         self.uplink_device_id.as_deref()
     }
+
+    /// Check if the client uses a randomized (locally administered) MAC address.
+    pub fn has_randomized_mac(&self) -> bool {
+        self.mac_address
+            .map(|mac| mac.is_locally_administered())
+            .unwrap_or(false)
+    }
+
+    /// Look up the client's manufacturer from its MAC address.
+    ///
+    /// Returns `None` when the MAC is unknown, randomized or not in the
+    /// embedded OUI table.
+    #[cfg(feature = "oui")]
+    pub fn vendor(&self) -> Option<&'static str> {
+        self.mac_address.as_ref().and_then(MacAddress::vendor)
+    }
+
+    /// Iterate over every address of the client, primary address first.
+    ///
+    /// Duplicates between the primary address and the IPv6 list are skipped.
+    pub fn addresses(&self) -> impl Iterator<Item = &IpAddress> {
+        let primary = self.ip_address.as_ref();
+        primary.into_iter().chain(
+            self.ipv6_addresses
+                .iter()
+                .filter(move |a| Some(*a) != primary),
+        )
+    }
+
+    /// Get the client's IPv4 address, if it has one.
+    pub fn ipv4_address(&self) -> Option<Ipv4Addr> {
+        self.addresses().find_map(IpAddress::as_ipv4)
+    }
+
+    /// Iterate over the client's IPv6 addresses.
+    pub fn ipv6_addresses(&self) -> impl Iterator<Item = Ipv6Addr> + '_ {
+        self.addresses().filter_map(IpAddress::as_ipv6)
+    }
+
+    /// Iterate over the client's globally routable addresses.
+    pub fn global_addresses(&self) -> impl Iterator<Item = &IpAddress> {
+        self.addresses().filter(|a| a.is_global())
+    }
+
+    /// Check if the client has both an IPv4 and an IPv6 address.
+    pub fn is_dual_stack(&self) -> bool {
+        self.ipv4_address().is_some() && self.ipv6_addresses().next().is_some()
+    }
 }
 
 #[cfg(test)]
@@ -182,6 +551,70 @@ mod tests {
         assert!(!client.is_guest());
     }
 
+    #[test]
+    fn test_authorized_guest() {
+        let json_data = json!({
+            "id": "guest-1",
+            "type": "WIRELESS",
+            "access": {
+                "type": "GUEST",
+                "authorized": true,
+                "authorization": {
+                    "authorizedAt": "2024-01-01T12:00:00Z",
+                    "authorizationMethod": "API",
+                    "expiresAt": "2024-01-01T14:00:00Z",
+                    "dataUsageLimitMBytes": 1000,
+                    "rxRateLimitKbps": 10000,
+                    "usage": {"durationSec": 600, "rxBytes": 300000000, "txBytes": 100000000}
+                }
+            }
+        });
+
+        let client: Client = serde_json::from_value(json_data).unwrap();
+        assert!(client.is_authorized_guest());
+
+        let authorization = client.guest_authorization().unwrap();
+        let now: DateTime<Utc> = "2024-01-01T13:15:00Z".parse().unwrap();
+        assert_eq!(authorization.remaining_at(now), Some(Duration::minutes(45)));
+        assert!(!authorization.is_expired_at(now));
+        assert_eq!(authorization.data_used(), DataSize::from_mb(400));
+        assert_eq!(authorization.remaining_quota(), DataSize::from_mb(600));
+        assert_eq!(authorization.rx_rate_limit, Bandwidth::from_mbps(10));
+
+        let later: DateTime<Utc> = "2024-01-01T15:00:00Z".parse().unwrap();
+        assert_eq!(authorization.remaining_at(later), Some(Duration::zero()));
+        assert!(authorization.is_expired_at(later));
+    }
+
+    #[test]
+    fn test_unauthorized_guest() {
+        let client: Client = serde_json::from_value(json!({
+            "id": "guest-2",
+            "access": {"type": "GUEST", "authorized": false}
+        }))
+        .unwrap();
+
+        assert!(client.is_guest());
+        assert!(!client.is_authorized_guest());
+        assert!(client.guest_authorization().is_none());
+    }
+
+    #[test]
+    fn test_exhausted_quota() {
+        let authorization = GuestAuthorization {
+            data_usage_limit: DataSize::from_mb(100),
+            usage: Some(GuestUsage {
+                bytes: Some(150_000_000),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        assert_eq!(authorization.remaining_quota(), Some(DataSize::ZERO));
+        assert!(authorization.is_quota_exhausted());
+        assert!(GuestAuthorization::default().remaining_quota().is_none());
+    }
+
     #[test]
     fn test_access_type_deserialization() {
         let default: AccessType = serde_json::from_str("\"DEFAULT\"").unwrap();
@@ -197,11 +630,62 @@ mod tests {
         assert_eq!(unknown, AccessType::Unknown);
     }
 
+    #[test]
+    fn test_client_mac_address() {
+        let client: Client = serde_json::from_value(json!({
+            "id": "client-mac",
+            "macAddress": "DA-A1-19-00-11-22"
+        }))
+        .unwrap();
+
+        assert_eq!(client.mac_address.unwrap().to_string(), "da:a1:19:00:11:22");
+        assert!(client.has_randomized_mac());
+    }
+
+    #[test]
+    fn test_dual_stack_client() {
+        let json_data = json!({
+            "id": "client-dual",
+            "type": "WIRED",
+            "ipAddress": "192.168.1.20",
+            "ipv6Addresses": [
+                "fe80::1c2b:3cff:fe4d:5e6f",
+                "fd12:3456:789a::20",
+                "2a01:4f8:c0c:1234::20"
+            ]
+        });
+
+        let client: Client = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(client.ipv4_address(), Some(Ipv4Addr::new(192, 168, 1, 20)));
+        assert_eq!(client.ipv6_addresses().count(), 3);
+        assert_eq!(client.addresses().count(), 4);
+        assert!(client.is_dual_stack());
+
+        let global: Vec<String> = client.global_addresses().map(|a| a.to_string()).collect();
+        assert_eq!(global, vec!["2a01:4f8:c0c:1234::20"]);
+    }
+
+    #[test]
+    fn test_ipv6_only_client() {
+        let json_data = json!({
+            "id": "client-v6",
+            "type": "WIRELESS",
+            "ipAddress": "2a01:4f8:c0c:1234::99"
+        });
+
+        let client: Client = serde_json::from_value(json_data).unwrap();
+
+        assert!(client.ipv4_address().is_none());
+        assert_eq!(client.ipv6_addresses().count(), 1);
+        assert!(!client.is_dual_stack());
+    }
+
     #[test]
     fn test_is_connected() {
         let connected = Client {
             id: "client-1".to_string(),
-            connected_at: Some("2024-01-01T12:00:00Z".to_string()),
+            connected_at: Some("2024-01-01T12:00:00Z".parse().unwrap()),
             ..Default::default()
         };
         assert!(connected.is_connected());
@@ -213,4 +697,91 @@ mod tests {
         };
         assert!(!disconnected.is_connected());
     }
+
+    #[test]
+    fn test_connected_for() {
+        let client: Client = serde_json::from_value(json!({
+            "id": "client-1",
+            "connectedAt": "2024-01-01T12:00:00Z"
+        }))
+        .unwrap();
+
+        let now: DateTime<Utc> = "2024-01-01T13:30:00Z".parse().unwrap();
+        assert_eq!(client.connected_for_at(now), Some(Duration::minutes(90)));
+        assert_eq!(
+            client.connected_at.as_ref().unwrap().raw(),
+            "2024-01-01T12:00:00Z"
+        );
+
+        let never = Client::default();
+        assert!(never.connected_for_at(now).is_none());
+    }
+
+    #[test]
+    fn test_wireless_client_details() {
+        let json_data = json!({
+            "id": "client-wifi",
+            "type": "WIRELESS",
+            "hostname": "pixel-8",
+            "macAddress": "00:11:22:33:44:55",
+            "networkId": "net-1",
+            "vlanId": 20,
+            "wifiBroadcastId": "wlan-1",
+            "ssid": "Home",
+            "frequencyGHz": 2.4,
+            "channel": 6,
+            "rssi": 22,
+            "signalDbm": -73,
+            "txRateKbps": 144400,
+            "rxRateKbps": 86700,
+            "txBytes": 1000,
+            "rxBytes": 500,
+            "fixedIpAddress": "192.168.20.50",
+            "note": "  Living room tablet  "
+        });
+
+        let client: Client = serde_json::from_value(json_data).unwrap();
+
+        assert_eq!(client.display_name(), "pixel-8");
+        assert_eq!(client.vlan_id, Some(20));
+        assert_eq!(client.radio_band(), Some(RadioBand::Band2_4GHz));
+        assert!(client.is_on_band(RadioBand::Band2_4GHz));
+        assert_eq!(client.signal_quality(), Some(SignalQuality::Fair));
+        assert!(client.has_weak_signal(-70));
+        assert!(!client.has_weak_signal(-75));
+        assert_eq!(client.tx_rate_kbps.unwrap().to_string(), "144.4 Mbps");
+        assert_eq!(client.tx_rate_mbps(), Some(144.4));
+        assert_eq!(client.rx_rate_kbps, Bandwidth::from_kbps(86700));
+        assert_eq!(client.total_bytes(), Some(DataSize::from_bytes(1500)));
+        assert!(client.has_fixed_ip());
+        assert_eq!(client.note(), Some("Living room tablet"));
+    }
+
+    #[test]
+    fn test_wired_client_has_no_radio_details() {
+        let client: Client = serde_json::from_value(json!({
+            "id": "client-wired",
+            "type": "WIRED",
+            "macAddress": "00:11:22:33:44:66",
+            "note": " "
+        }))
+        .unwrap();
+
+        assert_eq!(client.display_name(), "00:11:22:33:44:66");
+        assert!(client.radio_band().is_none());
+        assert!(client.signal_quality().is_none());
+        assert!(!client.has_weak_signal(-70));
+        assert!(client.total_bytes().is_none());
+        assert!(client.note().is_none());
+    }
+
+    #[test]
+    fn test_signal_quality_buckets() {
+        assert_eq!(SignalQuality::from_dbm(-40), SignalQuality::Excellent);
+        assert_eq!(SignalQuality::from_dbm(-55), SignalQuality::Excellent);
+        assert_eq!(SignalQuality::from_dbm(-60), SignalQuality::Good);
+        assert_eq!(SignalQuality::from_dbm(-75), SignalQuality::Fair);
+        assert_eq!(SignalQuality::from_dbm(-76), SignalQuality::Poor);
+        assert!(SignalQuality::Poor < SignalQuality::Excellent);
+    }
 }
@@ -1,14 +1,95 @@
-use serde::Deserialize;
+use crate::error::Error;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
-use std::net::Ipv4Addr;
+use std::hash::{Hash, Hasher};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash)]
-pub struct IpAddress(pub Ipv4Addr);
+/// An IPv4 or IPv6 address as reported by the controller.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct IpAddress(pub IpAddr);
 
 impl IpAddress {
-    pub fn new(addr: Ipv4Addr) -> Self {
-        IpAddress(addr)
+    pub fn new(addr: impl Into<IpAddr>) -> Self {
+        IpAddress(addr.into())
+    }
+
+    /// Check if this is an IPv4 address.
+    pub fn is_ipv4(&self) -> bool {
+        self.0.is_ipv4()
+    }
+
+    /// Check if this is an IPv6 address.
+    pub fn is_ipv6(&self) -> bool {
+        self.0.is_ipv6()
+    }
+
+    /// Get the IPv4 address, if this is one.
+    pub fn as_ipv4(&self) -> Option<Ipv4Addr> {
+        match self.0 {
+            IpAddr::V4(addr) => Some(addr),
+            IpAddr::V6(_) => None,
+        }
+    }
+
+    /// Get the IPv6 address, if this is one.
+    pub fn as_ipv6(&self) -> Option<Ipv6Addr> {
+        match self.0 {
+            IpAddr::V4(_) => None,
+            IpAddr::V6(addr) => Some(addr),
+        }
+    }
+
+    /// Check if the address is link-local (169.254.0.0/16 or fe80::/10).
+    pub fn is_link_local(&self) -> bool {
+        match self.0 {
+            IpAddr::V4(addr) => addr.is_link_local(),
+            IpAddr::V6(addr) => (addr.segments()[0] & 0xffc0) == 0xfe80,
+        }
+    }
+
+    /// Check if the address is an IPv6 unique local address (fc00::/7).
+    pub fn is_unique_local(&self) -> bool {
+        match self.0 {
+            IpAddr::V4(_) => false,
+            IpAddr::V6(addr) => (addr.segments()[0] & 0xfe00) == 0xfc00,
+        }
+    }
+
+    /// Check if the address is an IPv4 private address (RFC 1918).
+    pub fn is_private(&self) -> bool {
+        match self.0 {
+            IpAddr::V4(addr) => addr.is_private(),
+            IpAddr::V6(_) => false,
+        }
+    }
+
+    /// Check if the address is globally routable.
+    ///
+    /// Excludes unspecified, loopback, link-local, multicast, documentation,
+    /// RFC 1918, carrier-grade NAT and unique local addresses.
+    pub fn is_global(&self) -> bool {
+        if self.0.is_unspecified()
+            || self.0.is_loopback()
+            || self.0.is_multicast()
+            || self.is_link_local()
+        {
+            return false;
+        }
+
+        match self.0 {
+            IpAddr::V4(addr) => {
+                let [a, b, ..] = addr.octets();
+                let shared = a == 100 && (b & 0xc0) == 64;
+                !(addr.is_private() || addr.is_broadcast() || addr.is_documentation() || shared)
+            }
+            IpAddr::V6(addr) => {
+                let documentation = addr.segments()[0] == 0x2001 && addr.segments()[1] == 0x0db8;
+                !(self.is_unique_local() || documentation)
+            }
+        }
     }
 }
 
@@ -20,7 +101,37 @@ impl fmt::Display for IpAddress {
 
 impl Default for IpAddress {
     fn default() -> Self {
-        IpAddress(Ipv4Addr::new(0, 0, 0, 0))
+        IpAddress(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+    }
+}
+
+impl From<IpAddr> for IpAddress {
+    fn from(addr: IpAddr) -> Self {
+        IpAddress(addr)
+    }
+}
+
+impl From<Ipv4Addr> for IpAddress {
+    fn from(addr: Ipv4Addr) -> Self {
+        IpAddress(IpAddr::V4(addr))
+    }
+}
+
+impl From<Ipv6Addr> for IpAddress {
+    fn from(addr: Ipv6Addr) -> Self {
+        IpAddress(IpAddr::V6(addr))
+    }
+}
+
+impl FromStr for IpAddress {
+    type Err = std::net::AddrParseError;
+
+    /// Parse an IPv4 or IPv6 address. A trailing zone index such as
+    /// `fe80::1%br0` is accepted and discarded.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let addr = s.split_once('%').map(|(addr, _)| addr).unwrap_or(s);
+        IpAddr::from_str(addr).map(IpAddress)
     }
 }
 
@@ -30,21 +141,183 @@ impl<'de> Deserialize<'de> for IpAddress {
         D: serde::Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        let addr = Ipv4Addr::from_str(&s).map_err(serde::de::Error::custom)?;
-        Ok(IpAddress(addr))
+        IpAddress::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for IpAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(&self.0)
     }
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
-pub struct MacAddress(pub String);
+/// A 48-bit MAC address.
+///
+/// Parsed from any of the common notations: `aa:bb:cc:dd:ee:ff`,
+/// `AA-BB-CC-DD-EE-FF`, `aabb.ccdd.eeff` and `aabbccddeeff`. Equal addresses
+/// compare equal regardless of the notation they were parsed from, and always
+/// display in the canonical lowercase colon-separated form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct MacAddress([u8; 6]);
 
 impl MacAddress {
-    pub fn new(mac: impl Into<String>) -> Self {
-        MacAddress(mac.into())
+    /// Parse a MAC address from a string.
+    pub fn new(mac: impl AsRef<str>) -> Result<Self, Error> {
+        mac.as_ref().parse()
+    }
+
+    /// Create a MAC address from its raw bytes.
+    pub const fn from_bytes(bytes: [u8; 6]) -> Self {
+        MacAddress(bytes)
+    }
+
+    /// Get the raw bytes of the address.
+    pub const fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+
+    /// Get the Organizationally Unique Identifier (first three bytes).
+    pub fn oui(&self) -> [u8; 3] {
+        [self.0[0], self.0[1], self.0[2]]
+    }
+
+    /// Check if the locally administered bit is set.
+    ///
+    /// Randomized "private" Wi-Fi addresses used by modern phones and laptops
+    /// always have this bit set, so they carry no vendor information.
+    pub fn is_locally_administered(&self) -> bool {
+        self.0[0] & 0x02 != 0
+    }
+
+    /// Check if the address is universally administered (assigned by the vendor).
+    pub fn is_universal(&self) -> bool {
+        !self.is_locally_administered()
+    }
+
+    /// Check if this is a multicast (group) address.
+    pub fn is_multicast(&self) -> bool {
+        self.0[0] & 0x01 != 0
+    }
+
+    /// Check if this is a unicast address.
+    pub fn is_unicast(&self) -> bool {
+        !self.is_multicast()
+    }
+
+    /// Check if this is the broadcast address `ff:ff:ff:ff:ff:ff`.
+    pub fn is_broadcast(&self) -> bool {
+        self.0 == [0xff; 6]
+    }
+
+    /// Check if this is the all-zero address.
+    pub fn is_nil(&self) -> bool {
+        self.0 == [0; 6]
+    }
+
+    /// Format with a custom separator and case, e.g. `AA-BB-CC-DD-EE-FF`.
+    pub fn to_string_with(&self, separator: &str, uppercase: bool) -> String {
+        self.0
+            .iter()
+            .map(|b| {
+                if uppercase {
+                    format!("{:02X}", b)
+                } else {
+                    format!("{:02x}", b)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// Format in Cisco dotted notation, e.g. `aabb.ccdd.eeff`.
+    pub fn to_cisco_string(&self) -> String {
+        let b = self.0;
+        format!(
+            "{:02x}{:02x}.{:02x}{:02x}.{:02x}{:02x}",
+            b[0], b[1], b[2], b[3], b[4], b[5]
+        )
+    }
+
+    /// Look up the vendor name for the address in the embedded OUI table.
+    ///
+    /// Returns `None` for locally administered addresses and unknown prefixes.
+    #[cfg(feature = "oui")]
+    pub fn vendor(&self) -> Option<&'static str> {
+        if self.is_locally_administered() {
+            return None;
+        }
+        crate::models::oui::lookup(self.oui())
+    }
+}
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = self.0;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            b[0], b[1], b[2], b[3], b[4], b[5]
+        )
+    }
+}
+
+impl From<[u8; 6]> for MacAddress {
+    fn from(bytes: [u8; 6]) -> Self {
+        MacAddress(bytes)
+    }
+}
+
+impl From<MacAddress> for [u8; 6] {
+    fn from(mac: MacAddress) -> Self {
+        mac.0
     }
+}
+
+impl FromStr for MacAddress {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidMacAddress(s.to_string());
+        let trimmed = s.trim();
+
+        let digits: String = match trimmed.len() {
+            // aa:bb:cc:dd:ee:ff or aa-bb-cc-dd-ee-ff
+            17 => {
+                let separator = trimmed.as_bytes()[2];
+                if separator != b':' && separator != b'-' {
+                    return Err(invalid());
+                }
+                let groups: Vec<&str> = trimmed.split(separator as char).collect();
+                if groups.len() != 6 || groups.iter().any(|g| g.len() != 2) {
+                    return Err(invalid());
+                }
+                groups.concat()
+            }
+            // aabb.ccdd.eeff
+            14 => {
+                let groups: Vec<&str> = trimmed.split('.').collect();
+                if groups.len() != 3 || groups.iter().any(|g| g.len() != 4) {
+                    return Err(invalid());
+                }
+                groups.concat()
+            }
+            // aabbccddeeff
+            12 => trimmed.to_string(),
+            _ => return Err(invalid()),
+        };
 
-    pub fn as_str(&self) -> &str {
-        &self.0
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let mut bytes = [0u8; 6];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&digits[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(MacAddress(bytes))
     }
 }
 
@@ -54,7 +327,16 @@ impl<'de> Deserialize<'de> for MacAddress {
         D: serde::Deserializer<'de>,
     {
         let s = String::deserialize(deserializer)?;
-        Ok(MacAddress(s))
+        MacAddress::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for MacAddress {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_str(self)
     }
 }
 
@@ -76,3 +358,131 @@ impl<'de> Deserialize<'de> for Timestamp {
         Ok(Timestamp(n))
     }
 }
+
+impl Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_i64(self.0)
+    }
+}
+
+/// An ISO 8601 timestamp parsed into a UTC `DateTime`.
+///
+/// The raw string from the API is kept alongside the parsed value, so it can be
+/// displayed or forwarded unchanged. Comparisons use the parsed instant only.
+#[derive(Clone, Debug)]
+pub struct IsoDateTime {
+    raw: String,
+    value: DateTime<Utc>,
+}
+
+impl IsoDateTime {
+    /// Create a timestamp from a `DateTime`, using RFC 3339 as the raw form.
+    pub fn new(value: DateTime<Utc>) -> Self {
+        IsoDateTime {
+            raw: value.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true),
+            value,
+        }
+    }
+
+    /// Get the parsed UTC date and time.
+    pub fn as_datetime(&self) -> DateTime<Utc> {
+        self.value
+    }
+
+    /// Get the timestamp exactly as the API returned it.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl std::ops::Deref for IsoDateTime {
+    type Target = DateTime<Utc>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl From<DateTime<Utc>> for IsoDateTime {
+    fn from(value: DateTime<Utc>) -> Self {
+        IsoDateTime::new(value)
+    }
+}
+
+impl From<IsoDateTime> for DateTime<Utc> {
+    fn from(timestamp: IsoDateTime) -> Self {
+        timestamp.value
+    }
+}
+
+impl PartialEq for IsoDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl Eq for IsoDateTime {}
+
+impl PartialEq<DateTime<Utc>> for IsoDateTime {
+    fn eq(&self, other: &DateTime<Utc>) -> bool {
+        self.value == *other
+    }
+}
+
+impl Hash for IsoDateTime {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl PartialOrd for IsoDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for IsoDateTime {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl fmt::Display for IsoDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.raw)
+    }
+}
+
+impl FromStr for IsoDateTime {
+    type Err = chrono::ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = DateTime::parse_from_rfc3339(s.trim())?.with_timezone(&Utc);
+        Ok(IsoDateTime {
+            raw: s.to_string(),
+            value,
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for IsoDateTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        IsoDateTime::from_str(&s).map_err(serde::de::Error::custom)
+    }
+}
+
+impl Serialize for IsoDateTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.raw)
+    }
+}
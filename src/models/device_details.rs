@@ -1,8 +1,11 @@
-use crate::models::common::{IpAddress, MacAddress};
-use serde::Deserialize;
+use crate::models::catalog::{self, HardwareModel, ProductFamily};
+use crate::models::common::{IpAddress, IsoDateTime, MacAddress};
+use crate::models::site_device::DeviceState;
+use crate::models::units::{self, Bandwidth};
+use serde::{Deserialize, Serialize};
 
 /// Port connector type.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub enum PortConnector {
     #[serde(rename = "RJ45")]
     Rj45,
@@ -19,7 +22,7 @@ pub enum PortConnector {
 }
 
 /// Port interface state.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum InterfaceState {
     Up,
@@ -29,23 +32,23 @@ pub enum InterfaceState {
 }
 
 /// Power over Ethernet configuration and state.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct PoE {
     /// PoE standard (e.g., "802.3bt")
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub standard: Option<String>,
 
     /// PoE type identifier
-    #[serde(default, rename = "type")]
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
     pub r#type: Option<i32>,
 
     /// Whether PoE is enabled
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub enabled: Option<bool>,
 
     /// Current PoE state
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
 }
 
@@ -62,7 +65,7 @@ impl PoE {
 }
 
 /// Port information for physical interfaces.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Port {
     /// Port index identifier (1-based)
@@ -74,15 +77,20 @@ pub struct Port {
     /// Port connector type
     pub connector: PortConnector,
 
-    /// Maximum speed in Mbps
-    pub max_speed_mbps: i32,
+    /// Maximum link speed (sent in Mbps)
+    #[serde(with = "units::serde::mbps")]
+    pub max_speed_mbps: Bandwidth,
 
-    /// Current speed in Mbps
-    #[serde(default)]
-    pub speed_mbps: Option<i32>,
+    /// Current link speed (sent in Mbps)
+    #[serde(
+        default,
+        with = "units::serde::mbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub speed_mbps: Option<Bandwidth>,
 
     /// Power over Ethernet configuration
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub poe: Option<PoE>,
 }
 
@@ -114,12 +122,19 @@ impl Port {
 
     /// Get the current speed in Gbps, if available.
     pub fn speed_gbps(&self) -> Option<f64> {
-        self.speed_mbps.map(|s| s as f64 / 1000.0)
+        self.speed_mbps.map(|s| s.as_gbps_f64())
+    }
+
+    /// Check if the link negotiated below the port's maximum speed.
+    pub fn is_below_max_speed(&self) -> bool {
+        self.speed_mbps
+            .map(|s| s < self.max_speed_mbps)
+            .unwrap_or(false)
     }
 }
 
 /// Wireless radio standard.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 pub enum WirelessStandard {
     #[serde(rename = "802.11a")]
     Standard802_11a,
@@ -140,7 +155,7 @@ pub enum WirelessStandard {
 }
 
 /// Wireless radio information.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Radio {
     /// Wireless standard (802.11a, 802.11ac, etc.)
@@ -155,12 +170,12 @@ pub struct Radio {
     pub channel_width_mhz: i32,
 
     /// Current channel number
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub channel: Option<i32>,
 }
 
 /// Device physical interfaces.
-#[derive(Clone, Debug, Deserialize, PartialEq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct PhysicalInterfaces {
     /// List of physical ports
@@ -173,7 +188,7 @@ pub struct PhysicalInterfaces {
 }
 
 /// Device uplink connection information.
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DeviceUplink {
     /// ID of the parent device in the network topology
@@ -181,35 +196,35 @@ pub struct DeviceUplink {
 }
 
 /// Device switching feature details.
-#[derive(Clone, Debug, Deserialize, PartialEq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct SwitchingFeature {
     // Currently empty, but structured for future expansion
 }
 
 /// Device access point feature details.
-#[derive(Clone, Debug, Deserialize, PartialEq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct AccessPointFeature {
     // Currently empty, but structured for future expansion
 }
 
 /// Device features and capabilities.
-#[derive(Clone, Debug, Deserialize, PartialEq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DeviceFeatures {
     /// Switching feature details (if supported)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub switching: Option<SwitchingFeature>,
 
     /// Access point feature details (if supported)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub access_point: Option<AccessPointFeature>,
 }
 
 /// Detailed device information from the device details endpoint.
 /// Endpoint: GET /v1/sites/{siteId}/devices/{deviceId}
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct DeviceDetails {
     /// Unique device identifier (UUID)
@@ -234,25 +249,25 @@ pub struct DeviceDetails {
     pub state: String,
 
     /// Current firmware version
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub firmware_version: Option<String>,
 
     /// Whether firmware can be updated
     pub firmware_updatable: bool,
 
-    /// When the device was adopted (ISO 8601 timestamp)
-    #[serde(default)]
-    pub adopted_at: Option<String>,
+    /// When the device was adopted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adopted_at: Option<IsoDateTime>,
 
-    /// When the device was provisioned (ISO 8601 timestamp)
-    #[serde(default)]
-    pub provisioned_at: Option<String>,
+    /// When the device was provisioned
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provisioned_at: Option<IsoDateTime>,
 
     /// Configuration ID
     pub configuration_id: String,
 
     /// Device uplink connection info (optional)
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uplink: Option<DeviceUplink>,
 
     /// Device features and capabilities
@@ -265,6 +280,30 @@ pub struct DeviceDetails {
 }
 
 impl DeviceDetails {
+    /// Look up the hardware capabilities for this device's model.
+    pub fn catalog_info(&self) -> Option<&'static HardwareModel> {
+        catalog::lookup(&self.model)
+    }
+
+    /// Get the product family from the catalog, falling back to the device's features.
+    pub fn product_family(&self) -> Option<ProductFamily> {
+        if let Some(model) = self.catalog_info() {
+            return Some(model.family);
+        }
+        if self.has_access_point() {
+            Some(ProductFamily::AccessPoint)
+        } else if self.has_switching() {
+            Some(ProductFamily::Switch)
+        } else {
+            None
+        }
+    }
+
+    /// Parse the device state.
+    pub fn device_state(&self) -> DeviceState {
+        serde_json::from_value(serde_json::Value::String(self.state.clone())).unwrap_or_default()
+    }
+
     /// Check if the device is currently online.
     pub fn is_online(&self) -> bool {
         self.state == "ONLINE"
@@ -435,7 +474,7 @@ mod tests {
         let device: DeviceDetails = serde_json::from_value(json_data).unwrap();
 
         assert_eq!(device.id, "497f6eca-6276-4993-bfeb-53cbbbba6f08");
-        assert_eq!(device.mac_address.as_str(), "94:2a:6f:26:c6:ca");
+        assert_eq!(device.mac_address.to_string(), "94:2a:6f:26:c6:ca");
         assert_eq!(device.ip_address.to_string(), "192.168.1.55");
         assert_eq!(device.name, "IW HD");
         assert_eq!(device.model, "UHDIW");
@@ -490,6 +529,10 @@ mod tests {
         assert_eq!(device.radio_count(), 0);
         assert_eq!(device.active_ports().len(), 1);
         assert_eq!(device.inactive_ports().len(), 1);
+
+        let info = device.catalog_info().unwrap();
+        assert_eq!(info.code, "USL24");
+        assert_eq!(info.port_count(), 26);
     }
 
     #[test]
@@ -624,12 +667,14 @@ mod tests {
 
         let port1 = &device.interfaces.ports[0];
         assert_eq!(port1.idx, 1);
-        assert_eq!(port1.max_speed_mbps, 2500);
-        assert_eq!(port1.speed_mbps, Some(1000));
+        assert_eq!(port1.max_speed_mbps.as_mbps(), 2500);
+        assert_eq!(port1.speed_mbps, Bandwidth::from_mbps(1000));
+        assert!(port1.is_below_max_speed());
+        assert_eq!(port1.max_speed_mbps.to_string(), "2.5 Gbps");
 
         let port2 = &device.interfaces.ports[1];
         assert_eq!(port2.idx, 2);
-        assert_eq!(port2.max_speed_mbps, 10000);
+        assert_eq!(port2.max_speed_mbps.as_mbps(), 10000);
 
         // Verify radios
         let radio1 = &device.interfaces.radios[0];
@@ -760,7 +805,7 @@ mod tests {
 
         // Verify all fields deserialized correctly
         assert_eq!(device.id, "497f6eca-6276-4993-bfeb-53cbbbba6f08");
-        assert_eq!(device.mac_address.as_str(), "94:2a:6f:26:c6:ca");
+        assert_eq!(device.mac_address.to_string(), "94:2a:6f:26:c6:ca");
         assert_eq!(device.ip_address.to_string(), "192.168.1.55");
         assert_eq!(device.name, "IW HD");
         assert_eq!(device.model, "UHDIW");
@@ -778,8 +823,8 @@ mod tests {
         assert_eq!(port.idx, 1);
         assert_eq!(port.state, InterfaceState::Up);
         assert_eq!(port.connector, PortConnector::Rj45);
-        assert_eq!(port.max_speed_mbps, 10000);
-        assert_eq!(port.speed_mbps, Some(1000));
+        assert_eq!(port.max_speed_mbps.as_mbps(), 10000);
+        assert_eq!(port.speed_mbps, Bandwidth::from_mbps(1000));
         assert!(port.poe.is_some());
         let poe = port.poe.as_ref().unwrap();
         assert_eq!(poe.standard, Some("802.3bt".to_string()));
@@ -843,8 +888,8 @@ mod tests {
             idx: 1,
             state: InterfaceState::Up,
             connector: PortConnector::Rj45,
-            max_speed_mbps: 1000,
-            speed_mbps: Some(1000),
+            max_speed_mbps: Bandwidth::from_mbps(1000).unwrap(),
+            speed_mbps: Bandwidth::from_mbps(1000),
             poe: Some(PoE {
                 standard: Some("802.3bt".to_string()),
                 r#type: Some(3),
@@ -861,8 +906,8 @@ mod tests {
             idx: 2,
             state: InterfaceState::Up,
             connector: PortConnector::Rj45,
-            max_speed_mbps: 1000,
-            speed_mbps: Some(1000),
+            max_speed_mbps: Bandwidth::from_mbps(1000).unwrap(),
+            speed_mbps: Bandwidth::from_mbps(1000),
             poe: Some(PoE {
                 standard: Some("802.3at".to_string()),
                 r#type: Some(2),
@@ -879,8 +924,8 @@ mod tests {
             idx: 3,
             state: InterfaceState::Up,
             connector: PortConnector::Sfp,
-            max_speed_mbps: 10000,
-            speed_mbps: Some(10000),
+            max_speed_mbps: Bandwidth::from_mbps(10000).unwrap(),
+            speed_mbps: Bandwidth::from_mbps(10000),
             poe: None,
         };
         assert!(!port_no_poe.has_poe());
@@ -894,8 +939,8 @@ mod tests {
             idx: 1,
             state: InterfaceState::Up,
             connector: PortConnector::Rj45,
-            max_speed_mbps: 1000,
-            speed_mbps: Some(1000),
+            max_speed_mbps: Bandwidth::from_mbps(1000).unwrap(),
+            speed_mbps: Bandwidth::from_mbps(1000),
             poe: None,
         };
         assert!(port_up.is_up());
@@ -906,7 +951,7 @@ mod tests {
             idx: 2,
             state: InterfaceState::Down,
             connector: PortConnector::Rj45,
-            max_speed_mbps: 1000,
+            max_speed_mbps: Bandwidth::from_mbps(1000).unwrap(),
             speed_mbps: None,
             poe: None,
         };
@@ -919,8 +964,8 @@ mod tests {
             idx: 3,
             state: InterfaceState::Up,
             connector: PortConnector::SfpPlus,
-            max_speed_mbps: 10000,
-            speed_mbps: Some(10000),
+            max_speed_mbps: Bandwidth::from_mbps(10000).unwrap(),
+            speed_mbps: Bandwidth::from_mbps(10000),
             poe: None,
         };
         assert_eq!(port_10g.speed_gbps(), Some(10.0));
@@ -1,8 +1,11 @@
-use serde::Deserialize;
+use crate::models::common::IsoDateTime;
+use crate::models::units::Bandwidth;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 
 /// Statistics for a device, retrieved from the latest statistics endpoint.
 /// Endpoint: GET /v1/sites/{site_id}/devices/{device_id}/statistics/latest
-#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DeviceStatistics {
     /// Device uptime in seconds.
@@ -10,57 +13,57 @@ pub struct DeviceStatistics {
     pub uptime_sec: u64,
 
     /// Timestamp of the last heartbeat from the device.
-    #[serde(default)]
-    pub last_heartbeat_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_heartbeat_at: Option<IsoDateTime>,
 
     /// Timestamp of the next expected heartbeat from the device.
-    #[serde(default)]
-    pub next_heartbeat_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_heartbeat_at: Option<IsoDateTime>,
 
     /// 1-minute load average.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub load_average_1_min: Option<f64>,
 
     /// 5-minute load average.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub load_average_5_min: Option<f64>,
 
     /// 15-minute load average.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub load_average_15_min: Option<f64>,
 
     /// CPU utilization percentage (0.0 to 100.0).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub cpu_utilization_pct: Option<f64>,
 
     /// Memory utilization percentage (0.0 to 100.0).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub memory_utilization_pct: Option<f64>,
 
     /// Uplink statistics.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub uplink: Option<StatisticsUplink>,
 
     /// Interface statistics.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub interfaces: Option<StatisticsInterfaces>,
 }
 
 /// Uplink statistics showing transmit and receive rates.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StatisticsUplink {
-    /// Transmit rate in bits per second.
+    /// Transmit rate.
     #[serde(default)]
-    pub tx_rate_bps: u64,
+    pub tx_rate_bps: Bandwidth,
 
-    /// Receive rate in bits per second.
+    /// Receive rate.
     #[serde(default)]
-    pub rx_rate_bps: u64,
+    pub rx_rate_bps: Bandwidth,
 }
 
 /// Interface statistics container.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct StatisticsInterfaces {
     /// Radio interface statistics.
@@ -69,15 +72,15 @@ pub struct StatisticsInterfaces {
 }
 
 /// Statistics for a radio interface.
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RadioStatistics {
     /// Radio frequency in GHz (e.g., 2.4 or 5.0).
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub frequency_ghz: Option<f64>,
 
     /// Percentage of transmission retries.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub tx_retries_pct: Option<f64>,
 }
 
@@ -101,9 +104,37 @@ impl DeviceStatistics {
         }
     }
 
+    /// Returns the total uplink throughput (tx + rx).
+    pub fn total_uplink(&self) -> Option<Bandwidth> {
+        self.uplink
+            .as_ref()
+            .map(|u| u.tx_rate_bps.saturating_add(u.rx_rate_bps))
+    }
+
     /// Returns the total uplink throughput (tx + rx) in bits per second.
+    #[deprecated(note = "use `total_uplink`")]
     pub fn total_uplink_bps(&self) -> Option<u64> {
-        self.uplink.as_ref().map(|u| u.tx_rate_bps + u.rx_rate_bps)
+        self.total_uplink().map(|total| total.as_bps())
+    }
+
+    /// Returns true if the next expected heartbeat is earlier than `now`.
+    ///
+    /// Returns false if the controller did not report a next heartbeat.
+    pub fn heartbeat_overdue(&self, now: DateTime<Utc>) -> bool {
+        self.next_heartbeat_at
+            .as_ref()
+            .map(|next| next.as_datetime() < now)
+            .unwrap_or(false)
+    }
+
+    /// Returns the time elapsed since the last heartbeat as of `now`.
+    ///
+    /// Returns a zero duration if the heartbeat is ahead of `now`, e.g. due to
+    /// clock skew between the controller and the caller.
+    pub fn since_last_heartbeat(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.last_heartbeat_at
+            .as_ref()
+            .map(|last| (now - last.as_datetime()).max(Duration::zero()))
     }
 
     /// Returns true if the device has radio interfaces.
@@ -118,12 +149,12 @@ impl DeviceStatistics {
 impl StatisticsUplink {
     /// Returns the transmit rate in megabits per second.
     pub fn tx_rate_mbps(&self) -> f64 {
-        self.tx_rate_bps as f64 / 1_000_000.0
+        self.tx_rate_bps.as_mbps_f64()
     }
 
     /// Returns the receive rate in megabits per second.
     pub fn rx_rate_mbps(&self) -> f64 {
-        self.rx_rate_bps as f64 / 1_000_000.0
+        self.rx_rate_bps.as_mbps_f64()
     }
 }
 
@@ -177,21 +208,25 @@ mod tests {
     }
 
     #[test]
-    fn test_total_uplink_bps_with_uplink() {
+    fn test_total_uplink_with_uplink() {
         let stats = DeviceStatistics {
             uplink: Some(StatisticsUplink {
-                tx_rate_bps: 1_000_000,
-                rx_rate_bps: 2_000_000,
+                tx_rate_bps: Bandwidth::from_bps(1_000_000),
+                rx_rate_bps: Bandwidth::from_bps(2_000_000),
             }),
             ..Default::default()
         };
-        assert_eq!(stats.total_uplink_bps(), Some(3_000_000));
+        assert_eq!(stats.total_uplink(), Some(Bandwidth::from_bps(3_000_000)));
+        assert_eq!(stats.total_uplink().unwrap().to_string(), "3 Mbps");
+        #[allow(deprecated)]
+        let total_bps = stats.total_uplink_bps();
+        assert_eq!(total_bps, Some(3_000_000));
     }
 
     #[test]
-    fn test_total_uplink_bps_without_uplink() {
+    fn test_total_uplink_without_uplink() {
         let stats = DeviceStatistics::default();
-        assert_eq!(stats.total_uplink_bps(), None);
+        assert_eq!(stats.total_uplink(), None);
     }
 
     #[test]
@@ -226,8 +261,8 @@ mod tests {
     #[test]
     fn test_tx_rate_mbps() {
         let uplink = StatisticsUplink {
-            tx_rate_bps: 100_000_000, // 100 Mbps
-            rx_rate_bps: 0,
+            tx_rate_bps: Bandwidth::from_mbps(100).unwrap(),
+            rx_rate_bps: Bandwidth::ZERO,
         };
         assert!((uplink.tx_rate_mbps() - 100.0).abs() < 0.001);
     }
@@ -235,9 +270,32 @@ mod tests {
     #[test]
     fn test_rx_rate_mbps() {
         let uplink = StatisticsUplink {
-            tx_rate_bps: 0,
-            rx_rate_bps: 50_000_000, // 50 Mbps
+            tx_rate_bps: Bandwidth::ZERO,
+            rx_rate_bps: Bandwidth::from_mbps(50).unwrap(),
         };
         assert!((uplink.rx_rate_mbps() - 50.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_heartbeat_overdue() {
+        let stats: DeviceStatistics = serde_json::from_value(serde_json::json!({
+            "uptimeSec": 100,
+            "lastHeartbeatAt": "2024-01-01T12:00:00Z",
+            "nextHeartbeatAt": "2024-01-01T12:00:30Z"
+        }))
+        .unwrap();
+
+        let before: DateTime<Utc> = "2024-01-01T12:00:20Z".parse().unwrap();
+        let after: DateTime<Utc> = "2024-01-01T12:01:00Z".parse().unwrap();
+
+        assert!(!stats.heartbeat_overdue(before));
+        assert!(stats.heartbeat_overdue(after));
+        assert_eq!(
+            stats.since_last_heartbeat(after),
+            Some(Duration::seconds(60))
+        );
+        let skewed: DateTime<Utc> = "2024-01-01T11:59:50Z".parse().unwrap();
+        assert_eq!(stats.since_last_heartbeat(skewed), Some(Duration::zero()));
+        assert!(!DeviceStatistics::default().heartbeat_overdue(after));
+    }
 }
@@ -1,4 +1,509 @@
-use serde::{Deserialize, Serialize};
+use crate::error::{Error, Result};
+use crate::models::common::IsoDateTime;
+use crate::models::wifi::ScheduleWindow;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::net::IpAddr;
+use std::str::FromStr;
+
+/// Implement string-based serde for a type with `Display` and `FromStr`.
+///
+/// Values that do not parse are kept in the type's `Unknown` variant, so a
+/// rule using a form this crate does not know still loads and is sent back
+/// unchanged. `FromStr` itself stays strict.
+macro_rules! serde_via_str {
+    ($ty:ty) => {
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(
+                &self,
+                serializer: S,
+            ) -> std::result::Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(
+                deserializer: D,
+            ) -> std::result::Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                match s.parse() {
+                    Ok(value) => Ok(value),
+                    Err(_) => Ok(<$ty>::Unknown(s)),
+                }
+            }
+        }
+    };
+}
+
+fn invalid(message: String) -> Error {
+    Error::InvalidFirewallRule(message)
+}
+
+/// IP protocol matched by a firewall rule.
+///
+/// Serialized as `"tcp"`, `"udp"`, `"tcp_udp"`, `"icmp"`, `"icmpv6"`, `"all"`
+/// or a protocol number (e.g. `"47"` for GRE).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+    TcpUdp,
+    Icmp,
+    Icmpv6,
+    Any,
+    Number(u8),
+    /// A protocol this crate does not recognize, as received.
+    Unknown(String),
+}
+
+impl Protocol {
+    /// Check if this protocol matches a packet's IP protocol number.
+    ///
+    /// [`Protocol::Unknown`] matches nothing.
+    pub fn matches(&self, number: u8) -> bool {
+        match self {
+            Protocol::Tcp => number == 6,
+            Protocol::Udp => number == 17,
+            Protocol::TcpUdp => number == 6 || number == 17,
+            Protocol::Icmp => number == 1,
+            Protocol::Icmpv6 => number == 58,
+            Protocol::Any => true,
+            Protocol::Number(n) => *n == number,
+            Protocol::Unknown(_) => false,
+        }
+    }
+
+    /// Check if rules with this protocol can match on ports.
+    pub fn has_ports(&self) -> bool {
+        matches!(
+            self,
+            Protocol::Tcp
+                | Protocol::Udp
+                | Protocol::TcpUdp
+                | Protocol::Number(6)
+                | Protocol::Number(17)
+        )
+    }
+}
+
+impl fmt::Display for Protocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Protocol::Tcp => f.write_str("tcp"),
+            Protocol::Udp => f.write_str("udp"),
+            Protocol::TcpUdp => f.write_str("tcp_udp"),
+            Protocol::Icmp => f.write_str("icmp"),
+            Protocol::Icmpv6 => f.write_str("icmpv6"),
+            Protocol::Any => f.write_str("all"),
+            Protocol::Number(n) => write!(f, "{}", n),
+            Protocol::Unknown(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl FromStr for Protocol {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "tcp" => Ok(Protocol::Tcp),
+            "udp" => Ok(Protocol::Udp),
+            "tcp_udp" | "tcp/udp" => Ok(Protocol::TcpUdp),
+            "icmp" => Ok(Protocol::Icmp),
+            "icmpv6" => Ok(Protocol::Icmpv6),
+            "all" | "any" => Ok(Protocol::Any),
+            other => other
+                .parse()
+                .map(Protocol::Number)
+                .map_err(|_| invalid(format!("Unknown protocol: {}", s))),
+        }
+    }
+}
+
+serde_via_str!(Protocol);
+
+/// Destination or source ports matched by a firewall rule.
+///
+/// Serialized as `"443"`, `"8000-8080"` or `"list:<traffic list ID>"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PortMatch {
+    Single(u16),
+    /// Inclusive port range.
+    Range(u16, u16),
+    /// Ports from a traffic matching list.
+    TrafficList(String),
+    /// A port match this crate does not recognize, as received.
+    Unknown(String),
+}
+
+impl PortMatch {
+    /// Create a port range, checking that it starts before it ends.
+    pub fn range(start: u16, end: u16) -> Result<Self> {
+        if start > end {
+            return Err(invalid(format!("Port range {}-{} is reversed", start, end)));
+        }
+        Ok(PortMatch::Range(start, end))
+    }
+
+    /// Check if a port matches. Returns `None` for traffic list references,
+    /// which cannot be resolved without the list, and unknown forms.
+    pub fn contains(&self, port: u16) -> Option<bool> {
+        match self {
+            PortMatch::Single(p) => Some(*p == port),
+            PortMatch::Range(start, end) => Some((*start..=*end).contains(&port)),
+            PortMatch::TrafficList(_) | PortMatch::Unknown(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for PortMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortMatch::Single(port) => write!(f, "{}", port),
+            PortMatch::Range(start, end) => write!(f, "{}-{}", start, end),
+            PortMatch::TrafficList(id) => write!(f, "list:{}", id),
+            PortMatch::Unknown(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl FromStr for PortMatch {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let bad = || invalid(format!("Invalid port: {}", s));
+        if let Some(id) = s.strip_prefix("list:") {
+            return Ok(PortMatch::TrafficList(id.to_string()));
+        }
+        match s.split_once('-') {
+            Some((start, end)) => PortMatch::range(
+                start.trim().parse().map_err(|_| bad())?,
+                end.trim().parse().map_err(|_| bad())?,
+            ),
+            None => s.parse().map(PortMatch::Single).map_err(|_| bad()),
+        }
+    }
+}
+
+serde_via_str!(PortMatch);
+
+/// An IPv4 or IPv6 subnet in CIDR notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpCidr {
+    address: IpAddr,
+    prefix_length: u8,
+}
+
+impl IpCidr {
+    /// Create a subnet, checking the prefix length for the address family.
+    pub fn new(address: IpAddr, prefix_length: u8) -> Result<Self> {
+        let max = if address.is_ipv4() { 32 } else { 128 };
+        if prefix_length > max {
+            return Err(invalid(format!(
+                "Prefix length /{} is too long for {}",
+                prefix_length, address
+            )));
+        }
+        Ok(Self {
+            address,
+            prefix_length,
+        })
+    }
+
+    /// Create a subnet that matches a single address.
+    pub fn host(address: IpAddr) -> Self {
+        let prefix_length = if address.is_ipv4() { 32 } else { 128 };
+        Self {
+            address,
+            prefix_length,
+        }
+    }
+
+    pub fn address(&self) -> IpAddr {
+        self.address
+    }
+
+    pub fn prefix_length(&self) -> u8 {
+        self.prefix_length
+    }
+
+    /// Check if an address is inside the subnet.
+    pub fn contains(&self, address: IpAddr) -> bool {
+        match (self.address, address) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX
+                    .checked_shl(32 - u32::from(self.prefix_length))
+                    .unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX
+                    .checked_shl(128 - u32::from(self.prefix_length))
+                    .unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for IpCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.address, self.prefix_length)
+    }
+}
+
+/// Parse `"10.0.0.0/24"`, or a bare address as a single-host subnet.
+impl FromStr for IpCidr {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        let bad = || invalid(format!("Invalid subnet: {}", s));
+        match s.split_once('/') {
+            Some((address, prefix)) => IpCidr::new(
+                address.parse().map_err(|_| bad())?,
+                prefix.parse().map_err(|_| bad())?,
+            ),
+            None => s.parse().map(IpCidr::host).map_err(|_| bad()),
+        }
+    }
+}
+
+/// An inclusive range of addresses of one family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IpRange {
+    start: IpAddr,
+    end: IpAddr,
+}
+
+impl IpRange {
+    /// Create a range, checking that both ends are of one family and in order.
+    pub fn new(start: IpAddr, end: IpAddr) -> Result<Self> {
+        if start.is_ipv4() != end.is_ipv4() {
+            return Err(invalid(format!(
+                "Range {}-{} mixes IPv4 and IPv6",
+                start, end
+            )));
+        }
+        if start > end {
+            return Err(invalid(format!("Range {}-{} is reversed", start, end)));
+        }
+        Ok(Self { start, end })
+    }
+
+    pub fn start(&self) -> IpAddr {
+        self.start
+    }
+
+    pub fn end(&self) -> IpAddr {
+        self.end
+    }
+
+    /// Check if an address is inside the range.
+    pub fn contains(&self, address: IpAddr) -> bool {
+        address.is_ipv4() == self.start.is_ipv4() && (self.start..=self.end).contains(&address)
+    }
+}
+
+impl fmt::Display for IpRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Addresses matched by a firewall rule.
+///
+/// Serialized as `"10.0.0.5"`, `"10.0.0.0/24"`, `"10.0.0.10-10.0.0.20"`,
+/// `"network:<network ID>"` or `"list:<traffic list ID>"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AddressMatch {
+    /// A single address, written without a prefix length.
+    Host(IpAddr),
+    Cidr(IpCidr),
+    Range(IpRange),
+    /// Every address of a network.
+    Network(String),
+    /// Addresses from a traffic matching list.
+    TrafficList(String),
+    /// An address match this crate does not recognize, as received.
+    Unknown(String),
+}
+
+impl AddressMatch {
+    /// Check if an address matches. Returns `None` for network and traffic
+    /// list references, which cannot be resolved without the referenced data,
+    /// and unknown forms.
+    pub fn contains(&self, address: IpAddr) -> Option<bool> {
+        match self {
+            AddressMatch::Host(host) => Some(*host == address),
+            AddressMatch::Cidr(cidr) => Some(cidr.contains(address)),
+            AddressMatch::Range(range) => Some(range.contains(address)),
+            AddressMatch::Network(_) | AddressMatch::TrafficList(_) | AddressMatch::Unknown(_) => {
+                None
+            }
+        }
+    }
+
+    /// Get the IP version of a literal address match.
+    pub fn ip_version(&self) -> Option<IpVersion> {
+        match self {
+            AddressMatch::Host(host) => Some(IpVersion::of(*host)),
+            AddressMatch::Cidr(cidr) => Some(IpVersion::of(cidr.address())),
+            AddressMatch::Range(range) => Some(IpVersion::of(range.start())),
+            AddressMatch::Network(_) | AddressMatch::TrafficList(_) | AddressMatch::Unknown(_) => {
+                None
+            }
+        }
+    }
+}
+
+impl fmt::Display for AddressMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AddressMatch::Host(host) => write!(f, "{}", host),
+            AddressMatch::Cidr(cidr) => write!(f, "{}", cidr),
+            AddressMatch::Range(range) => write!(f, "{}", range),
+            AddressMatch::Network(id) => write!(f, "network:{}", id),
+            AddressMatch::TrafficList(id) => write!(f, "list:{}", id),
+            AddressMatch::Unknown(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl FromStr for AddressMatch {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if let Some(id) = s.strip_prefix("network:") {
+            return Ok(AddressMatch::Network(id.to_string()));
+        }
+        if let Some(id) = s.strip_prefix("list:") {
+            return Ok(AddressMatch::TrafficList(id.to_string()));
+        }
+        match s.split_once('-') {
+            Some((start, end)) => {
+                let bad = || invalid(format!("Invalid address range: {}", s));
+                let range = IpRange::new(
+                    start.trim().parse().map_err(|_| bad())?,
+                    end.trim().parse().map_err(|_| bad())?,
+                )?;
+                Ok(AddressMatch::Range(range))
+            }
+            None if !s.contains('/') => s
+                .parse()
+                .map(AddressMatch::Host)
+                .map_err(|_| invalid(format!("Invalid address: {}", s))),
+            None => s.parse().map(AddressMatch::Cidr),
+        }
+    }
+}
+
+serde_via_str!(AddressMatch);
+
+/// Connection tracking state matched by a firewall rule.
+///
+/// Serialized as `"NEW"`, `"ESTABLISHED"`, `"RELATED"` or `"INVALID"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ConnectionState {
+    New,
+    Established,
+    Related,
+    Invalid,
+    /// A state this crate does not recognize, as received.
+    Unknown(String),
+}
+
+impl fmt::Display for ConnectionState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectionState::New => f.write_str("NEW"),
+            ConnectionState::Established => f.write_str("ESTABLISHED"),
+            ConnectionState::Related => f.write_str("RELATED"),
+            ConnectionState::Invalid => f.write_str("INVALID"),
+            ConnectionState::Unknown(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl FromStr for ConnectionState {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "NEW" => Ok(ConnectionState::New),
+            "ESTABLISHED" => Ok(ConnectionState::Established),
+            "RELATED" => Ok(ConnectionState::Related),
+            "INVALID" => Ok(ConnectionState::Invalid),
+            _ => Err(invalid(format!("Unknown connection state: {}", s))),
+        }
+    }
+}
+
+serde_via_str!(ConnectionState);
+
+/// IP version matched by a firewall rule.
+///
+/// Serialized as `"IPV4"`, `"IPV6"` or `"BOTH"`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum IpVersion {
+    Ipv4,
+    Ipv6,
+    Both,
+    /// A version this crate does not recognize, as received.
+    Unknown(String),
+}
+
+impl fmt::Display for IpVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IpVersion::Ipv4 => f.write_str("IPV4"),
+            IpVersion::Ipv6 => f.write_str("IPV6"),
+            IpVersion::Both => f.write_str("BOTH"),
+            IpVersion::Unknown(raw) => f.write_str(raw),
+        }
+    }
+}
+
+impl FromStr for IpVersion {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "IPV4" => Ok(IpVersion::Ipv4),
+            "IPV6" => Ok(IpVersion::Ipv6),
+            "BOTH" => Ok(IpVersion::Both),
+            _ => Err(invalid(format!("Unknown IP version: {}", s))),
+        }
+    }
+}
+
+serde_via_str!(IpVersion);
+
+impl IpVersion {
+    /// Get the version of an address.
+    pub fn of(address: IpAddr) -> Self {
+        if address.is_ipv4() {
+            IpVersion::Ipv4
+        } else {
+            IpVersion::Ipv6
+        }
+    }
+
+    /// Check if a rule for this version applies to a packet of `version`.
+    ///
+    /// [`IpVersion::Unknown`] includes nothing.
+    pub fn includes(&self, version: &IpVersion) -> bool {
+        match self {
+            IpVersion::Both => true,
+            IpVersion::Unknown(_) => false,
+            known => known == version,
+        }
+    }
+}
 
 /// Firewall policy action type.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Default)]
@@ -22,43 +527,177 @@ pub struct FirewallAction {
 }
 
 /// Firewall zone.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FirewallZone {
     pub id: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(default)]
     pub networks: Vec<String>,
 }
 
 /// Firewall policy rule.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct FirewallPolicy {
     pub id: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
     #[serde(default)]
     pub action: FirewallAction,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_zone_id: Option<String>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub destination_zone_id: Option<String>,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub order: Option<i32>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Protocol to match, or `None` for any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<Protocol>,
+    /// Source addresses to match. Empty matches any address in the zone.
     #[serde(default)]
-    pub protocol: Option<String>,
+    pub source_addresses: Vec<AddressMatch>,
     #[serde(default)]
-    pub source_addresses: Vec<String>,
+    pub destination_addresses: Vec<AddressMatch>,
+    /// Source ports to match. Empty matches any port.
     #[serde(default)]
-    pub destination_addresses: Vec<String>,
+    pub source_ports: Vec<PortMatch>,
     #[serde(default)]
-    pub source_ports: Vec<String>,
-    #[serde(default)]
-    pub destination_ports: Vec<String>,
+    pub destination_ports: Vec<PortMatch>,
+    /// Connection states to match. Empty matches every state.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub connection_states: Vec<ConnectionState>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip_version: Option<IpVersion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logging_enabled: Option<bool>,
+    /// Times the rule is active. Empty means always.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ScheduleWindow>,
+    /// When the policy was last changed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<IsoDateTime>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_protocol_parse_and_display() {
+        for (wire, protocol) in [
+            ("tcp", Protocol::Tcp),
+            ("tcp_udp", Protocol::TcpUdp),
+            ("icmpv6", Protocol::Icmpv6),
+            ("all", Protocol::Any),
+            ("47", Protocol::Number(47)),
+        ] {
+            assert_eq!(wire.parse::<Protocol>().unwrap(), protocol);
+            assert_eq!(protocol.to_string(), wire);
+        }
+        assert_eq!("TCP/UDP".parse::<Protocol>().unwrap(), Protocol::TcpUdp);
+        assert!("tcp-ish".parse::<Protocol>().is_err());
+        assert!(Protocol::TcpUdp.matches(17));
+        assert!(!Protocol::Icmp.matches(58));
+    }
+
+    #[test]
+    fn test_port_match_parse() {
+        assert_eq!("443".parse::<PortMatch>().unwrap(), PortMatch::Single(443));
+        assert_eq!(
+            "8000-8080".parse::<PortMatch>().unwrap(),
+            PortMatch::Range(8000, 8080)
+        );
+        assert_eq!(
+            "list:tl-1".parse::<PortMatch>().unwrap(),
+            PortMatch::TrafficList("tl-1".to_string())
+        );
+        assert!("8080-8000".parse::<PortMatch>().is_err());
+        assert!("70000".parse::<PortMatch>().is_err());
+        assert!("https".parse::<PortMatch>().is_err());
+
+        assert_eq!(PortMatch::Range(8000, 8080).contains(8080), Some(true));
+        assert_eq!(PortMatch::TrafficList("tl-1".into()).contains(80), None);
+    }
+
+    #[test]
+    fn test_address_match_parse_and_contains() {
+        let cidr: AddressMatch = "10.0.20.0/24".parse().unwrap();
+        assert_eq!(cidr.contains("10.0.20.9".parse().unwrap()), Some(true));
+        assert_eq!(cidr.contains("10.0.21.9".parse().unwrap()), Some(false));
+        assert_eq!(cidr.contains("fd00::1".parse().unwrap()), Some(false));
+
+        let host: AddressMatch = "10.0.0.5".parse().unwrap();
+        assert_eq!(host, AddressMatch::Host("10.0.0.5".parse().unwrap()));
+        assert_eq!(host.to_string(), "10.0.0.5");
+        assert_eq!(
+            "10.0.0.5/32".parse::<AddressMatch>().unwrap().to_string(),
+            "10.0.0.5/32"
+        );
+
+        let v6: AddressMatch = "fd00:20::/64".parse().unwrap();
+        assert_eq!(v6.contains("fd00:20::42".parse().unwrap()), Some(true));
+        assert_eq!(v6.ip_version(), Some(IpVersion::Ipv6));
+
+        let range: AddressMatch = "10.0.0.10-10.0.0.20".parse().unwrap();
+        assert_eq!(range.contains("10.0.0.15".parse().unwrap()), Some(true));
+        assert_eq!(range.contains("10.0.0.21".parse().unwrap()), Some(false));
+
+        assert_eq!(
+            "network:net-1".parse::<AddressMatch>().unwrap(),
+            AddressMatch::Network("net-1".to_string())
+        );
+        assert!("10.0.0.0/33".parse::<AddressMatch>().is_err());
+        assert!("10.0.0.20-10.0.0.10".parse::<AddressMatch>().is_err());
+        assert!("10.0.0.1-fd00::1".parse::<AddressMatch>().is_err());
+        assert!("lan".parse::<AddressMatch>().is_err());
+    }
+
+    #[test]
+    fn test_firewall_policy_keeps_unknown_fields() {
+        let wire = json!({
+            "id": "policy-1",
+            "protocol": "sctp",
+            "sourceAddresses": ["geo:NL"],
+            "destinationAddresses": ["10.0.0.5"],
+            "sourcePorts": [],
+            "destinationPorts": ["53", "dns"],
+            "connectionStates": ["UNTRACKED"],
+            "ipVersion": "IPV5"
+        });
+        let policy: FirewallPolicy = serde_json::from_value(wire.clone()).unwrap();
+
+        assert_eq!(policy.protocol, Some(Protocol::Unknown("sctp".to_string())));
+        assert_eq!(
+            policy.destination_ports,
+            vec![PortMatch::Single(53), PortMatch::Unknown("dns".to_string())]
+        );
+        assert_eq!(
+            policy.connection_states,
+            vec![ConnectionState::Unknown("UNTRACKED".to_string())]
+        );
+        assert_eq!(
+            policy.ip_version,
+            Some(IpVersion::Unknown("IPV5".to_string()))
+        );
+        assert_eq!(
+            policy.source_addresses[0].contains("10.0.0.1".parse().unwrap()),
+            None
+        );
+
+        let mut round_trip = serde_json::to_value(&policy).unwrap();
+        round_trip.as_object_mut().unwrap().remove("action");
+        round_trip.as_object_mut().unwrap().remove("enabled");
+        assert_eq!(round_trip, wire);
+
+        // Parsing outside serde stays strict
+        assert!("sctp".parse::<Protocol>().is_err());
+        assert!("UNTRACKED".parse::<ConnectionState>().is_err());
+    }
 }
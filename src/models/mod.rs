@@ -1,18 +1,25 @@
 pub mod access_point;
+pub mod catalog;
 pub mod client;
 pub mod common;
 pub mod device;
 pub mod device_details;
 pub mod device_statistics;
 pub mod firewall;
+#[cfg(feature = "oui")]
+pub mod oui;
 pub mod site;
 pub mod site_device;
+pub mod units;
 pub mod voucher;
 pub mod wifi;
 
 pub use access_point::APModel;
-pub use client::{AccessType, Client, ClientAccess, ClientType};
-pub use common::{IpAddress, MacAddress, Timestamp};
+pub use catalog::{HardwareModel, ProductFamily, RadioBand};
+pub use client::{
+    AccessType, Client, ClientAccess, ClientType, GuestAuthorization, GuestUsage, SignalQuality,
+};
+pub use common::{IpAddress, IsoDateTime, MacAddress, Timestamp};
 pub use device::{Device, DeviceType};
 pub use device_details::{
     AccessPointFeature, DeviceDetails, DeviceFeatures, DeviceUplink, InterfaceState,
@@ -21,8 +28,15 @@ pub use device_details::{
 pub use device_statistics::{
     DeviceStatistics, RadioStatistics, StatisticsInterfaces, StatisticsUplink,
 };
-pub use firewall::{FirewallAction, FirewallPolicy, FirewallZone};
+pub use firewall::{
+    AddressMatch, ConnectionState, FirewallAction, FirewallPolicy, FirewallZone, IpCidr, IpRange,
+    IpVersion, PortMatch, Protocol,
+};
 pub use site::Site;
 pub use site_device::{DeviceFeature, DeviceInterface, DeviceState, SiteDevice};
-pub use voucher::Voucher;
-pub use wifi::{WifiBroadcast, WifiSecurity};
+pub use units::{Bandwidth, DataSize, Minutes};
+pub use voucher::{Voucher, VoucherStatus};
+pub use wifi::{
+    ApTargeting, MinimumDataRates, Passphrase, PmfMode, Ppsk, ScheduleWindow, WifiBroadcast,
+    WifiSecurity,
+};
@@ -0,0 +1,62 @@
+//! Embedded OUI (Organizationally Unique Identifier) vendor table.
+//!
+//! Enabled with the `oui` cargo feature. The table is parsed from
+//! `oui.txt` on first use.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+const OUI_DATA: &str = include_str!("oui.txt");
+
+fn table() -> &'static HashMap<[u8; 3], &'static str> {
+    static TABLE: OnceLock<HashMap<[u8; 3], &'static str>> = OnceLock::new();
+    TABLE.get_or_init(|| OUI_DATA.lines().filter_map(parse_line).collect())
+}
+
+fn parse_line(line: &'static str) -> Option<([u8; 3], &'static str)> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return None;
+    }
+
+    let (prefix, vendor) = line.split_once('\t')?;
+    let mut oui = [0u8; 3];
+    let mut parts = prefix.split(':');
+    for byte in oui.iter_mut() {
+        *byte = u8::from_str_radix(parts.next()?, 16).ok()?;
+    }
+    Some((oui, vendor.trim()))
+}
+
+/// Look up the vendor name for an OUI.
+pub fn lookup(oui: [u8; 3]) -> Option<&'static str> {
+    table().get(&oui).copied()
+}
+
+/// Get the number of entries in the embedded table.
+pub fn len() -> usize {
+    table().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_line_parses() {
+        let entries = OUI_DATA
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .count();
+
+        assert_eq!(entries, len());
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(lookup([0x94, 0x2a, 0x6f]), Some("Ubiquiti"));
+        assert_eq!(lookup([0xb8, 0x27, 0xeb]), Some("Raspberry Pi"));
+        assert_eq!(lookup([0x12, 0x34, 0x56]), None);
+    }
+}
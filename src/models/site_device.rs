@@ -1,8 +1,9 @@
+use crate::models::catalog::{self, HardwareModel};
 use crate::models::common::{IpAddress, MacAddress};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Device state as returned by the new site devices API.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum DeviceState {
     Online,
@@ -22,7 +23,7 @@ pub enum DeviceState {
 }
 
 /// Device feature capabilities.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum DeviceFeature {
     Switching,
@@ -33,7 +34,7 @@ pub enum DeviceFeature {
 }
 
 /// Device interface types.
-#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum DeviceInterface {
     Ports,
@@ -44,7 +45,7 @@ pub enum DeviceInterface {
 
 /// Device model for the new site-scoped API.
 /// Endpoint: GET /v1/sites/{siteId}/devices
-#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct SiteDevice {
     /// Unique device identifier (UUID format)
@@ -54,7 +55,7 @@ pub struct SiteDevice {
     pub mac_address: MacAddress,
 
     /// IP address of the device
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ip_address: Option<IpAddress>,
 
     /// Display name of the device
@@ -68,15 +69,15 @@ pub struct SiteDevice {
     pub state: DeviceState,
 
     /// Whether the device is supported
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub supported: Option<bool>,
 
     /// Current firmware version
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub firmware_version: Option<String>,
 
     /// Whether firmware can be updated
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub firmware_updatable: Option<bool>,
 
     /// List of device features (e.g., Switching, AccessPoint, Gateway)
@@ -89,6 +90,11 @@ pub struct SiteDevice {
 }
 
 impl SiteDevice {
+    /// Look up the hardware capabilities for this device's model.
+    pub fn catalog_info(&self) -> Option<&'static HardwareModel> {
+        catalog::lookup(&self.model)
+    }
+
     /// Check if the device is currently online.
     pub fn is_online(&self) -> bool {
         self.state == DeviceState::Online
@@ -174,7 +180,7 @@ mod tests {
         let device: SiteDevice = serde_json::from_value(json_data).unwrap();
 
         assert_eq!(device.id, "497f6eca-6276-4993-bfeb-53cbbbba6f08");
-        assert_eq!(device.mac_address.as_str(), "94:2a:6f:26:c6:ca");
+        assert_eq!(device.mac_address.to_string(), "94:2a:6f:26:c6:ca");
         assert_eq!(
             device.ip_address.as_ref().unwrap().to_string(),
             "192.168.1.55"
@@ -400,4 +406,20 @@ mod tests {
         assert!(gateway_device.has_switching());
         assert!(gateway_device.is_gateway());
     }
+
+    #[test]
+    fn test_site_device_catalog_info() {
+        let device: SiteDevice = serde_json::from_value(json!({
+            "id": "switch-1",
+            "macAddress": "94:2a:6f:00:00:01",
+            "name": "Core Switch",
+            "model": "US24PRO",
+            "state": "ONLINE"
+        }))
+        .unwrap();
+
+        let info = device.catalog_info().unwrap();
+        assert_eq!(info.sku, "USW-Pro-24-PoE");
+        assert_eq!(info.poe_budget_watts, Some(400));
+    }
 }
@@ -0,0 +1,630 @@
+//! Typed units for bandwidth, data size and durations.
+//!
+//! The API mixes units freely: voucher bandwidth limits are in kbps, uplink
+//! rates in bps and port speeds in Mbps. These newtypes store a single base unit
+//! ([`Bandwidth`] in bits per second, [`DataSize`] in bytes, [`Minutes`] in
+//! minutes) and convert to the unit a field expects when serialized, so a value
+//! can't be sent in the wrong unit by accident.
+//!
+//! All multiples are decimal (SI): 1 Mbps is 1,000,000 bps and 1 MB is
+//! 1,000,000 bytes. Parsing also accepts binary data sizes (`KiB`, `MiB`, ...).
+//!
+//! ```
+//! use rustifi::models::{Bandwidth, DataSize, Minutes};
+//!
+//! let limit: Bandwidth = "10Mbps".parse().unwrap();
+//! assert_eq!(limit.as_kbps(), 10_000);
+//! assert_eq!(Bandwidth::from_bps(1_200_000_000).to_string(), "1.2 Gbps");
+//!
+//! assert_eq!(DataSize::from_mb(500).unwrap().to_string(), "500 MB");
+//! assert_eq!("1h30m".parse::<Minutes>().unwrap(), Minutes::new(90));
+//! ```
+
+use crate::error::Error;
+use ::serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+const BANDWIDTH_UNITS: &[(u64, &str)] = &[
+    (1_000_000_000_000, "Tbps"),
+    (1_000_000_000, "Gbps"),
+    (1_000_000, "Mbps"),
+    (1_000, "kbps"),
+    (1, "bps"),
+];
+
+const DATA_SIZE_UNITS: &[(u64, &str)] = &[
+    (1_000_000_000_000, "TB"),
+    (1_000_000_000, "GB"),
+    (1_000_000, "MB"),
+    (1_000, "KB"),
+    (1, "B"),
+];
+
+const BINARY_DATA_SIZE_UNITS: &[(u64, &str)] = &[
+    (1 << 40, "TiB"),
+    (1 << 30, "GiB"),
+    (1 << 20, "MiB"),
+    (1 << 10, "KiB"),
+];
+
+/// Format `value` in the largest unit it reaches, with up to two decimals.
+///
+/// A value that rounds up to the next unit is shown in that unit, so 999,999
+/// bps reads "1 Mbps" rather than "1000 kbps".
+fn format_scaled(f: &mut fmt::Formatter<'_>, value: u64, units: &[(u64, &str)]) -> fmt::Result {
+    let rounded = |scale: u64| (value as f64 / scale as f64 * 100.0).round() / 100.0;
+    let index = units
+        .iter()
+        .position(|(scale, _)| value >= *scale)
+        .unwrap_or(units.len() - 1);
+    let (mut scale, mut unit) = units[index];
+    if index > 0
+        && !value.is_multiple_of(scale)
+        && rounded(scale) * scale as f64 >= units[index - 1].0 as f64
+    {
+        (scale, unit) = units[index - 1];
+    }
+
+    if value.is_multiple_of(scale) {
+        return write!(f, "{} {}", value / scale, unit);
+    }
+
+    let scaled = format!("{:.2}", rounded(scale));
+    let scaled = scaled.trim_end_matches('0').trim_end_matches('.');
+    write!(f, "{} {}", scaled, unit)
+}
+
+/// Parse a number followed by an optional unit (e.g. "10Mbps", "1.5 GB").
+///
+/// A bare number is interpreted in the base unit. Returns `None` for unknown
+/// units, negative or malformed numbers, and values that overflow a `u64`.
+fn parse_scaled(s: &str, units: &[&[(u64, &str)]]) -> Option<u64> {
+    let s = s.trim();
+    let split = s
+        .find(|c: char| c.is_ascii_alphabetic() || c == '/')
+        .unwrap_or(s.len());
+    let (number, unit) = (s[..split].trim(), s[split..].trim());
+
+    let scale = if unit.is_empty() {
+        1
+    } else {
+        units
+            .iter()
+            .flat_map(|table| table.iter())
+            .find(|(_, name)| name.eq_ignore_ascii_case(unit))
+            .map(|(scale, _)| *scale)?
+    };
+
+    if let Ok(whole) = number.parse::<u64>() {
+        return whole.checked_mul(scale);
+    }
+
+    let value = number.parse::<f64>().ok()? * scale as f64;
+    if value.is_finite() && value >= 0.0 && value < u64::MAX as f64 {
+        Some(value.round() as u64)
+    } else {
+        None
+    }
+}
+
+/// A data rate, stored in bits per second.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Bandwidth(u64);
+
+impl Bandwidth {
+    pub const ZERO: Bandwidth = Bandwidth(0);
+
+    pub const fn from_bps(bps: u64) -> Self {
+        Bandwidth(bps)
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_kbps(kbps: u64) -> Option<Self> {
+        match kbps.checked_mul(1_000) {
+            Some(bps) => Some(Bandwidth(bps)),
+            None => None,
+        }
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_mbps(mbps: u64) -> Option<Self> {
+        match mbps.checked_mul(1_000_000) {
+            Some(bps) => Some(Bandwidth(bps)),
+            None => None,
+        }
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_gbps(gbps: u64) -> Option<Self> {
+        match gbps.checked_mul(1_000_000_000) {
+            Some(bps) => Some(Bandwidth(bps)),
+            None => None,
+        }
+    }
+
+    pub const fn as_bps(&self) -> u64 {
+        self.0
+    }
+
+    /// Get the rate in whole kbps, rounded down.
+    pub const fn as_kbps(&self) -> u64 {
+        self.0 / 1_000
+    }
+
+    /// Get the rate in whole Mbps, rounded down.
+    pub const fn as_mbps(&self) -> u64 {
+        self.0 / 1_000_000
+    }
+
+    pub fn as_mbps_f64(&self) -> f64 {
+        self.0 as f64 / 1_000_000.0
+    }
+
+    pub fn as_gbps_f64(&self) -> f64 {
+        self.0 as f64 / 1_000_000_000.0
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(bps) => Some(Bandwidth(bps)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(bps) => Some(Bandwidth(bps)),
+            None => None,
+        }
+    }
+
+    pub const fn saturating_add(self, other: Self) -> Self {
+        Bandwidth(self.0.saturating_add(other.0))
+    }
+}
+
+impl fmt::Display for Bandwidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_scaled(f, self.0, BANDWIDTH_UNITS)
+    }
+}
+
+impl FromStr for Bandwidth {
+    type Err = Error;
+
+    /// Parse a rate like "10Mbps", "1.5 Gbps" or "512 kbps". A bare number is bps.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_scaled(s, &[BANDWIDTH_UNITS])
+            .map(Bandwidth)
+            .ok_or_else(|| Error::InvalidQuantity(format!("bandwidth {:?}", s)))
+    }
+}
+
+/// An amount of data, stored in bytes.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct DataSize(u64);
+
+impl DataSize {
+    pub const ZERO: DataSize = DataSize(0);
+
+    pub const fn from_bytes(bytes: u64) -> Self {
+        DataSize(bytes)
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_kb(kb: u64) -> Option<Self> {
+        match kb.checked_mul(1_000) {
+            Some(bytes) => Some(DataSize(bytes)),
+            None => None,
+        }
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_mb(mb: u64) -> Option<Self> {
+        match mb.checked_mul(1_000_000) {
+            Some(bytes) => Some(DataSize(bytes)),
+            None => None,
+        }
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_gb(gb: u64) -> Option<Self> {
+        match gb.checked_mul(1_000_000_000) {
+            Some(bytes) => Some(DataSize(bytes)),
+            None => None,
+        }
+    }
+
+    pub const fn as_bytes(&self) -> u64 {
+        self.0
+    }
+
+    /// Get the size in whole megabytes, rounded down.
+    pub const fn as_mb(&self) -> u64 {
+        self.0 / 1_000_000
+    }
+
+    pub fn as_gb_f64(&self) -> f64 {
+        self.0 as f64 / 1_000_000_000.0
+    }
+
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(bytes) => Some(DataSize(bytes)),
+            None => None,
+        }
+    }
+
+    pub const fn checked_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(bytes) => Some(DataSize(bytes)),
+            None => None,
+        }
+    }
+
+    pub const fn saturating_add(self, other: Self) -> Self {
+        DataSize(self.0.saturating_add(other.0))
+    }
+}
+
+impl fmt::Display for DataSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        format_scaled(f, self.0, DATA_SIZE_UNITS)
+    }
+}
+
+impl FromStr for DataSize {
+    type Err = Error;
+
+    /// Parse a size like "500MB", "1.5 GB" or "2GiB". A bare number is bytes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_scaled(s, &[DATA_SIZE_UNITS, BINARY_DATA_SIZE_UNITS])
+            .map(DataSize)
+            .ok_or_else(|| Error::InvalidQuantity(format!("data size {:?}", s)))
+    }
+}
+
+/// A whole number of minutes, as used for voucher and guest durations.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct Minutes(u32);
+
+impl Minutes {
+    pub const fn new(minutes: u32) -> Self {
+        Minutes(minutes)
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_hours(hours: u32) -> Option<Self> {
+        match hours.checked_mul(60) {
+            Some(minutes) => Some(Minutes(minutes)),
+            None => None,
+        }
+    }
+
+    /// Returns `None` if the value overflows.
+    pub const fn from_days(days: u32) -> Option<Self> {
+        match days.checked_mul(24 * 60) {
+            Some(minutes) => Some(Minutes(minutes)),
+            None => None,
+        }
+    }
+
+    /// Convert a duration, rounding down to whole minutes.
+    ///
+    /// Returns `None` for negative durations and durations that overflow.
+    pub fn from_duration(duration: chrono::Duration) -> Option<Self> {
+        u32::try_from(duration.num_minutes()).ok().map(Minutes)
+    }
+
+    pub const fn get(&self) -> u32 {
+        self.0
+    }
+
+    pub fn as_duration(&self) -> chrono::Duration {
+        chrono::Duration::minutes(i64::from(self.0))
+    }
+
+    pub const fn checked_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(minutes) => Some(Minutes(minutes)),
+            None => None,
+        }
+    }
+}
+
+impl From<Minutes> for chrono::Duration {
+    fn from(minutes: Minutes) -> Self {
+        minutes.as_duration()
+    }
+}
+
+impl fmt::Display for Minutes {
+    /// Formats as days, hours and minutes, omitting zero parts (e.g. "1d 2h", "90m" → "1h 30m").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (days, hours, mins) = (self.0 / 1440, self.0 % 1440 / 60, self.0 % 60);
+        let parts: Vec<String> = [(days, "d"), (hours, "h"), (mins, "m")]
+            .iter()
+            .filter(|(value, _)| *value > 0)
+            .map(|(value, unit)| format!("{}{}", value, unit))
+            .collect();
+
+        if parts.is_empty() {
+            write!(f, "0m")
+        } else {
+            write!(f, "{}", parts.join(" "))
+        }
+    }
+}
+
+impl FromStr for Minutes {
+    type Err = Error;
+
+    /// Parse a duration like "90", "90m", "2h", "1d" or "1h 30m". A bare number is minutes.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::InvalidQuantity(format!("duration {:?}", s));
+
+        let trimmed = s.trim();
+        if let Ok(minutes) = trimmed.parse::<u32>() {
+            return Ok(Minutes(minutes));
+        }
+
+        let mut total: u32 = 0;
+        let mut rest = trimmed;
+        while !rest.is_empty() {
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .ok_or_else(invalid)?;
+            let value: u32 = rest[..digits].parse().map_err(|_| invalid())?;
+            let after = rest[digits..].trim_start();
+            let unit_len = after
+                .find(|c: char| !c.is_ascii_alphabetic())
+                .unwrap_or(after.len());
+            let scale = match after[..unit_len].to_ascii_lowercase().as_str() {
+                "m" | "min" | "mins" => 1,
+                "h" | "hr" | "hrs" => 60,
+                "d" | "day" | "days" => 1440,
+                _ => return Err(invalid()),
+            };
+            total = value
+                .checked_mul(scale)
+                .and_then(|v| total.checked_add(v))
+                .ok_or_else(invalid)?;
+            rest = after[unit_len..].trim_start();
+        }
+
+        if trimmed.is_empty() {
+            Err(invalid())
+        } else {
+            Ok(Minutes(total))
+        }
+    }
+}
+
+/// Serde adapters for fields whose wire format uses a specific unit.
+///
+/// Serialization fails instead of silently rounding when a value is not a
+/// whole number of the wire unit, and deserialization fails on overflow.
+///
+/// ```
+/// use rustifi::models::Bandwidth;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Limits {
+///     #[serde(with = "rustifi::models::units::serde::kbps")]
+///     down: Bandwidth,
+/// }
+/// ```
+pub mod serde {
+    macro_rules! unit_adapter {
+        ($name:ident, $ty:ident, $scale:expr, $unit:expr) => {
+            #[doc = concat!("Serialize a [`", stringify!($ty), "`](super::", stringify!($ty), ") as a whole number of ", $unit, ".")]
+            pub mod $name {
+                use super::super::$ty;
+                use ::serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+                const SCALE: u64 = $scale;
+
+                pub fn serialize<S: Serializer>(value: &$ty, serializer: S) -> Result<S::Ok, S::Error> {
+                    if !value.0.is_multiple_of(SCALE) {
+                        return Err(ser::Error::custom(format!(
+                            "{} is not a whole number of {}",
+                            value, $unit
+                        )));
+                    }
+                    serializer.serialize_u64(value.0 / SCALE)
+                }
+
+                pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<$ty, D::Error> {
+                    let n = u64::deserialize(deserializer)?;
+                    n.checked_mul(SCALE)
+                        .map($ty)
+                        .ok_or_else(|| de::Error::custom(format!("{} {} overflows", n, $unit)))
+                }
+
+                /// The same adapter for `Option` fields.
+                pub mod option {
+                    use super::$ty;
+                    use ::serde::{Deserialize, Deserializer, Serializer};
+
+                    pub fn serialize<S: Serializer>(
+                        value: &Option<$ty>,
+                        serializer: S,
+                    ) -> Result<S::Ok, S::Error> {
+                        match value {
+                            Some(value) => super::serialize(value, serializer),
+                            None => serializer.serialize_none(),
+                        }
+                    }
+
+                    pub fn deserialize<'de, D: Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> Result<Option<$ty>, D::Error> {
+                        #[derive(Deserialize)]
+                        struct Wrapper(#[serde(with = "super")] $ty);
+
+                        Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|w| w.0))
+                    }
+                }
+            }
+        };
+    }
+
+    unit_adapter!(kbps, Bandwidth, 1_000, "kbps");
+    unit_adapter!(mbps, Bandwidth, 1_000_000, "Mbps");
+    unit_adapter!(megabytes, DataSize, 1_000_000, "MB");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bandwidth_display() {
+        assert_eq!(Bandwidth::from_bps(0).to_string(), "0 bps");
+        assert_eq!(Bandwidth::from_bps(999).to_string(), "999 bps");
+        assert_eq!(Bandwidth::from_kbps(512).unwrap().to_string(), "512 kbps");
+        assert_eq!(Bandwidth::from_mbps(10).unwrap().to_string(), "10 Mbps");
+        assert_eq!(Bandwidth::from_bps(1_200_000_000).to_string(), "1.2 Gbps");
+        assert_eq!(Bandwidth::from_bps(2_500_000).to_string(), "2.5 Mbps");
+        assert_eq!(Bandwidth::from_bps(1_234_567).to_string(), "1.23 Mbps");
+        assert_eq!(Bandwidth::from_bps(999_999).to_string(), "1 Mbps");
+        assert_eq!(Bandwidth::from_bps(999_994).to_string(), "999.99 kbps");
+    }
+
+    #[test]
+    fn test_bandwidth_parse() {
+        assert_eq!(
+            "10Mbps".parse::<Bandwidth>().unwrap(),
+            Bandwidth::from_mbps(10).unwrap()
+        );
+        assert_eq!(
+            " 1.5 gbps ".parse::<Bandwidth>().unwrap(),
+            Bandwidth::from_bps(1_500_000_000)
+        );
+        assert_eq!("512 kbps".parse::<Bandwidth>().unwrap().as_kbps(), 512);
+        assert_eq!("2000".parse::<Bandwidth>().unwrap().as_bps(), 2000);
+
+        for invalid in [
+            "",
+            "fast",
+            "10 MB",
+            "-1Mbps",
+            "1e30 Tbps",
+            "99999999999 Tbps",
+        ] {
+            assert!(
+                matches!(invalid.parse::<Bandwidth>(), Err(Error::InvalidQuantity(_))),
+                "{invalid:?} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn test_bandwidth_checked_conversions() {
+        assert!(Bandwidth::from_gbps(u64::MAX / 1000).is_none());
+        assert_eq!(Bandwidth::from_mbps(1).unwrap().as_kbps(), 1000);
+        assert_eq!(Bandwidth::from_bps(1_999_999).as_mbps(), 1);
+
+        let a = Bandwidth::from_mbps(5).unwrap();
+        let b = Bandwidth::from_mbps(3).unwrap();
+        assert_eq!(a.checked_sub(b), Bandwidth::from_mbps(2));
+        assert_eq!(b.checked_sub(a), None);
+        assert_eq!(Bandwidth::from_bps(u64::MAX).checked_add(a), None);
+    }
+
+    #[test]
+    fn test_data_size() {
+        assert_eq!(DataSize::from_mb(500).unwrap().to_string(), "500 MB");
+        assert_eq!(DataSize::from_bytes(1_500_000_000).to_string(), "1.5 GB");
+        assert_eq!(DataSize::from_bytes(12).to_string(), "12 B");
+        assert_eq!(DataSize::from_bytes(999_999_999).to_string(), "1 GB");
+        assert_eq!(
+            "500MB".parse::<DataSize>().unwrap(),
+            DataSize::from_mb(500).unwrap()
+        );
+        assert_eq!("2 GiB".parse::<DataSize>().unwrap().as_bytes(), 2 << 30);
+        assert_eq!("1.5kb".parse::<DataSize>().unwrap().as_bytes(), 1500);
+        assert!("10 Mbps".parse::<DataSize>().is_err());
+    }
+
+    #[test]
+    fn test_minutes() {
+        assert_eq!(Minutes::new(0).to_string(), "0m");
+        assert_eq!(Minutes::new(90).to_string(), "1h 30m");
+        assert_eq!(Minutes::from_days(2).unwrap().to_string(), "2d");
+        assert_eq!(Minutes::new(1500).to_string(), "1d 1h");
+
+        assert_eq!("90".parse::<Minutes>().unwrap(), Minutes::new(90));
+        assert_eq!("1h30m".parse::<Minutes>().unwrap(), Minutes::new(90));
+        assert_eq!("1d 2h".parse::<Minutes>().unwrap(), Minutes::new(1560));
+        assert_eq!("45 min".parse::<Minutes>().unwrap(), Minutes::new(45));
+        for invalid in ["", "h", "1w", "1.5h", "99999999d"] {
+            assert!(invalid.parse::<Minutes>().is_err(), "{invalid:?}");
+        }
+
+        assert!(Minutes::from_hours(u32::MAX).is_none());
+        assert_eq!(
+            Minutes::from_duration(chrono::Duration::seconds(150)),
+            Some(Minutes::new(2))
+        );
+        assert_eq!(Minutes::from_duration(chrono::Duration::minutes(-1)), None);
+        assert_eq!(
+            chrono::Duration::from(Minutes::new(3)),
+            chrono::Duration::minutes(3)
+        );
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Limits {
+        #[serde(with = "super::serde::kbps")]
+        down: Bandwidth,
+        #[serde(
+            default,
+            with = "super::serde::megabytes::option",
+            skip_serializing_if = "Option::is_none"
+        )]
+        quota: Option<DataSize>,
+    }
+
+    #[test]
+    fn test_unit_adapters() {
+        let limits: Limits =
+            serde_json::from_value(serde_json::json!({"down": 10000, "quota": 500})).unwrap();
+        assert_eq!(limits.down, Bandwidth::from_mbps(10).unwrap());
+        assert_eq!(limits.quota, DataSize::from_mb(500));
+        assert_eq!(
+            serde_json::to_value(&limits).unwrap(),
+            serde_json::json!({"down": 10000, "quota": 500})
+        );
+
+        let missing: Limits = serde_json::from_value(serde_json::json!({"down": 1})).unwrap();
+        assert_eq!(missing.quota, None);
+
+        let inexact = Limits {
+            down: Bandwidth::from_bps(1500),
+            quota: None,
+        };
+        assert!(serde_json::to_value(&inexact).is_err());
+
+        assert!(serde_json::from_value::<Limits>(serde_json::json!({"down": u64::MAX})).is_err());
+    }
+}
@@ -1,7 +1,10 @@
-use serde::Deserialize;
+use crate::models::common::IsoDateTime;
+use crate::models::units::{self, Bandwidth, DataSize, Minutes};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
 
 /// Hotspot voucher for guest network access.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Voucher {
     /// Unique voucher identifier.
@@ -10,35 +13,239 @@ pub struct Voucher {
     /// Human-readable voucher code for guest entry.
     pub code: String,
 
+    /// Name the vouchers were generated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
     /// Whether the voucher has been redeemed.
     #[serde(default)]
     pub used: bool,
 
-    /// Access duration in minutes once activated.
-    #[serde(default)]
-    pub duration_minutes: Option<u32>,
+    /// Access duration once activated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration_minutes: Option<Minutes>,
 
-    /// Data usage limit in megabytes.
-    #[serde(default)]
-    pub data_limit_mb: Option<u64>,
+    /// Data usage limit (sent in megabytes).
+    #[serde(
+        default,
+        with = "units::serde::megabytes::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub data_limit_mb: Option<DataSize>,
 
-    /// Download bandwidth limit in kbps.
-    #[serde(default)]
-    pub bandwidth_limit_down: Option<u64>,
+    /// Download bandwidth limit (sent in kbps).
+    #[serde(
+        default,
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bandwidth_limit_down: Option<Bandwidth>,
 
-    /// Upload bandwidth limit in kbps.
-    #[serde(default)]
-    pub bandwidth_limit_up: Option<u64>,
+    /// Upload bandwidth limit (sent in kbps).
+    #[serde(
+        default,
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub bandwidth_limit_up: Option<Bandwidth>,
 
     /// Optional admin note for the voucher.
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub note: Option<String>,
 
-    /// ISO 8601 timestamp when the voucher was created.
-    #[serde(default)]
-    pub created_at: Option<String>,
+    /// When the voucher was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<IsoDateTime>,
 
-    /// ISO 8601 timestamp when the voucher was redeemed.
-    #[serde(default)]
-    pub used_at: Option<String>,
+    /// When the voucher was redeemed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub used_at: Option<IsoDateTime>,
+
+    /// Last moment the voucher can be redeemed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<IsoDateTime>,
+
+    /// Number of guests that may redeem the voucher, or `None` for unlimited.
+    #[serde(
+        default,
+        rename = "authorizedGuestLimit",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub guest_limit: Option<u32>,
+
+    /// Number of guests that have redeemed the voucher.
+    #[serde(
+        default,
+        rename = "authorizedGuestCount",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub guest_count: Option<u32>,
+}
+
+/// Lifecycle state of a voucher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VoucherStatus {
+    /// Not redeemed yet and still redeemable.
+    Unused,
+    /// Redeemed, with access time and guest slots left.
+    Active,
+    /// The access period has ended, or the voucher was not redeemed in time.
+    Expired,
+    /// Every guest slot has been redeemed and no access period is running,
+    /// e.g. because the voucher has no duration. A fully used voucher whose
+    /// access period is still running is [`Active`](Self::Active).
+    Exhausted,
+}
+
+impl Voucher {
+    /// Get when the voucher's access period ends.
+    ///
+    /// The duration starts when the voucher is redeemed, so this is `None` for
+    /// unused vouchers and vouchers without a duration.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        let used_at = self.used_at.as_ref()?.as_datetime();
+        Some(used_at + self.duration_minutes?.as_duration())
+    }
+
+    /// Get the access time left as of `now`, if the voucher has been redeemed.
+    ///
+    /// Returns a zero duration once the voucher has expired.
+    pub fn remaining_at(&self, now: DateTime<Utc>) -> Option<Duration> {
+        self.expires_at()
+            .map(|expires| (expires - now).max(Duration::zero()))
+    }
+
+    /// Check if every guest slot has been redeemed.
+    pub fn is_exhausted(&self) -> bool {
+        match self.guest_limit {
+            Some(limit) => self.guest_count.unwrap_or(self.used as u32) >= limit,
+            None => false,
+        }
+    }
+
+    /// Classify the voucher as of `now`.
+    pub fn status_at(&self, now: DateTime<Utc>) -> VoucherStatus {
+        let redeemed = self.used || self.used_at.is_some() || self.guest_count.unwrap_or(0) > 0;
+        let past_validity = self
+            .valid_until
+            .as_ref()
+            .is_some_and(|until| until.as_datetime() <= now);
+        let access_ended = self.expires_at().is_some_and(|expires| expires <= now);
+        let in_use = self.expires_at().is_some_and(|expires| expires > now);
+
+        if access_ended || (!redeemed && past_validity) {
+            VoucherStatus::Expired
+        } else if self.is_exhausted() && !in_use {
+            VoucherStatus::Exhausted
+        } else if redeemed {
+            VoucherStatus::Active
+        } else {
+            VoucherStatus::Unused
+        }
+    }
+
+    /// Classify the voucher as of the current time.
+    pub fn status(&self) -> VoucherStatus {
+        self.status_at(Utc::now())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_voucher_expires_at() {
+        let voucher: Voucher = serde_json::from_value(json!({
+            "id": "voucher-1",
+            "code": "12345-67890",
+            "used": true,
+            "durationMinutes": 60,
+            "dataLimitMb": 500,
+            "bandwidthLimitDown": 10000,
+            "createdAt": "2024-01-01T08:00:00Z",
+            "usedAt": "2024-01-01T12:00:00+02:00"
+        }))
+        .unwrap();
+
+        let expires = voucher.expires_at().unwrap();
+        assert_eq!(expires.to_rfc3339(), "2024-01-01T11:00:00+00:00");
+        assert_eq!(
+            voucher.used_at.as_ref().unwrap().raw(),
+            "2024-01-01T12:00:00+02:00"
+        );
+
+        assert_eq!(voucher.duration_minutes, Some(Minutes::new(60)));
+        assert_eq!(voucher.data_limit_mb.unwrap().to_string(), "500 MB");
+        assert_eq!(voucher.bandwidth_limit_down.unwrap().to_string(), "10 Mbps");
+
+        let now: DateTime<Utc> = "2024-01-01T10:45:00Z".parse().unwrap();
+        assert_eq!(voucher.remaining_at(now), Some(Duration::minutes(15)));
+    }
+
+    #[test]
+    fn test_unused_voucher_has_no_expiry() {
+        let voucher: Voucher = serde_json::from_value(json!({
+            "id": "voucher-2",
+            "code": "11111-22222",
+            "durationMinutes": 60
+        }))
+        .unwrap();
+
+        assert!(voucher.expires_at().is_none());
+    }
+
+    #[test]
+    fn test_voucher_status() {
+        let now: DateTime<Utc> = "2024-01-02T12:00:00Z".parse().unwrap();
+        let voucher = |value: serde_json::Value| -> Voucher {
+            let mut base = json!({"id": "voucher-1", "code": "12345-67890"});
+            base.as_object_mut()
+                .unwrap()
+                .extend(value.as_object().unwrap().clone());
+            serde_json::from_value(base).unwrap()
+        };
+
+        let unused = voucher(json!({"validUntil": "2024-01-03T00:00:00Z"}));
+        assert_eq!(unused.status_at(now), VoucherStatus::Unused);
+
+        let stale = voucher(json!({"validUntil": "2024-01-02T00:00:00Z"}));
+        assert_eq!(stale.status_at(now), VoucherStatus::Expired);
+
+        let active = voucher(json!({
+            "used": true,
+            "durationMinutes": 1440,
+            "usedAt": "2024-01-02T08:00:00Z",
+            "validUntil": "2024-01-02T09:00:00Z",
+            "authorizedGuestLimit": 3,
+            "authorizedGuestCount": 2
+        }));
+        assert_eq!(active.status_at(now), VoucherStatus::Active);
+
+        let single_use = voucher(json!({
+            "used": true,
+            "durationMinutes": 600,
+            "usedAt": "2024-01-02T11:55:00Z",
+            "authorizedGuestLimit": 1
+        }));
+        assert!(single_use.is_exhausted());
+        assert_eq!(single_use.status_at(now), VoucherStatus::Active);
+
+        let exhausted = voucher(json!({
+            "used": true,
+            "usedAt": "2024-01-02T08:00:00Z",
+            "authorizedGuestLimit": 1
+        }));
+        assert!(exhausted.is_exhausted());
+        assert_eq!(exhausted.status_at(now), VoucherStatus::Exhausted);
+
+        let expired = voucher(json!({
+            "used": true,
+            "durationMinutes": 60,
+            "usedAt": "2024-01-02T08:00:00Z",
+            "authorizedGuestLimit": 1
+        }));
+        assert_eq!(expired.status_at(now), VoucherStatus::Expired);
+    }
 }
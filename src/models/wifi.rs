@@ -1,4 +1,8 @@
+use crate::models::catalog::RadioBand;
+use crate::models::units::{self, Bandwidth};
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
 use serde::{Deserialize, Serialize};
+use std::fmt;
 
 /// WiFi security type.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
@@ -7,23 +11,283 @@ pub enum WifiSecurity {
     Open,
     Wpa2,
     Wpa3,
+    /// WPA2/WPA3 transition mode, for networks with older clients.
+    Wpa2Wpa3,
     WpaEnterprise,
 }
 
+/// Protected Management Frames (802.11w) mode.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PmfMode {
+    Disabled,
+    Optional,
+    Required,
+}
+
+/// Minimum data rate per band. Clients that cannot sustain it are not served.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct MinimumDataRates {
+    /// Minimum 2.4 GHz rate (sent in kbps).
+    #[serde(
+        default,
+        rename = "band2_4GHzKbps",
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub band_2_4ghz: Option<Bandwidth>,
+    /// Minimum 5 GHz rate (sent in kbps).
+    #[serde(
+        default,
+        rename = "band5GHzKbps",
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub band_5ghz: Option<Bandwidth>,
+    /// Minimum 6 GHz rate (sent in kbps).
+    #[serde(
+        default,
+        rename = "band6GHzKbps",
+        with = "units::serde::kbps::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub band_6ghz: Option<Bandwidth>,
+}
+
+/// Access points that broadcast a WiFi network.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ApTargeting {
+    /// Every access point on the site.
+    All,
+    /// Only the listed access points.
+    #[serde(rename_all = "camelCase")]
+    Devices { device_ids: Vec<String> },
+    /// Only access points in the listed AP groups.
+    #[serde(rename_all = "camelCase")]
+    ApGroups { ap_group_ids: Vec<String> },
+}
+
+/// A weekly time window during which a WiFi network is broadcast.
+///
+/// A window whose end is not after its start runs past midnight into the
+/// following day.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct ScheduleWindow {
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl ScheduleWindow {
+    pub fn new(days: impl IntoIterator<Item = Weekday>, start: NaiveTime, end: NaiveTime) -> Self {
+        Self {
+            days: days.into_iter().collect(),
+            start,
+            end,
+        }
+    }
+
+    /// Check if the window covers a local date and time.
+    pub fn is_active_at(&self, at: NaiveDateTime) -> bool {
+        let day = at.weekday();
+        let time = at.time();
+        if self.start < self.end {
+            self.days.contains(&day) && self.start <= time && time < self.end
+        } else {
+            (self.days.contains(&day) && time >= self.start)
+                || (self.days.contains(&day.pred()) && time < self.end)
+        }
+    }
+}
+
 /// WiFi broadcast (SSID) configuration.
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct WifiBroadcast {
     pub id: String,
     pub name: String,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub ssid: Option<String>,
     #[serde(default)]
     pub enabled: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub security: Option<WifiSecurity>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vlan_id: Option<i64>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub hide_ssid: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pmf_mode: Option<PmfMode>,
+    /// Bands the network is broadcast on. Empty means every band.
+    #[serde(
+        default,
+        rename = "broadcastingFrequenciesGHz",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub bands: Vec<RadioBand>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub minimum_data_rates: Option<MinimumDataRates>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub band_steering_enabled: Option<bool>,
+    /// 802.11r fast roaming.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fast_roaming_enabled: Option<bool>,
+    /// 802.11v BSS transition management.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bss_transition_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_isolation_enabled: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multicast_to_unicast_enabled: Option<bool>,
+    /// RADIUS profile used by enterprise security.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius_profile_id: Option<String>,
+    /// Broadcast schedule. Empty means always on.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub schedule: Vec<ScheduleWindow>,
+    #[serde(
+        default,
+        rename = "broadcastingDeviceFilter",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ap_targeting: Option<ApTargeting>,
+}
+
+impl WifiBroadcast {
+    /// Check if the network is broadcast on a band.
+    pub fn is_on_band(&self, band: RadioBand) -> bool {
+        self.bands.is_empty() || self.bands.contains(&band)
+    }
+
+    /// Check if the schedule allows broadcasting at a local date and time.
+    ///
+    /// Disabled networks are never broadcast.
+    pub fn is_scheduled_at(&self, at: NaiveDateTime) -> bool {
+        self.enabled
+            && (self.schedule.is_empty() || self.schedule.iter().any(|w| w.is_active_at(at)))
+    }
+}
+
+/// A WPA passphrase. `Debug` output hides the value.
+#[derive(Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Passphrase(String);
+
+impl Passphrase {
+    pub fn new(passphrase: impl Into<String>) -> Self {
+        Passphrase(passphrase.into())
+    }
+
+    /// Get the passphrase in clear text.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Check that the passphrase is 8-63 printable ASCII characters, as WPA
+    /// requires. Non-ASCII text is rejected rather than counted.
+    pub fn is_valid_length(&self) -> bool {
+        (8..=63).contains(&self.0.len()) && self.0.bytes().all(|b| (b' '..=b'~').contains(&b))
+    }
+}
+
+impl fmt::Debug for Passphrase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+/// A private pre-shared key (PPSK) of a WiFi broadcast.
+///
+/// Clients that join with the passphrase are placed on the key's network
+/// instead of the broadcast's own.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Ppsk {
+    pub id: String,
+    pub name: String,
+    pub passphrase: Passphrase,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vlan_id: Option<i64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use serde_json::json;
+
+    #[test]
+    fn test_ppsk_debug_redacts_passphrase() {
+        let ppsk: Ppsk = serde_json::from_value(json!({
+            "id": "ppsk-1",
+            "name": "Unit 4B",
+            "passphrase": "tenant-4b-secret",
+            "vlanId": 104
+        }))
+        .unwrap();
+
+        assert_eq!(ppsk.passphrase.expose(), "tenant-4b-secret");
+        let debug = format!("{:?}", ppsk);
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("tenant-4b-secret"));
+    }
+
+    #[test]
+    fn test_passphrase_is_valid_length() {
+        assert!(Passphrase::new("tenant-4b-secret").is_valid_length());
+        assert!(Passphrase::new("with spaces ~ok~").is_valid_length());
+        assert!(!Passphrase::new("short").is_valid_length());
+        assert!(!Passphrase::new("x".repeat(64)).is_valid_length());
+        // 8 characters but 16 bytes
+        assert!(!Passphrase::new("ééééééé").is_valid_length());
+        assert!(!Passphrase::new("é".repeat(40)).is_valid_length());
+        assert!(!Passphrase::new("tab\there-secret").is_valid_length());
+    }
+
+    #[test]
+    fn test_schedule_window_is_active_at() {
+        let at = |day: u32, hour: u32| {
+            NaiveDate::from_ymd_opt(2024, 1, day)
+                .unwrap()
+                .and_hms_opt(hour, 0, 0)
+                .unwrap()
+        };
+        let time = |hour: u32| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+
+        // 2024-01-01 is a Monday
+        let office = ScheduleWindow::new([Weekday::Mon, Weekday::Tue], time(8), time(18));
+        assert!(office.is_active_at(at(1, 8)));
+        assert!(!office.is_active_at(at(1, 18)));
+        assert!(!office.is_active_at(at(3, 12)));
+
+        let overnight = ScheduleWindow::new([Weekday::Fri], time(22), time(2));
+        assert!(overnight.is_active_at(at(5, 23)));
+        assert!(overnight.is_active_at(at(6, 1)));
+        assert!(!overnight.is_active_at(at(6, 2)));
+        assert!(!overnight.is_active_at(at(4, 23)));
+    }
+
+    #[test]
+    fn test_wifi_broadcast_bands_and_schedule() {
+        let wifi: WifiBroadcast = serde_json::from_value(json!({
+            "id": "wifi-1",
+            "name": "Office",
+            "enabled": true,
+            "security": "WPA2_WPA3",
+            "broadcastingFrequenciesGHz": [2.4, 5],
+            "schedule": [{"days": ["Mon"], "start": "08:00:00", "end": "18:00:00"}]
+        }))
+        .unwrap();
+
+        assert_eq!(wifi.security, Some(WifiSecurity::Wpa2Wpa3));
+        assert!(wifi.is_on_band(RadioBand::Band5GHz));
+        assert!(!wifi.is_on_band(RadioBand::Band6GHz));
+
+        let monday = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        assert!(wifi.is_scheduled_at(monday.and_hms_opt(9, 0, 0).unwrap()));
+        assert!(!wifi.is_scheduled_at(monday.and_hms_opt(19, 0, 0).unwrap()));
+    }
 }
@@ -0,0 +1,424 @@
+//! Cross-site helpers for controllers that host many sites.
+//!
+//! The convenience methods elsewhere in the crate operate on a single `site_id`.
+//! This module lists every site via [`GetSites`], runs a per-site operation with
+//! bounded concurrency, and reports success or failure for each site separately.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::UnifiClient;
+//! use futures::StreamExt;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! // Held by reference so each `async move` block copies the reference
+//! let client = &UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+//!
+//! // Run an operation on every site concurrently
+//! let devices = client
+//!     .for_each_site(|site| async move { client.fetch_all_devices(&site.id).await })
+//!     .await?;
+//!
+//! for (site, result) in devices.iter() {
+//!     match result {
+//!         Ok(devices) => println!("{}: {} devices", site.display_name(), devices.len()),
+//!         Err(e) => eprintln!("{}: failed: {}", site.display_name(), e),
+//!     }
+//! }
+//!
+//! // Or stream every client on the controller, tagged with its site
+//! let mut clients = client.stream_clients_all_sites();
+//! while let Some(result) = clients.next().await {
+//!     let tagged = result?;
+//!     println!("{} @ {}", tagged.item.id, tagged.site.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::clients::GetClients;
+use crate::api::devices::GetDevices;
+use crate::api::sites::GetSites;
+use crate::api::Paginated;
+use crate::error::{Error, Result};
+use crate::models::{Client, Site, SiteDevice};
+use crate::UnifiClient;
+use futures::stream::{self, BoxStream, Stream, StreamExt, TryStreamExt};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Default number of sites processed concurrently.
+pub const DEFAULT_SITE_CONCURRENCY: usize = 8;
+
+/// The outcome of running an operation on every site.
+///
+/// Results are kept in the order the controller listed the sites. A failure on
+/// one site does not affect the others.
+#[derive(Debug)]
+pub struct SiteResults<T> {
+    entries: Vec<(Site, Result<T>)>,
+}
+
+impl<T> SiteResults<T> {
+    /// Create results from per-site outcomes.
+    pub fn new(entries: Vec<(Site, Result<T>)>) -> Self {
+        Self { entries }
+    }
+
+    /// Iterate over every site and its result.
+    pub fn iter(&self) -> impl Iterator<Item = (&Site, &Result<T>)> {
+        self.entries.iter().map(|(site, result)| (site, result))
+    }
+
+    /// Get the result for a site by its ID.
+    pub fn get(&self, site_id: &str) -> Option<&Result<T>> {
+        self.entries
+            .iter()
+            .find(|(site, _)| site.id == site_id)
+            .map(|(_, result)| result)
+    }
+
+    /// Iterate over the sites that succeeded.
+    pub fn successes(&self) -> impl Iterator<Item = (&Site, &T)> {
+        self.entries
+            .iter()
+            .filter_map(|(site, result)| result.as_ref().ok().map(|value| (site, value)))
+    }
+
+    /// Iterate over the sites that failed.
+    pub fn failures(&self) -> impl Iterator<Item = (&Site, &Error)> {
+        self.entries
+            .iter()
+            .filter_map(|(site, result)| result.as_ref().err().map(|error| (site, error)))
+    }
+
+    /// Check if every site succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.entries.iter().all(|(_, result)| result.is_ok())
+    }
+
+    /// Get the number of sites.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if there were no sites.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Convert into a map keyed by site.
+    pub fn into_map(self) -> HashMap<Site, Result<T>> {
+        self.entries.into_iter().collect()
+    }
+
+    /// Convert into the ordered list of per-site results.
+    pub fn into_inner(self) -> Vec<(Site, Result<T>)> {
+        self.entries
+    }
+}
+
+impl<T> IntoIterator for SiteResults<T> {
+    type Item = (Site, Result<T>);
+    type IntoIter = std::vec::IntoIter<(Site, Result<T>)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+/// An item tagged with the site it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SiteTagged<T> {
+    /// The site the item belongs to.
+    pub site: Arc<Site>,
+
+    /// The item itself.
+    pub item: T,
+}
+
+/// A merged stream of items from every site, each tagged with its site.
+pub type SiteItemStream<T> = BoxStream<'static, Result<SiteTagged<T>>>;
+
+/// Extension methods for UnifiClient to work across all sites.
+impl UnifiClient {
+    /// List every site and run `f` on each, processing up to
+    /// [`DEFAULT_SITE_CONCURRENCY`] sites at a time.
+    ///
+    /// Returns an error only if the site list cannot be fetched. Failures of `f`
+    /// are reported per site in the returned [`SiteResults`].
+    ///
+    /// `f` receives its own copy of the site, so an `async move` block can
+    /// borrow from it, e.g. `&site.id`. Capture the client by reference, as
+    /// below, so the block does not try to move it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rustifi::UnifiClient;
+    /// # async fn example() -> rustifi::Result<()> {
+    /// let client = &UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+    ///
+    /// let results = client
+    ///     .for_each_site(|site| async move { client.fetch_all_clients(&site.id).await })
+    ///     .await?;
+    ///
+    /// for (site, error) in results.failures() {
+    ///     eprintln!("{} failed: {}", site.name, error);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn for_each_site<T, F, Fut>(&self, f: F) -> Result<SiteResults<T>>
+    where
+        F: Fn(Site) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        self.for_each_site_limited(DEFAULT_SITE_CONCURRENCY, f)
+            .await
+    }
+
+    /// List every site and run `f` on each, with at most `max_concurrent` sites in flight.
+    ///
+    /// A `max_concurrent` of 0 is normalized to 1.
+    pub async fn for_each_site_limited<T, F, Fut>(
+        &self,
+        max_concurrent: usize,
+        f: F,
+    ) -> Result<SiteResults<T>>
+    where
+        F: Fn(Site) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let sites = self.request::<GetSites>().await?.data;
+        Ok(run_per_site(sites, max_concurrent, f).await)
+    }
+
+    /// Stream every item of a paginated endpoint across all sites, tagged with its site.
+    ///
+    /// `endpoint` builds the per-site endpoint from a site ID. Up to
+    /// [`DEFAULT_SITE_CONCURRENCY`] sites are paged at a time and their items are
+    /// interleaved as they arrive. Errors from a site are wrapped in
+    /// [`Error::Site`] and do not stop the other sites.
+    pub fn stream_all_sites<E, F>(&self, endpoint: F) -> SiteItemStream<E::Item>
+    where
+        F: Fn(&str) -> E + Send + 'static,
+        E: Paginated + Send + Sync + 'static,
+        E::Item: Send + 'static,
+    {
+        let client = self.clone();
+        let sites = {
+            let client = self.clone();
+            async move { client.request::<GetSites>().await }
+        };
+
+        stream::once(sites)
+            .map_ok(move |response| {
+                let client = client.clone();
+                merge_site_streams(response.data, |site_id| {
+                    client.stream_owned(endpoint(site_id)).items()
+                })
+            })
+            .try_flatten()
+            .boxed()
+    }
+
+    /// Stream every client on the controller, tagged with its site.
+    pub fn stream_clients_all_sites(&self) -> SiteItemStream<Client> {
+        self.stream_all_sites(|site_id| GetClients::new(site_id))
+    }
+
+    /// Stream every device on the controller, tagged with its site.
+    pub fn stream_devices_all_sites(&self) -> SiteItemStream<SiteDevice> {
+        self.stream_all_sites(|site_id| GetDevices::new(site_id))
+    }
+}
+
+/// Run `f` on every site, keeping the sites' order in the results.
+async fn run_per_site<T, F, Fut>(sites: Vec<Site>, max_concurrent: usize, f: F) -> SiteResults<T>
+where
+    F: Fn(Site) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let f = &f;
+    let entries = stream::iter(sites)
+        .map(|site| async move {
+            let result = f(site.clone()).await;
+            (site, result)
+        })
+        .buffered(max_concurrent.max(1))
+        .collect()
+        .await;
+
+    SiteResults::new(entries)
+}
+
+/// Interleave per-site item streams, tagging items with their site and
+/// wrapping errors in [`Error::Site`].
+fn merge_site_streams<T, S>(
+    sites: Vec<Site>,
+    mut stream_for: impl FnMut(&str) -> S,
+) -> SiteItemStream<T>
+where
+    S: Stream<Item = Result<T>> + Send + Unpin + 'static,
+    T: Send + 'static,
+{
+    let per_site: Vec<_> = sites
+        .into_iter()
+        .map(|site| {
+            let site = Arc::new(site);
+            stream_for(&site.id).map(move |result| match result {
+                Ok(item) => Ok(SiteTagged {
+                    site: Arc::clone(&site),
+                    item,
+                }),
+                Err(e) => Err(Error::Site {
+                    site_id: site.id.clone(),
+                    source: Box::new(e),
+                }),
+            })
+        })
+        .collect();
+
+    stream::iter(per_site)
+        .flatten_unordered(DEFAULT_SITE_CONCURRENCY)
+        .boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_site(id: &str) -> Site {
+        Site {
+            id: id.to_string(),
+            name: id.to_string(),
+            desc: None,
+            role: None,
+            attr_no_delete: None,
+            attr_hidden_id: None,
+        }
+    }
+
+    fn make_results() -> SiteResults<usize> {
+        SiteResults::new(vec![
+            (make_site("a"), Ok(3)),
+            (
+                make_site("b"),
+                Err(Error::NotFound("site b unreachable".to_string())),
+            ),
+            (make_site("c"), Ok(5)),
+        ])
+    }
+
+    #[test]
+    fn test_site_results_partial_failure() {
+        let results = make_results();
+
+        assert_eq!(results.len(), 3);
+        assert!(!results.is_complete());
+        assert_eq!(results.successes().map(|(_, n)| *n).sum::<usize>(), 8);
+
+        let failed: Vec<&str> = results
+            .failures()
+            .map(|(site, _)| site.id.as_str())
+            .collect();
+        assert_eq!(failed, vec!["b"]);
+    }
+
+    #[test]
+    fn test_site_results_lookup_and_map() {
+        let results = make_results();
+
+        assert!(matches!(results.get("a"), Some(Ok(3))));
+        assert!(matches!(results.get("b"), Some(Err(_))));
+        assert!(results.get("missing").is_none());
+
+        let map = results.into_map();
+        assert!(matches!(map.get(&make_site("c")), Some(Ok(5))));
+    }
+
+    #[tokio::test]
+    async fn test_run_per_site_keeps_order_and_limit() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::time::Duration;
+
+        let in_flight = AtomicUsize::new(0);
+        let peak = AtomicUsize::new(0);
+        let sites: Vec<Site> = ["a", "b", "c", "d", "e"]
+            .into_iter()
+            .map(make_site)
+            .collect();
+
+        let results = run_per_site(sites, 2, |site| {
+            let (in_flight, peak) = (&in_flight, &peak);
+            async move {
+                let now = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                peak.fetch_max(now, Ordering::SeqCst);
+                // Later sites finish first
+                let delay = 5 * (5 - site.id.as_bytes()[0].saturating_sub(b'a')) as u64;
+                tokio::time::sleep(Duration::from_millis(delay)).await;
+                in_flight.fetch_sub(1, Ordering::SeqCst);
+                if site.id == "c" {
+                    Err(Error::NotFound("site c unreachable".to_string()))
+                } else {
+                    Ok(site.id.len())
+                }
+            }
+        })
+        .await;
+
+        let order: Vec<&str> = results.iter().map(|(site, _)| site.id.as_str()).collect();
+        assert_eq!(order, vec!["a", "b", "c", "d", "e"]);
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+        assert_eq!(results.successes().count(), 4);
+        assert!(matches!(results.get("c"), Some(Err(Error::NotFound(_)))));
+
+        let single = run_per_site(vec![make_site("a")], 0, |_| async { Ok(()) }).await;
+        assert!(single.is_complete());
+    }
+
+    #[tokio::test]
+    async fn test_merge_site_streams_tags_items_and_errors() {
+        let sites = vec![make_site("a"), make_site("b")];
+        let merged: Vec<Result<SiteTagged<u32>>> = merge_site_streams(sites, |site_id| {
+            let items = if site_id == "a" {
+                vec![Ok(1), Ok(2)]
+            } else {
+                vec![Ok(3), Err(Error::Parse("bad page".to_string()))]
+            };
+            stream::iter(items)
+        })
+        .collect()
+        .await;
+
+        assert_eq!(merged.len(), 4);
+        let mut tagged: Vec<(String, u32)> = merged
+            .iter()
+            .filter_map(|r| r.as_ref().ok())
+            .map(|t| (t.site.id.clone(), t.item))
+            .collect();
+        tagged.sort();
+        assert_eq!(
+            tagged,
+            vec![("a".into(), 1), ("a".into(), 2), ("b".into(), 3)]
+        );
+        assert!(merged
+            .iter()
+            .any(|r| matches!(r, Err(Error::Site { site_id, .. }) if site_id == "b")));
+    }
+
+    #[test]
+    fn test_site_error_wraps_source() {
+        let error = Error::Site {
+            site_id: "site-1".to_string(),
+            source: Box::new(Error::Parse("bad json".to_string())),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "Site site-1 failed: Response parsing failed: bad json"
+        );
+    }
+}
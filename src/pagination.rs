@@ -1,11 +1,14 @@
 //! Pagination utilities for iterating through paginated API responses.
 //!
 //! This module provides helpers for fetching all pages of data from paginated
-//! endpoints, either as a complete collection or as an async stream.
+//! endpoints, either as a complete collection or as an async stream. Any endpoint
+//! implementing [`Paginated`] can be used with [`UnifiClient::fetch_all`] and
+//! [`UnifiClient::stream`].
 //!
 //! # Example
 //!
 //! ```no_run
+//! use rustifi::api::hotspot::GetVouchers;
 //! use rustifi::UnifiClient;
 //! use futures::StreamExt;
 //!
@@ -15,6 +18,9 @@
 //! // Fetch all clients at once
 //! let all_clients = client.fetch_all_clients("site-id").await?;
 //!
+//! // Fetch every page of any list endpoint
+//! let vouchers = client.fetch_all(GetVouchers::new("site-id")).await?;
+//!
 //! // Or stream page by page
 //! let mut stream = client.stream_clients("site-id");
 //! while let Some(result) = stream.next().await {
@@ -25,83 +31,249 @@
 //! # }
 //! ```
 
+use crate::api::acl::AclRule;
 use crate::api::clients::GetClients;
-use crate::api::devices::GetDevices;
-use crate::error::Result;
-use crate::models::{Client, SiteDevice};
+use crate::api::devices::{GetDevices, PendingDevice};
+use crate::api::dns::DnsPolicy;
+pub use crate::api::endpoint::Paginated;
+use crate::api::hotspot::GetVouchers;
+use crate::api::networks::Network;
+use crate::api::resources::{
+    Country, DeviceTag, DpiApplication, DpiCategory, RadiusProfile, VpnServer, VpnTunnel, Wan,
+};
+use crate::api::traffic::TrafficList;
+use crate::error::{Error, Result};
+use crate::models::{Client, FirewallPolicy, FirewallZone, SiteDevice, Voucher, WifiBroadcast};
 use crate::response::SiteResponse;
 use crate::UnifiClient;
-use futures::stream::Stream;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
 /// Default page size for pagination.
 pub const DEFAULT_PAGE_SIZE: usize = 100;
 
+/// Items that carry a stable identifier, used to de-duplicate concurrently fetched pages.
+pub trait Identifiable {
+    /// The unique identifier of this item.
+    fn id(&self) -> &str;
+}
+
+macro_rules! impl_identifiable {
+    ($($ty:ty => $field:ident),* $(,)?) => {
+        $(
+            impl Identifiable for $ty {
+                fn id(&self) -> &str {
+                    &self.$field
+                }
+            }
+        )*
+    };
+}
+
+impl_identifiable!(
+    Client => id,
+    SiteDevice => id,
+    PendingDevice => id,
+    Voucher => id,
+    Network => id,
+    FirewallZone => id,
+    FirewallPolicy => id,
+    AclRule => id,
+    DnsPolicy => id,
+    TrafficList => id,
+    WifiBroadcast => id,
+    Wan => id,
+    VpnServer => id,
+    VpnTunnel => id,
+    RadiusProfile => id,
+    DeviceTag => id,
+    DpiCategory => id,
+    DpiApplication => id,
+    Country => code,
+);
+
+/// Remove items whose ID has already been seen, keeping the first occurrence.
+///
+/// Offset pagination can return the same item on two pages when the
+/// underlying collection changes between requests.
+pub fn dedup_by_id<T: Identifiable>(items: Vec<T>) -> Vec<T> {
+    let mut seen = HashSet::with_capacity(items.len());
+    items
+        .into_iter()
+        .filter(|item| seen.insert(item.id().to_string()))
+        .collect()
+}
+
+/// Fetch every page of `endpoint` with `fetch`, as described on
+/// [`UnifiClient::fetch_all_concurrent`].
+async fn fetch_pages_concurrent<E, F, Fut>(
+    endpoint: &E,
+    max_concurrent: usize,
+    fetch: F,
+) -> Result<Vec<E::Item>>
+where
+    E: Paginated,
+    E::Item: Identifiable,
+    F: Fn(E) -> Fut,
+    Fut: std::future::Future<Output = Result<SiteResponse<E::Item>>>,
+{
+    let first = fetch(endpoint.page(0, DEFAULT_PAGE_SIZE)).await?;
+
+    // The controller may cap the limit, so page by what it actually returned
+    let page_size = if first.limit > 0 {
+        first.limit
+    } else if first.count > 0 {
+        first.count
+    } else {
+        DEFAULT_PAGE_SIZE
+    };
+    let total_count = first.total_count;
+    let mut has_more = first.has_more() && !first.data.is_empty();
+    let mut next_offset = first.next_offset().unwrap_or(page_size);
+    let mut all_items = first.data;
+
+    if has_more {
+        let offsets = (next_offset..total_count).step_by(page_size);
+        let pages: Vec<SiteResponse<E::Item>> = stream::iter(offsets)
+            .map(|offset| fetch(endpoint.page(offset, page_size)))
+            .buffered(max_concurrent.max(1))
+            .try_collect()
+            .await?;
+
+        has_more = false;
+        for page in pages {
+            has_more = page.has_more() && !page.data.is_empty();
+            next_offset = page.next_offset().unwrap_or(page.offset + page_size);
+            all_items.extend(page.data);
+        }
+    }
+
+    // Items added while paging push the total past what was planned
+    while has_more {
+        let page = fetch(endpoint.page(next_offset, page_size)).await?;
+        has_more = page.has_more() && !page.data.is_empty();
+        next_offset = page.next_offset().unwrap_or(next_offset + page_size);
+        all_items.extend(page.data);
+    }
+
+    Ok(dedup_by_id(all_items))
+}
+
 /// Type alias for the boxed future used in page streaming.
 type PageFuture<'a, T> =
     Pin<Box<dyn std::future::Future<Output = Result<SiteResponse<T>>> + Send + 'a>>;
 
-/// A stream that yields pages of items from a paginated endpoint.
-pub struct PageStream<'a, T> {
-    client: &'a UnifiClient,
-    site_id: String,
+/// A serializable checkpoint of a page stream's position.
+///
+/// Take a cursor with [`PageStream::cursor`] or [`OwnedPageStream::cursor`] and
+/// persist it. After a failure, resume with [`UnifiClient::resume_stream`] to
+/// continue from the page that was about to be fetched (or that failed).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PageCursor {
+    /// Path of the endpoint being paged, e.g. `sites/{id}/clients`.
+    pub endpoint: String,
+    /// Offset of the next page to fetch.
+    pub offset: usize,
+    /// Page size in use.
+    pub limit: usize,
+    /// The `total_count` reported by the most recent page, if any.
+    #[serde(default)]
+    pub total_count: Option<usize>,
+    /// Whether the stream reached the end of the collection.
+    #[serde(default)]
+    pub complete: bool,
+}
+
+/// A change in `total_count` observed between two pages of the same stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TotalCountChange {
+    /// Offset of the page on which the change was observed.
+    pub offset: usize,
+    /// The previously reported total.
+    pub previous: usize,
+    /// The newly reported total.
+    pub current: usize,
+}
+
+impl TotalCountChange {
+    /// Number of items added to the collection, if it grew.
+    pub fn added(&self) -> usize {
+        self.current.saturating_sub(self.previous)
+    }
+
+    /// Number of items removed from the collection, if it shrank.
+    pub fn removed(&self) -> usize {
+        self.previous.saturating_sub(self.current)
+    }
+}
+
+/// Position of a page stream within its endpoint, shared by borrowed and owned streams.
+struct PageState<E: Paginated> {
+    endpoint: E,
     offset: usize,
     limit: usize,
     total_count: Option<usize>,
     done: bool,
-    // Store the future for the current page fetch
-    pending_future: Option<PageFuture<'a, T>>,
+    failed: bool,
+    changes: Vec<TotalCountChange>,
 }
 
-impl<'a> PageStream<'a, Client> {
-    /// Create a new stream for clients.
-    pub(crate) fn clients(client: &'a UnifiClient, site_id: impl Into<String>) -> Self {
+impl<E: Paginated> PageState<E> {
+    fn new(endpoint: E) -> Self {
         Self {
-            client,
-            site_id: site_id.into(),
+            endpoint,
             offset: 0,
             limit: DEFAULT_PAGE_SIZE,
             total_count: None,
             done: false,
-            pending_future: None,
+            failed: false,
+            changes: Vec::new(),
         }
     }
 
-    /// Set the page size (limit).
-    /// A size of 0 is normalized to 1 to prevent empty page requests.
-    pub fn page_size(mut self, size: usize) -> Self {
-        self.limit = if size == 0 { 1 } else { size };
-        self
+    /// Restore a stream position, rejecting cursors taken from a different endpoint.
+    fn from_cursor(endpoint: E, cursor: &PageCursor) -> Result<Self> {
+        let path = endpoint.build_path();
+        if cursor.endpoint != path {
+            return Err(Error::InvalidState(format!(
+                "page cursor for '{}' cannot resume '{}'",
+                cursor.endpoint, path
+            )));
+        }
+
+        let mut state = Self::new(endpoint);
+        state.offset = cursor.offset;
+        state.set_page_size(cursor.limit);
+        state.total_count = cursor.total_count;
+        state.done = cursor.complete;
+        Ok(state)
     }
-}
 
-impl<'a> PageStream<'a, SiteDevice> {
-    /// Create a new stream for devices.
-    pub(crate) fn devices(client: &'a UnifiClient, site_id: impl Into<String>) -> Self {
-        Self {
-            client,
-            site_id: site_id.into(),
-            offset: 0,
-            limit: DEFAULT_PAGE_SIZE,
-            total_count: None,
-            done: false,
-            pending_future: None,
+    fn cursor(&self) -> PageCursor {
+        PageCursor {
+            endpoint: self.endpoint.build_path(),
+            offset: self.offset,
+            limit: self.limit,
+            total_count: self.total_count,
+            complete: self.done && !self.failed,
         }
     }
 
-    /// Set the page size (limit).
-    /// A size of 0 is normalized to 1 to prevent empty page requests.
-    pub fn page_size(mut self, size: usize) -> Self {
+    fn set_page_size(&mut self, size: usize) {
         self.limit = if size == 0 { 1 } else { size };
-        self
     }
-}
 
-impl<'a> Stream for PageStream<'a, Client> {
-    type Item = Result<Vec<Client>>;
-
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+    /// Drive the pending page request, creating it with `fetch` when none is in flight.
+    fn poll_page<'a>(
+        &mut self,
+        pending_future: &mut Option<PageFuture<'a, E::Item>>,
+        cx: &mut Context<'_>,
+        fetch: impl FnOnce(E) -> PageFuture<'a, E::Item>,
+    ) -> Poll<Option<Result<Vec<E::Item>>>> {
         if self.done {
             return Poll::Ready(None);
         }
@@ -113,20 +285,26 @@ impl<'a> Stream for PageStream<'a, Client> {
         }
 
         // If there's no pending future, create one
-        if self.pending_future.is_none() {
-            let endpoint = GetClients::with_pagination(&self.site_id, self.offset, self.limit);
-            let client = self.client;
-            self.pending_future = Some(Box::pin(async move { client.execute(&endpoint).await }));
-        }
+        let future = pending_future
+            .get_or_insert_with(|| fetch(self.endpoint.page(self.offset, self.limit)));
 
         // Poll the pending future
-        let future = self.pending_future.as_mut().unwrap();
         match future.as_mut().poll(cx) {
             Poll::Pending => Poll::Pending,
             Poll::Ready(result) => {
-                self.pending_future = None;
+                *pending_future = None;
                 match result {
                     Ok(response) => {
+                        if let Some(previous) = self
+                            .total_count
+                            .filter(|&total| total != response.total_count)
+                        {
+                            self.changes.push(TotalCountChange {
+                                offset: self.offset,
+                                previous,
+                                current: response.total_count,
+                            });
+                        }
                         self.total_count = Some(response.total_count);
                         self.offset = response.next_offset().unwrap_or(self.offset + self.limit);
 
@@ -137,7 +315,9 @@ impl<'a> Stream for PageStream<'a, Client> {
                         Poll::Ready(Some(Ok(response.data)))
                     }
                     Err(e) => {
+                        // Keep the offset so a cursor taken now retries this page
                         self.done = true;
+                        self.failed = true;
                         Poll::Ready(Some(Err(e)))
                     }
                 }
@@ -146,49 +326,199 @@ impl<'a> Stream for PageStream<'a, Client> {
     }
 }
 
-impl<'a> Stream for PageStream<'a, SiteDevice> {
-    type Item = Result<Vec<SiteDevice>>;
+/// A stream that yields pages of items from a paginated endpoint.
+///
+/// This stream borrows the [`UnifiClient`]. Use [`OwnedPageStream`] when the
+/// stream needs to outlive the client reference, e.g. inside `tokio::spawn`.
+pub struct PageStream<'a, E: Paginated> {
+    client: &'a UnifiClient,
+    state: PageState<E>,
+    // Store the future for the current page fetch
+    pending_future: Option<PageFuture<'a, E::Item>>,
+}
 
-    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
-        if self.done {
-            return Poll::Ready(None);
+impl<'a, E: Paginated> PageStream<'a, E> {
+    /// Create a new stream over all pages of `endpoint`.
+    pub(crate) fn new(client: &'a UnifiClient, endpoint: E) -> Self {
+        Self {
+            client,
+            state: PageState::new(endpoint),
+            pending_future: None,
         }
+    }
 
-        // If we have a known total and we've fetched everything, we're done
-        if self.total_count.is_some_and(|total| self.offset >= total) {
-            self.done = true;
-            return Poll::Ready(None);
+    /// Set the page size (limit).
+    /// A size of 0 is normalized to 1 to prevent empty page requests.
+    pub fn page_size(mut self, size: usize) -> Self {
+        self.state.set_page_size(size);
+        self
+    }
+
+    /// Flatten this stream into one that yields individual items.
+    pub fn items(self) -> ItemStream<Self, E::Item> {
+        ItemStream::new(self)
+    }
+
+    /// Return a checkpoint of the stream's current position.
+    pub fn cursor(&self) -> PageCursor {
+        self.state.cursor()
+    }
+
+    /// Changes in `total_count` observed between pages, including against a resumed cursor.
+    pub fn total_count_changes(&self) -> &[TotalCountChange] {
+        &self.state.changes
+    }
+}
+
+// The endpoint is never pinned; only the boxed future is polled in place.
+impl<E: Paginated> Unpin for PageStream<'_, E> {}
+
+impl<'a, E> Stream for PageStream<'a, E>
+where
+    E: Paginated + Send + Sync + 'a,
+    E::Item: Send + 'a,
+{
+    type Item = Result<Vec<E::Item>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let client = this.client;
+        this.state
+            .poll_page(&mut this.pending_future, cx, |endpoint| {
+                Box::pin(async move { client.execute(&endpoint).await })
+            })
+    }
+}
+
+/// A page stream that owns its [`UnifiClient`] and is `'static`.
+///
+/// The client is cloned when the stream is created, which is cheap because
+/// the underlying HTTP client is reference counted. Owned streams can be
+/// moved into `tokio::spawn`, stored in structs, or returned from functions.
+pub struct OwnedPageStream<E: Paginated> {
+    client: UnifiClient,
+    state: PageState<E>,
+    pending_future: Option<PageFuture<'static, E::Item>>,
+}
+
+impl<E: Paginated> OwnedPageStream<E> {
+    /// Create a new owned stream over all pages of `endpoint`.
+    pub fn new(client: UnifiClient, endpoint: E) -> Self {
+        Self {
+            client,
+            state: PageState::new(endpoint),
+            pending_future: None,
         }
+    }
 
-        // If there's no pending future, create one
-        if self.pending_future.is_none() {
-            let endpoint = GetDevices::with_pagination(&self.site_id, self.offset, self.limit);
-            let client = self.client;
-            self.pending_future = Some(Box::pin(async move { client.execute(&endpoint).await }));
+    /// Resume an owned stream from a previously saved cursor.
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidState`] if the cursor was taken from a different endpoint.
+    pub fn resume(client: UnifiClient, endpoint: E, cursor: &PageCursor) -> Result<Self> {
+        Ok(Self {
+            client,
+            state: PageState::from_cursor(endpoint, cursor)?,
+            pending_future: None,
+        })
+    }
+
+    /// Set the page size (limit).
+    /// A size of 0 is normalized to 1 to prevent empty page requests.
+    pub fn page_size(mut self, size: usize) -> Self {
+        self.state.set_page_size(size);
+        self
+    }
+
+    /// Flatten this stream into one that yields individual items.
+    pub fn items(self) -> ItemStream<Self, E::Item> {
+        ItemStream::new(self)
+    }
+
+    /// Return a checkpoint of the stream's current position.
+    pub fn cursor(&self) -> PageCursor {
+        self.state.cursor()
+    }
+
+    /// Changes in `total_count` observed between pages, including against a resumed cursor.
+    pub fn total_count_changes(&self) -> &[TotalCountChange] {
+        &self.state.changes
+    }
+}
+
+// The endpoint is never pinned; only the boxed future is polled in place.
+impl<E: Paginated> Unpin for OwnedPageStream<E> {}
+
+impl<E> Stream for OwnedPageStream<E>
+where
+    E: Paginated + Send + Sync + 'static,
+    E::Item: Send + 'static,
+{
+    type Item = Result<Vec<E::Item>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        let client = this.client.clone();
+        this.state
+            .poll_page(&mut this.pending_future, cx, move |endpoint| {
+                Box::pin(async move { client.execute(&endpoint).await })
+            })
+    }
+}
+
+/// A stream that yields items one at a time from a stream of pages.
+///
+/// Created with [`PageStream::items`] or [`OwnedPageStream::items`]. Errors from
+/// the page stream are passed through as they occur.
+pub struct ItemStream<S, T> {
+    pages: S,
+    buffer: std::vec::IntoIter<T>,
+}
+
+impl<S, T> ItemStream<S, T> {
+    /// Wrap a stream of pages so it yields individual items.
+    pub fn new(pages: S) -> Self {
+        Self {
+            pages,
+            buffer: Vec::new().into_iter(),
         }
+    }
 
-        // Poll the pending future
-        let future = self.pending_future.as_mut().unwrap();
-        match future.as_mut().poll(cx) {
-            Poll::Pending => Poll::Pending,
-            Poll::Ready(result) => {
-                self.pending_future = None;
-                match result {
-                    Ok(response) => {
-                        self.total_count = Some(response.total_count);
-                        self.offset = response.next_offset().unwrap_or(self.offset + self.limit);
+    /// Get a reference to the underlying page stream, e.g. to take a cursor.
+    ///
+    /// Cursors are page-granular: items already buffered from the current
+    /// page are not part of the cursor position.
+    pub fn get_ref(&self) -> &S {
+        &self.pages
+    }
 
-                        if response.data.is_empty() || !response.has_more() {
-                            self.done = true;
-                        }
+    /// Consume the adapter and return the underlying page stream.
+    pub fn into_inner(self) -> S {
+        self.pages
+    }
+}
 
-                        Poll::Ready(Some(Ok(response.data)))
-                    }
-                    Err(e) => {
-                        self.done = true;
-                        Poll::Ready(Some(Err(e)))
-                    }
-                }
+// Buffered items are moved out by value and never pinned.
+impl<S: Unpin, T> Unpin for ItemStream<S, T> {}
+
+impl<S, T> Stream for ItemStream<S, T>
+where
+    S: Stream<Item = Result<Vec<T>>> + Unpin,
+{
+    type Item = Result<T>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            if let Some(item) = this.buffer.next() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            match Pin::new(&mut this.pages).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(Some(Ok(page))) => this.buffer = page.into_iter(),
             }
         }
     }
@@ -196,31 +526,34 @@ impl<'a> Stream for PageStream<'a, SiteDevice> {
 
 /// Extension methods for UnifiClient to support pagination.
 impl UnifiClient {
-    /// Fetch all clients for a site, automatically handling pagination.
+    /// Fetch every page of a paginated endpoint and return all items.
     ///
-    /// This method fetches all pages sequentially and returns a complete list.
-    /// For large datasets, consider using `stream_clients()` instead.
+    /// Any offset or limit already set on `endpoint` is replaced while paging.
+    /// For large datasets, consider using [`stream`](Self::stream) instead.
     ///
     /// # Example
     ///
     /// ```no_run
     /// # use rustifi::UnifiClient;
+    /// use rustifi::api::networks::GetNetworks;
+    ///
     /// # async fn example() -> rustifi::Result<()> {
     /// let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
-    /// let all_clients = client.fetch_all_clients("site-id").await?;
-    /// println!("Total clients: {}", all_clients.len());
+    /// let networks = client.fetch_all(GetNetworks::new("site-id")).await?;
+    /// println!("Total networks: {}", networks.len());
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn fetch_all_clients(&self, site_id: &str) -> Result<Vec<Client>> {
+    pub async fn fetch_all<E: Paginated>(&self, endpoint: E) -> Result<Vec<E::Item>> {
         let mut all_items = Vec::new();
         let mut offset = 0;
 
         loop {
-            let endpoint = GetClients::with_pagination(site_id, offset, DEFAULT_PAGE_SIZE);
-            let response = self.execute(&endpoint).await?;
+            let response = self
+                .execute(&endpoint.page(offset, DEFAULT_PAGE_SIZE))
+                .await?;
 
-            let has_more = response.has_more();
+            let has_more = response.has_more() && !response.data.is_empty();
             let next_offset = response.next_offset().unwrap_or(offset + DEFAULT_PAGE_SIZE);
 
             all_items.extend(response.data);
@@ -235,6 +568,183 @@ impl UnifiClient {
         Ok(all_items)
     }
 
+    /// Fetch every page of a paginated endpoint, requesting pages concurrently.
+    ///
+    /// The first page is fetched on its own to learn `total_count` and the page
+    /// size the controller honours. The remaining offsets are then requested with
+    /// at most `max_concurrent` requests in flight. Pages are reassembled in order
+    /// and items are de-duplicated by ID, since offsets can shift while paging.
+    /// If the collection grew while fetching, the remaining pages are fetched
+    /// sequentially.
+    ///
+    /// A `max_concurrent` of 0 is normalized to 1.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rustifi::UnifiClient;
+    /// use rustifi::api::clients::GetClients;
+    ///
+    /// # async fn example() -> rustifi::Result<()> {
+    /// let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+    /// let clients = client
+    ///     .fetch_all_concurrent(GetClients::new("site-id"), 8)
+    ///     .await?;
+    /// println!("Total clients: {}", clients.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_all_concurrent<E>(
+        &self,
+        endpoint: E,
+        max_concurrent: usize,
+    ) -> Result<Vec<E::Item>>
+    where
+        E: Paginated,
+        E::Item: Identifiable,
+    {
+        fetch_pages_concurrent(&endpoint, max_concurrent, |page| async move {
+            self.execute(&page).await
+        })
+        .await
+    }
+
+    /// Create a stream that yields pages of items from a paginated endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rustifi::UnifiClient;
+    /// use rustifi::api::firewall::GetFirewallPolicies;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn example() -> rustifi::Result<()> {
+    /// let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+    /// let mut stream = client
+    ///     .stream(GetFirewallPolicies::new("site-id"))
+    ///     .page_size(50);
+    ///
+    /// while let Some(result) = stream.next().await {
+    ///     for policy in result? {
+    ///         println!("Policy: {}", policy.id);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream<E: Paginated>(&self, endpoint: E) -> PageStream<'_, E> {
+        PageStream::new(self, endpoint)
+    }
+
+    /// Create an owned, `'static` stream that yields pages of items.
+    ///
+    /// The client is cloned into the stream, so it can be moved into
+    /// `tokio::spawn` or stored alongside other state.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rustifi::UnifiClient;
+    /// use rustifi::api::clients::GetClients;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn example() -> rustifi::Result<()> {
+    /// let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+    /// let mut stream = client.stream_owned(GetClients::new("site-id")).items();
+    ///
+    /// let handle = tokio::spawn(async move {
+    ///     let mut count = 0;
+    ///     while let Some(result) = stream.next().await {
+    ///         result?;
+    ///         count += 1;
+    ///     }
+    ///     Ok::<_, rustifi::Error>(count)
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_owned<E: Paginated>(&self, endpoint: E) -> OwnedPageStream<E> {
+        OwnedPageStream::new(self.clone(), endpoint)
+    }
+
+    /// Resume a page stream from a previously saved [`PageCursor`].
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidState`] if the cursor was taken from a different endpoint.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rustifi::UnifiClient;
+    /// use rustifi::api::clients::GetClients;
+    /// use rustifi::pagination::PageCursor;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn example(saved: PageCursor) -> rustifi::Result<()> {
+    /// let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+    /// let mut stream = client.resume_stream(GetClients::new("site-id"), &saved)?;
+    ///
+    /// while let Some(result) = stream.next().await {
+    ///     match result {
+    ///         Ok(page) => println!("Got {} clients", page.len()),
+    ///         Err(e) => {
+    ///             let checkpoint = stream.cursor();
+    ///             eprintln!("Failed at offset {}: {}", checkpoint.offset, e);
+    ///             break;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// for change in stream.total_count_changes() {
+    ///     println!("+{} / -{} clients while paging", change.added(), change.removed());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn resume_stream<E: Paginated>(
+        &self,
+        endpoint: E,
+        cursor: &PageCursor,
+    ) -> Result<PageStream<'_, E>> {
+        Ok(PageStream {
+            client: self,
+            state: PageState::from_cursor(endpoint, cursor)?,
+            pending_future: None,
+        })
+    }
+
+    /// Resume an owned, `'static` page stream from a previously saved [`PageCursor`].
+    ///
+    /// # Errors
+    /// Returns [`Error::InvalidState`] if the cursor was taken from a different endpoint.
+    pub fn resume_stream_owned<E: Paginated>(
+        &self,
+        endpoint: E,
+        cursor: &PageCursor,
+    ) -> Result<OwnedPageStream<E>> {
+        OwnedPageStream::resume(self.clone(), endpoint, cursor)
+    }
+
+    /// Fetch all clients for a site, automatically handling pagination.
+    ///
+    /// This method fetches all pages sequentially and returns a complete list.
+    /// For large datasets, consider using `stream_clients()` instead.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rustifi::UnifiClient;
+    /// # async fn example() -> rustifi::Result<()> {
+    /// let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+    /// let all_clients = client.fetch_all_clients("site-id").await?;
+    /// println!("Total clients: {}", all_clients.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_all_clients(&self, site_id: &str) -> Result<Vec<Client>> {
+        self.fetch_all(GetClients::new(site_id)).await
+    }
+
     /// Fetch all devices for a site, automatically handling pagination.
     ///
     /// This method fetches all pages sequentially and returns a complete list.
@@ -252,26 +762,36 @@ impl UnifiClient {
     /// # }
     /// ```
     pub async fn fetch_all_devices(&self, site_id: &str) -> Result<Vec<SiteDevice>> {
-        let mut all_items = Vec::new();
-        let mut offset = 0;
-
-        loop {
-            let endpoint = GetDevices::with_pagination(site_id, offset, DEFAULT_PAGE_SIZE);
-            let response = self.execute(&endpoint).await?;
-
-            let has_more = response.has_more();
-            let next_offset = response.next_offset().unwrap_or(offset + DEFAULT_PAGE_SIZE);
-
-            all_items.extend(response.data);
+        self.fetch_all(GetDevices::new(site_id)).await
+    }
 
-            if !has_more {
-                break;
-            }
+    /// Fetch all hotspot vouchers for a site, automatically handling pagination.
+    pub async fn fetch_all_vouchers(&self, site_id: &str) -> Result<Vec<Voucher>> {
+        self.fetch_all(GetVouchers::new(site_id)).await
+    }
 
-            offset = next_offset;
-        }
+    /// Fetch all clients for a site, requesting pages concurrently.
+    ///
+    /// See [`fetch_all_concurrent`](Self::fetch_all_concurrent) for details.
+    pub async fn fetch_all_clients_concurrent(
+        &self,
+        site_id: &str,
+        max_concurrent: usize,
+    ) -> Result<Vec<Client>> {
+        self.fetch_all_concurrent(GetClients::new(site_id), max_concurrent)
+            .await
+    }
 
-        Ok(all_items)
+    /// Fetch all devices for a site, requesting pages concurrently.
+    ///
+    /// See [`fetch_all_concurrent`](Self::fetch_all_concurrent) for details.
+    pub async fn fetch_all_devices_concurrent(
+        &self,
+        site_id: &str,
+        max_concurrent: usize,
+    ) -> Result<Vec<SiteDevice>> {
+        self.fetch_all_concurrent(GetDevices::new(site_id), max_concurrent)
+            .await
     }
 
     /// Create a stream that yields pages of clients.
@@ -298,8 +818,8 @@ impl UnifiClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn stream_clients(&self, site_id: &str) -> PageStream<'_, Client> {
-        PageStream::clients(self, site_id)
+    pub fn stream_clients(&self, site_id: &str) -> PageStream<'_, GetClients> {
+        self.stream(GetClients::new(site_id))
     }
 
     /// Create a stream that yields pages of devices.
@@ -326,14 +846,58 @@ impl UnifiClient {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn stream_devices(&self, site_id: &str) -> PageStream<'_, SiteDevice> {
-        PageStream::devices(self, site_id)
+    pub fn stream_devices(&self, site_id: &str) -> PageStream<'_, GetDevices> {
+        self.stream(GetDevices::new(site_id))
+    }
+
+    /// Create an owned, `'static` stream that yields pages of clients.
+    pub fn stream_clients_owned(&self, site_id: &str) -> OwnedPageStream<GetClients> {
+        self.stream_owned(GetClients::new(site_id))
+    }
+
+    /// Create an owned, `'static` stream that yields pages of devices.
+    pub fn stream_devices_owned(&self, site_id: &str) -> OwnedPageStream<GetDevices> {
+        self.stream_owned(GetDevices::new(site_id))
+    }
+
+    /// Create an owned, `'static` stream that yields clients one at a time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use rustifi::UnifiClient;
+    /// use futures::StreamExt;
+    ///
+    /// # async fn example() -> rustifi::Result<()> {
+    /// let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+    /// let mut clients = client.stream_client_items("site-id");
+    ///
+    /// while let Some(result) = clients.next().await {
+    ///     println!("Client: {}", result?.id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_client_items(
+        &self,
+        site_id: &str,
+    ) -> ItemStream<OwnedPageStream<GetClients>, Client> {
+        self.stream_clients_owned(site_id).items()
+    }
+
+    /// Create an owned, `'static` stream that yields devices one at a time.
+    pub fn stream_device_items(
+        &self,
+        site_id: &str,
+    ) -> ItemStream<OwnedPageStream<GetDevices>, SiteDevice> {
+        self.stream_devices_owned(site_id).items()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::endpoint::Endpoint;
     use crate::response::SiteResponse;
 
     #[test]
@@ -633,4 +1197,416 @@ mod tests {
         assert!(params.contains(&("offset", "30".to_string())));
         assert!(params.contains(&("limit", "15".to_string())));
     }
+
+    // Tests for owned and item-level streams
+
+    #[test]
+    fn test_owned_page_stream_is_static_and_send() {
+        fn assert_spawnable<S: Stream + Send + 'static>(_: &S) {}
+
+        let client = UnifiClient::new("https://unifi.example.com").unwrap();
+        let pages = client.stream_clients_owned("site-1").page_size(50);
+        assert_spawnable(&pages);
+
+        let items = client.stream_device_items("site-1");
+        drop(client);
+        assert_spawnable(&items);
+    }
+
+    #[tokio::test]
+    async fn test_item_stream_flattens_pages() {
+        use futures::StreamExt;
+
+        let pages = futures::stream::iter(vec![Ok(vec![1, 2]), Ok(vec![]), Ok(vec![3])]);
+        let items: Vec<i32> = ItemStream::new(pages)
+            .map(|item| item.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(items, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_item_stream_passes_errors_through() {
+        use futures::StreamExt;
+
+        let pages = futures::stream::iter(vec![
+            Ok(vec!["a".to_string()]),
+            Err(crate::Error::InvalidState("boom".to_string())),
+        ]);
+        let mut items = ItemStream::new(pages);
+
+        assert_eq!(items.next().await.unwrap().unwrap(), "a");
+        assert!(items.next().await.unwrap().is_err());
+        assert!(items.next().await.is_none());
+    }
+
+    // Tests for concurrent fetch de-duplication
+
+    #[test]
+    fn test_dedup_by_id_keeps_first_occurrence() {
+        let clients = vec![
+            Client {
+                id: "a".to_string(),
+                name: Some("first".to_string()),
+                ..Default::default()
+            },
+            Client {
+                id: "b".to_string(),
+                ..Default::default()
+            },
+            Client {
+                id: "a".to_string(),
+                name: Some("shifted".to_string()),
+                ..Default::default()
+            },
+        ];
+
+        let deduped = dedup_by_id(clients);
+
+        assert_eq!(deduped.len(), 2);
+        assert_eq!(deduped[0].id, "a");
+        assert_eq!(deduped[0].name.as_deref(), Some("first"));
+        assert_eq!(deduped[1].id, "b");
+    }
+
+    fn make_clients(ids: std::ops::Range<usize>) -> Vec<Client> {
+        ids.map(|i| Client {
+            id: format!("client-{}", i),
+            ..Default::default()
+        })
+        .collect()
+    }
+
+    /// Answer a page request from `items`, capping the limit like a controller.
+    fn serve_page(
+        items: &[Client],
+        endpoint: &GetClients,
+        max_limit: usize,
+    ) -> SiteResponse<Client> {
+        let param = |name: &str| -> usize {
+            endpoint
+                .query_params()
+                .into_iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| value.parse().unwrap())
+                .unwrap()
+        };
+        let (offset, limit) = (param("offset"), param("limit").min(max_limit));
+        let data: Vec<Client> = items.iter().skip(offset).take(limit).cloned().collect();
+        SiteResponse {
+            count: data.len(),
+            data,
+            offset,
+            limit,
+            total_count: items.len(),
+        }
+    }
+
+    fn ids(clients: &[Client]) -> Vec<String> {
+        clients.iter().map(|c| c.id.clone()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_concurrent_reassembles_in_order() {
+        let items = make_clients(0..230);
+        let requested = std::sync::Mutex::new(Vec::new());
+
+        let fetched = fetch_pages_concurrent(&GetClients::new("site-1"), 4, |page| {
+            let response = serve_page(&items, &page, 50);
+            requested.lock().unwrap().push(response.offset);
+            async move {
+                // Later pages finish first
+                let delay = 250u64.saturating_sub(response.offset as u64) / 10;
+                tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+                Ok(response)
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(ids(&fetched), ids(&items));
+        // Paged by the controller's cap of 50, not the requested 100
+        let mut offsets = requested.into_inner().unwrap();
+        offsets.sort();
+        assert_eq!(offsets, vec![0, 50, 100, 150, 200]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_concurrent_follows_growing_total() {
+        let items = std::sync::Mutex::new(make_clients(0..200));
+
+        let fetched = fetch_pages_concurrent(&GetClients::new("site-1"), 2, |page| {
+            let mut items = items.lock().unwrap();
+            let response = serve_page(&items, &page, 100);
+            if response.offset == 0 {
+                // Clients join on both ends after the first page
+                items.splice(0..0, make_clients(900..905));
+                items.extend(make_clients(1000..1060));
+            }
+            async move { Ok(response) }
+        })
+        .await
+        .unwrap();
+
+        // The shifted page repeats 95-99, which are de-duplicated, and the
+        // appended clients are fetched past the planned total
+        let expected: Vec<Client> = make_clients(0..200)
+            .into_iter()
+            .chain(make_clients(1000..1060))
+            .collect();
+        assert_eq!(ids(&fetched), ids(&expected));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_concurrent_handles_shrinking_total() {
+        let items = std::sync::Mutex::new(make_clients(0..250));
+
+        let fetched = fetch_pages_concurrent(&GetClients::new("site-1"), 1, |page| {
+            let mut items = items.lock().unwrap();
+            let response = serve_page(&items, &page, 100);
+            if response.offset == 0 {
+                items.truncate(180);
+            }
+            async move { Ok(response) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(ids(&fetched), ids(&make_clients(0..180)));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_concurrent_stops_on_error() {
+        let items = make_clients(0..250);
+
+        let result = fetch_pages_concurrent(&GetClients::new("site-1"), 4, |page| {
+            let response = serve_page(&items, &page, 100);
+            async move {
+                if response.offset == 100 {
+                    Err(Error::InvalidState("page failed".to_string()))
+                } else {
+                    Ok(response)
+                }
+            }
+        })
+        .await;
+
+        assert!(matches!(result, Err(Error::InvalidState(_))));
+    }
+
+    /// Poll `state` to its next page, answering requests from `script`.
+    async fn next_scripted_page(
+        state: &mut PageState<GetClients>,
+        script: &mut Vec<Result<SiteResponse<Client>>>,
+    ) -> Option<Result<Vec<Client>>> {
+        let mut pending = None;
+        futures::future::poll_fn(|cx| {
+            state.poll_page(&mut pending, cx, |_| {
+                let response = script.remove(0);
+                Box::pin(async move { response })
+            })
+        })
+        .await
+    }
+
+    fn scripted_page(
+        ids: std::ops::Range<usize>,
+        offset: usize,
+        total_count: usize,
+    ) -> Result<SiteResponse<Client>> {
+        let data = make_clients(ids);
+        Ok(SiteResponse {
+            count: data.len(),
+            data,
+            offset,
+            limit: 2,
+            total_count,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_poll_page_records_total_count_changes() {
+        let mut state = PageState::new(GetClients::new("site-1"));
+        state.set_page_size(2);
+        let mut script = vec![
+            scripted_page(0..2, 0, 5),
+            scripted_page(2..4, 2, 7),
+            scripted_page(4..6, 4, 6),
+        ];
+
+        let mut fetched = Vec::new();
+        while let Some(page) = next_scripted_page(&mut state, &mut script).await {
+            fetched.extend(page.unwrap());
+        }
+
+        assert_eq!(ids(&fetched), ids(&make_clients(0..6)));
+        assert!(script.is_empty());
+        assert_eq!(
+            state.changes,
+            vec![
+                TotalCountChange {
+                    offset: 2,
+                    previous: 5,
+                    current: 7
+                },
+                TotalCountChange {
+                    offset: 4,
+                    previous: 7,
+                    current: 6
+                },
+            ]
+        );
+        assert_eq!(state.changes[0].added(), 2);
+        assert_eq!(state.changes[1].removed(), 1);
+        assert!(state.cursor().complete);
+    }
+
+    #[tokio::test]
+    async fn test_poll_page_stops_when_total_shrinks() {
+        let mut state = PageState::new(GetClients::new("site-1"));
+        state.set_page_size(2);
+        let mut script = vec![scripted_page(0..2, 0, 6), scripted_page(2..3, 2, 3)];
+
+        assert_eq!(
+            next_scripted_page(&mut state, &mut script)
+                .await
+                .unwrap()
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            next_scripted_page(&mut state, &mut script)
+                .await
+                .unwrap()
+                .unwrap()
+                .len(),
+            1
+        );
+        // The shrunken total is reached without another request
+        assert!(next_scripted_page(&mut state, &mut script).await.is_none());
+        assert_eq!(state.changes[0].removed(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_poll_page_keeps_offset_on_error() {
+        let mut state = PageState::new(GetClients::new("site-1"));
+        state.set_page_size(2);
+        let mut script = vec![
+            scripted_page(0..2, 0, 5),
+            Err(Error::InvalidState("page failed".to_string())),
+        ];
+
+        next_scripted_page(&mut state, &mut script)
+            .await
+            .unwrap()
+            .unwrap();
+        let failed = next_scripted_page(&mut state, &mut script).await.unwrap();
+        assert!(matches!(failed, Err(Error::InvalidState(_))));
+        assert!(next_scripted_page(&mut state, &mut script).await.is_none());
+
+        let cursor = state.cursor();
+        assert_eq!(cursor.offset, 2);
+        assert_eq!(cursor.total_count, Some(5));
+        assert!(!cursor.complete);
+    }
+
+    #[test]
+    fn test_identifiable_country_uses_code() {
+        let country = Country {
+            code: "NZ".to_string(),
+            name: "New Zealand".to_string(),
+        };
+        assert_eq!(Identifiable::id(&country), "NZ");
+    }
+
+    // Tests for resumable cursors
+
+    #[test]
+    fn test_page_cursor_from_new_stream() {
+        let client = UnifiClient::new("https://unifi.example.com").unwrap();
+        let stream = client.stream_clients("site-1").page_size(25);
+        let cursor = stream.cursor();
+
+        assert_eq!(cursor.endpoint, "sites/site-1/clients");
+        assert_eq!(cursor.offset, 0);
+        assert_eq!(cursor.limit, 25);
+        assert_eq!(cursor.total_count, None);
+        assert!(!cursor.complete);
+        assert!(stream.total_count_changes().is_empty());
+    }
+
+    #[test]
+    fn test_page_cursor_round_trip() {
+        let cursor = PageCursor {
+            endpoint: "sites/site-1/clients".to_string(),
+            offset: 300,
+            limit: 100,
+            total_count: Some(1234),
+            complete: false,
+        };
+
+        let json = serde_json::to_value(&cursor).unwrap();
+        assert_eq!(json["totalCount"], 1234);
+
+        let restored: PageCursor = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, cursor);
+    }
+
+    #[test]
+    fn test_resume_stream_restores_position() {
+        let client = UnifiClient::new("https://unifi.example.com").unwrap();
+        let cursor = PageCursor {
+            endpoint: "sites/site-1/devices".to_string(),
+            offset: 200,
+            limit: 50,
+            total_count: Some(500),
+            complete: false,
+        };
+
+        let stream = client
+            .resume_stream(GetDevices::new("site-1"), &cursor)
+            .unwrap();
+        assert_eq!(stream.cursor(), cursor);
+
+        let owned = client
+            .resume_stream_owned(GetDevices::new("site-1"), &cursor)
+            .unwrap();
+        assert_eq!(owned.cursor(), cursor);
+    }
+
+    #[test]
+    fn test_resume_stream_rejects_other_endpoint() {
+        let client = UnifiClient::new("https://unifi.example.com").unwrap();
+        let cursor = PageCursor {
+            endpoint: "sites/site-1/devices".to_string(),
+            offset: 200,
+            limit: 50,
+            total_count: None,
+            complete: false,
+        };
+
+        let result = client.resume_stream(GetClients::new("site-1"), &cursor);
+        assert!(matches!(result, Err(Error::InvalidState(_))));
+    }
+
+    #[test]
+    fn test_total_count_change_added_and_removed() {
+        let grew = TotalCountChange {
+            offset: 100,
+            previous: 100,
+            current: 104,
+        };
+        assert_eq!(grew.added(), 4);
+        assert_eq!(grew.removed(), 0);
+
+        let shrank = TotalCountChange {
+            offset: 100,
+            previous: 100,
+            current: 97,
+        };
+        assert_eq!(shrank.added(), 0);
+        assert_eq!(shrank.removed(), 3);
+    }
 }
@@ -0,0 +1,333 @@
+//! Private pre-shared key (PPSK) helpers.
+//!
+//! A WiFi broadcast with PPSKs accepts several passphrases, each placing its
+//! clients on a different network or VLAN. This module validates key sets and
+//! moves them in and out of CSV, so keys can be managed in a spreadsheet.
+//!
+//! The CSV has a header row. `name` and `passphrase` columns are required;
+//! `network_id` and `vlan_id` are optional and may be left empty.
+//!
+//! ```text
+//! name,passphrase,network_id,vlan_id
+//! Unit 4B,tenant-4b-secret,,104
+//! "Suite 200, North",suite200-secret,net-7,
+//! ```
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::UnifiClient;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+//!
+//! let csv = std::fs::read_to_string("tenants.csv").unwrap();
+//! let import = client.import_ppsks("site-id", "broadcast-id", &csv).await?;
+//! println!("{} created, {} updated", import.created.len(), import.updated.len());
+//!
+//! let backup = client.export_ppsks_csv("site-id", "broadcast-id").await?;
+//! std::fs::write("tenants-backup.csv", backup).unwrap();
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::wifi::{CreatePpsk, GetPpsks, PpskRequest, UpdatePpsk};
+use crate::error::{Error, Result};
+use crate::models::Ppsk;
+use crate::UnifiClient;
+use std::collections::{HashMap, HashSet};
+
+const CSV_HEADER: [&str; 4] = ["name", "passphrase", "network_id", "vlan_id"];
+
+/// Check a set of PPSKs before sending them to the controller.
+///
+/// Every passphrase must be 8-63 printable ASCII characters, and no two
+/// entries may share a name or a passphrase. Errors name the entries but never
+/// include a passphrase.
+pub fn validate_ppsks<'a>(entries: impl IntoIterator<Item = &'a PpskRequest>) -> Result<()> {
+    let mut names = HashSet::new();
+    let mut seen: HashMap<&str, &str> = HashMap::new();
+    for entry in entries {
+        if !names.insert(entry.name.as_str()) {
+            return Err(Error::InvalidWifiConfig(format!(
+                "PPSK name '{}' is used more than once",
+                entry.name
+            )));
+        }
+        if !entry.passphrase.is_valid_length() {
+            return Err(Error::InvalidWifiConfig(format!(
+                "PPSK '{}' passphrase must be 8-63 printable ASCII characters",
+                entry.name
+            )));
+        }
+        if let Some(other) = seen.insert(entry.passphrase.expose(), &entry.name) {
+            return Err(Error::InvalidWifiConfig(format!(
+                "PPSKs '{}' and '{}' share a passphrase",
+                other, entry.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Write PPSKs as CSV, including passphrases in clear text.
+pub fn ppsks_to_csv<'a>(entries: impl IntoIterator<Item = &'a PpskRequest>) -> String {
+    let mut csv = CSV_HEADER.join(",");
+    csv.push_str("\r\n");
+    for entry in entries {
+        let vlan_id = entry.vlan_id.map(|v| v.to_string()).unwrap_or_default();
+        let fields = [
+            entry.name.as_str(),
+            entry.passphrase.expose(),
+            entry.network_id.as_deref().unwrap_or(""),
+            vlan_id.as_str(),
+        ];
+        let row: Vec<String> = fields.iter().map(|field| escape_field(field)).collect();
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// Read PPSKs from CSV.
+///
+/// Columns are matched by header name, so they may appear in any order.
+/// Blank lines are skipped. The entries are not validated; see
+/// [`validate_ppsks`].
+pub fn ppsks_from_csv(csv: &str) -> Result<Vec<PpskRequest>> {
+    let mut records = parse_csv(csv)?.into_iter();
+    let Some((_, header)) = records.next() else {
+        return Ok(Vec::new());
+    };
+
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h.trim().eq_ignore_ascii_case(name))
+    };
+    let missing = |name: &str| Error::InvalidWifiConfig(format!("CSV has no '{}' column", name));
+    let name_col = column("name").ok_or_else(|| missing("name"))?;
+    let passphrase_col = column("passphrase").ok_or_else(|| missing("passphrase"))?;
+    let network_col = column("network_id");
+    let vlan_col = column("vlan_id");
+
+    records
+        .filter(|(_, record)| record.iter().any(|field| !field.is_empty()))
+        .map(|(line, record)| {
+            let field = |col: Option<usize>| {
+                col.and_then(|c| record.get(c))
+                    .map(|f| f.trim())
+                    .filter(|f| !f.is_empty())
+            };
+            let name = field(Some(name_col)).ok_or_else(|| {
+                Error::InvalidWifiConfig(format!("CSV line {}: name is empty", line))
+            })?;
+            // Passphrases are taken verbatim; spaces are significant
+            let passphrase = record.get(passphrase_col).cloned().unwrap_or_default();
+
+            let mut entry = PpskRequest::new(name, passphrase);
+            entry.network_id = field(network_col).map(str::to_string);
+            entry.vlan_id = field(vlan_col)
+                .map(|v| {
+                    v.parse().map_err(|_| {
+                        Error::InvalidWifiConfig(format!(
+                            "CSV line {}: invalid VLAN ID '{}'",
+                            line, v
+                        ))
+                    })
+                })
+                .transpose()?;
+            Ok(entry)
+        })
+        .collect()
+}
+
+/// Quote a CSV field if it contains a delimiter, quote or line break.
+fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\r', '\n']) || field.trim() != field {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Split CSV text into records, each tagged with its starting line number.
+fn parse_csv(csv: &str) -> Result<Vec<(usize, Vec<String>)>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut record_line = 1;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push((record_line, std::mem::take(&mut record)));
+                line += 1;
+                record_line = line;
+            }
+            (c, _) => {
+                if c == '\n' {
+                    line += 1;
+                }
+                field.push(c);
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err(Error::InvalidWifiConfig(format!(
+            "CSV line {}: unterminated quoted field",
+            record_line
+        )));
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push((record_line, record));
+    }
+    Ok(records)
+}
+
+/// The outcome of a PPSK import.
+#[derive(Debug, Default)]
+pub struct PpskImport {
+    pub created: Vec<Ppsk>,
+    pub updated: Vec<Ppsk>,
+}
+
+/// Extension methods for UnifiClient to manage private pre-shared keys.
+impl UnifiClient {
+    /// Fetch every PPSK of a WiFi broadcast.
+    pub async fn fetch_all_ppsks(&self, site_id: &str, broadcast_id: &str) -> Result<Vec<Ppsk>> {
+        self.fetch_all(GetPpsks::new(site_id, broadcast_id)).await
+    }
+
+    /// Export the PPSKs of a WiFi broadcast as CSV.
+    ///
+    /// The output contains the passphrases in clear text.
+    pub async fn export_ppsks_csv(&self, site_id: &str, broadcast_id: &str) -> Result<String> {
+        let entries: Vec<PpskRequest> = self
+            .fetch_all_ppsks(site_id, broadcast_id)
+            .await?
+            .iter()
+            .map(PpskRequest::from)
+            .collect();
+        Ok(ppsks_to_csv(&entries))
+    }
+
+    /// Import PPSKs from CSV into a WiFi broadcast.
+    ///
+    /// Entries whose name matches an existing key update it; the rest are
+    /// created. The combined key set is validated before anything is sent.
+    /// Entries are applied in order, so if a request fails the earlier ones
+    /// remain applied.
+    pub async fn import_ppsks(
+        &self,
+        site_id: &str,
+        broadcast_id: &str,
+        csv: &str,
+    ) -> Result<PpskImport> {
+        let entries = ppsks_from_csv(csv)?;
+        let existing = self.fetch_all_ppsks(site_id, broadcast_id).await?;
+
+        let replaced: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        let kept: Vec<PpskRequest> = existing
+            .iter()
+            .filter(|ppsk| !replaced.contains(&ppsk.name.as_str()))
+            .map(PpskRequest::from)
+            .collect();
+        validate_ppsks(entries.iter().chain(&kept))?;
+
+        let mut import = PpskImport::default();
+        for entry in entries {
+            match existing.iter().find(|ppsk| ppsk.name == entry.name) {
+                Some(current) => {
+                    let endpoint = UpdatePpsk::new(site_id, broadcast_id, &current.id, entry);
+                    import.updated.push(self.execute(&endpoint).await?.data);
+                }
+                None => {
+                    let endpoint = CreatePpsk::new(site_id, broadcast_id, entry);
+                    import.created.push(self.execute(&endpoint).await?.data);
+                }
+            }
+        }
+        Ok(import)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ppsk_csv_round_trip() {
+        let entries = vec![
+            PpskRequest::new("Unit 4B", "tenant-4b-secret").vlan_id(104),
+            PpskRequest::new("Suite 200, North", "say \"hello\"\nfriend").network_id("net-7"),
+            PpskRequest::new("Lobby", " padded pass "),
+        ];
+
+        let csv = ppsks_to_csv(&entries);
+        assert!(csv.starts_with("name,passphrase,network_id,vlan_id\r\n"));
+        assert!(csv.contains("Unit 4B,tenant-4b-secret,,104\r\n"));
+        assert!(csv.contains("\"Suite 200, North\",\"say \"\"hello\"\"\nfriend\",net-7,\r\n"));
+
+        assert_eq!(ppsks_from_csv(&csv).unwrap(), entries);
+    }
+
+    #[test]
+    fn test_ppsks_from_csv_columns_by_header() {
+        let csv = "VLAN_ID,Passphrase,Name\n104,tenant-4b-secret,Unit 4B\n\n,lobby-secret,Lobby";
+        let entries = ppsks_from_csv(csv).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].vlan_id, Some(104));
+        assert_eq!(entries[1].name, "Lobby");
+        assert_eq!(entries[1].vlan_id, None);
+
+        assert!(ppsks_from_csv("name\nUnit 4B").is_err());
+        assert!(ppsks_from_csv("name,passphrase,vlan_id\nUnit 4B,secret123,abc").is_err());
+        assert!(ppsks_from_csv("name,passphrase\n\"Unit 4B,secret123").is_err());
+    }
+
+    #[test]
+    fn test_validate_ppsks() {
+        let ok = [
+            PpskRequest::new("Unit 4B", "tenant-4b-secret"),
+            PpskRequest::new("Unit 5A", "tenant-5a-secret"),
+        ];
+        assert!(validate_ppsks(&ok).is_ok());
+
+        let short = [PpskRequest::new("Unit 4B", "short")];
+        assert!(validate_ppsks(&short).is_err());
+
+        let non_ascii = [PpskRequest::new("Unit 4B", "ééééééé")];
+        assert!(validate_ppsks(&non_ascii).is_err());
+
+        let same_name = [
+            PpskRequest::new("Unit 4B", "tenant-4b-secret"),
+            PpskRequest::new("Unit 4B", "tenant-5a-secret"),
+        ];
+        let error = validate_ppsks(&same_name).unwrap_err().to_string();
+        assert!(error.contains("'Unit 4B'"));
+
+        let duplicate = [
+            PpskRequest::new("Unit 4B", "tenant-secret"),
+            PpskRequest::new("Unit 5A", "tenant-secret"),
+        ];
+        let error = validate_ppsks(&duplicate).unwrap_err().to_string();
+        assert!(error.contains("'Unit 4B' and 'Unit 5A'"));
+        assert!(!error.contains("tenant-secret"));
+    }
+}
@@ -2,5 +2,5 @@ pub mod wrapper;
 
 pub use wrapper::{
     ActionResponse, ApiResponse, DeleteResponse, EmptyResponse, MutationResponse,
-    PaginatedResponse, SiteResponse,
+    PaginatedResponse, ResponseMeta, SiteResponse,
 };
@@ -1,32 +1,68 @@
-use serde::Deserialize;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ApiResponse<T> {
     pub data: T,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<ResponseMeta>,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub errors: Option<Vec<ApiError>>,
 }
 
-#[derive(Debug, Deserialize)]
+impl<T> ApiResponse<T> {
+    /// Check if the classic API result code is `"ok"`.
+    ///
+    /// Responses without a `meta` block (the Integration API) are treated as ok.
+    pub fn is_ok(&self) -> bool {
+        self.meta.as_ref().map(ResponseMeta::is_ok).unwrap_or(true)
+    }
+
+    /// Return the data, or an [`Error::Api`] if the result code is not `"ok"`.
+    pub fn into_result(self) -> Result<T> {
+        match self.meta {
+            Some(meta) if !meta.is_ok() => Err(meta.into_error()),
+            _ => Ok(self.data),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ResponseMeta {
     pub rc: String,
+    /// Error message key returned with `rc: "error"` (e.g. "api.err.NoSiteContext").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub msg: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+impl ResponseMeta {
+    /// Check if the result code is `"ok"`.
+    pub fn is_ok(&self) -> bool {
+        self.rc == "ok"
+    }
+
+    /// Convert an error result into an [`Error::Api`].
+    pub fn into_error(self) -> Error {
+        Error::Api {
+            code: self.rc,
+            message: self.msg.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ApiError {
     pub code: String,
     pub message: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PaginatedResponse<T> {
     pub data: Vec<T>,
     pub meta: PaginationMeta,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct PaginationMeta {
     pub total_count: usize,
     pub first: usize,
@@ -35,7 +71,7 @@ pub struct PaginationMeta {
 
 /// Response format for site-scoped endpoints in the new API.
 /// Pagination fields are at the top level (not nested in meta).
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SiteResponse<T> {
     pub data: Vec<T>,
@@ -75,30 +111,30 @@ pub type ListResponse<T> = ApiResponse<Vec<T>>;
 pub type SingleResponse<T> = ApiResponse<T>;
 
 /// Response for create/update operations that return the created/updated entity.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct MutationResponse<T> {
     pub data: T,
 }
 
 /// Response for delete operations.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DeleteResponse {
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub http_status_code: Option<u16>,
 }
 
 /// Empty response for operations that don't return data.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct EmptyResponse {}
 
 /// Response for action operations that return a status.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActionResponse {
     #[serde(default)]
     pub success: bool,
-    #[serde(default)]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub message: Option<String>,
 }
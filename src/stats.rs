@@ -197,15 +197,19 @@ mod tests {
             client_type,
             name: None,
             connected_at: None,
+            mac_address: None,
             ip_address: None,
+            ipv6_addresses: Vec::new(),
             access: if is_guest {
                 Some(ClientAccess {
                     access_type: AccessType::Guest,
+                    ..Default::default()
                 })
             } else {
                 None
             },
             uplink_device_id: device_id.map(String::from),
+            ..Default::default()
         }
     }
 
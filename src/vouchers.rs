@@ -0,0 +1,191 @@
+//! Hotspot voucher cleanup helpers.
+//!
+//! The Integration API deletes vouchers one at a time. This module selects
+//! vouchers with a [`VoucherFilter`] and deletes the matches concurrently.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use rustifi::vouchers::VoucherFilter;
+//! use rustifi::UnifiClient;
+//!
+//! # async fn example() -> rustifi::Result<()> {
+//! let client = UnifiClient::with_api_key("https://unifi.example.com", "api-key")?;
+//!
+//! let cleanup = client
+//!     .delete_vouchers("site-id", &VoucherFilter::unused_for_days(7))
+//!     .await?;
+//! println!("Deleted {} vouchers", cleanup.deleted.len());
+//! for (voucher, error) in &cleanup.failed {
+//!     eprintln!("{}: {}", voucher.code, error);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::api::hotspot::DeleteVoucher;
+use crate::error::{Error, Result};
+use crate::models::{Voucher, VoucherStatus};
+use crate::UnifiClient;
+use chrono::{DateTime, Duration, Utc};
+use futures::stream::{self, StreamExt};
+
+/// Maximum number of concurrent delete requests.
+const MAX_CONCURRENT_REQUESTS: usize = 10;
+
+/// Selects vouchers for bulk operations.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VoucherFilter {
+    /// Vouchers whose status is [`VoucherStatus::Expired`].
+    Expired,
+    /// Unused vouchers created at least this long ago.
+    UnusedOlderThan(Duration),
+    /// Vouchers whose note contains this text, ignoring case.
+    NoteContains(String),
+}
+
+impl VoucherFilter {
+    /// Select unused vouchers created at least `days` days ago.
+    pub fn unused_for_days(days: u32) -> Self {
+        VoucherFilter::UnusedOlderThan(Duration::days(days.into()))
+    }
+
+    /// Select vouchers whose note contains `text`, ignoring case.
+    pub fn note_contains(text: impl Into<String>) -> Self {
+        VoucherFilter::NoteContains(text.into())
+    }
+
+    /// Check if a voucher matches as of `now`.
+    ///
+    /// Vouchers without a creation time never match `UnusedOlderThan`.
+    pub fn matches_at(&self, voucher: &Voucher, now: DateTime<Utc>) -> bool {
+        match self {
+            VoucherFilter::Expired => voucher.status_at(now) == VoucherStatus::Expired,
+            VoucherFilter::UnusedOlderThan(age) => {
+                voucher.status_at(now) == VoucherStatus::Unused
+                    && voucher
+                        .created_at
+                        .as_ref()
+                        .is_some_and(|created| now - created.as_datetime() >= *age)
+            }
+            VoucherFilter::NoteContains(text) => voucher
+                .note
+                .as_deref()
+                .is_some_and(|note| note.to_lowercase().contains(&text.to_lowercase())),
+        }
+    }
+}
+
+/// The outcome of a bulk voucher deletion.
+#[derive(Debug, Default)]
+pub struct VoucherCleanup {
+    pub deleted: Vec<Voucher>,
+    pub failed: Vec<(Voucher, Error)>,
+}
+
+impl VoucherCleanup {
+    /// Check if every matching voucher was deleted.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Select the vouchers matching `filter` as of `now`.
+pub fn vouchers_matching<'a>(
+    vouchers: &'a [Voucher],
+    filter: &VoucherFilter,
+    now: DateTime<Utc>,
+) -> Vec<&'a Voucher> {
+    vouchers
+        .iter()
+        .filter(|voucher| filter.matches_at(voucher, now))
+        .collect()
+}
+
+/// Extension methods for UnifiClient to manage hotspot vouchers.
+impl UnifiClient {
+    /// Fetch the vouchers on a site that currently match `filter`.
+    pub async fn find_vouchers(
+        &self,
+        site_id: &str,
+        filter: &VoucherFilter,
+    ) -> Result<Vec<Voucher>> {
+        let vouchers = self.fetch_all_vouchers(site_id).await?;
+        Ok(vouchers_matching(&vouchers, filter, Utc::now())
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Delete every voucher on a site that currently matches `filter`.
+    ///
+    /// Only fetching the vouchers can fail the whole call; a failed delete is
+    /// recorded in [`VoucherCleanup::failed`] and the others still proceed.
+    pub async fn delete_vouchers(
+        &self,
+        site_id: &str,
+        filter: &VoucherFilter,
+    ) -> Result<VoucherCleanup> {
+        let matching = self.find_vouchers(site_id, filter).await?;
+
+        let results: Vec<(Voucher, Result<_>)> = stream::iter(matching)
+            .map(|voucher| async move {
+                let result = self
+                    .execute(&DeleteVoucher::new(site_id, &voucher.id))
+                    .await;
+                (voucher, result)
+            })
+            .buffer_unordered(MAX_CONCURRENT_REQUESTS)
+            .collect()
+            .await;
+
+        let mut cleanup = VoucherCleanup::default();
+        for (voucher, result) in results {
+            match result {
+                Ok(_) => cleanup.deleted.push(voucher),
+                Err(error) => cleanup.failed.push((voucher, error)),
+            }
+        }
+        Ok(cleanup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_vouchers_matching() {
+        let vouchers: Vec<Voucher> = serde_json::from_value(json!([
+            {"id": "fresh", "code": "1", "createdAt": "2024-01-09T00:00:00Z"},
+            {"id": "stale", "code": "2", "createdAt": "2024-01-01T00:00:00Z", "note": "Conference"},
+            {"id": "unknown-age", "code": "3"},
+            {
+                "id": "expired",
+                "code": "4",
+                "used": true,
+                "durationMinutes": 60,
+                "createdAt": "2024-01-01T00:00:00Z",
+                "usedAt": "2024-01-02T00:00:00Z",
+                "note": "conference day 2"
+            }
+        ]))
+        .unwrap();
+        let now: DateTime<Utc> = "2024-01-10T00:00:00Z".parse().unwrap();
+
+        let ids = |filter: VoucherFilter| -> Vec<String> {
+            vouchers_matching(&vouchers, &filter, now)
+                .into_iter()
+                .map(|v| v.id.clone())
+                .collect()
+        };
+
+        assert_eq!(ids(VoucherFilter::Expired), vec!["expired"]);
+        assert_eq!(ids(VoucherFilter::unused_for_days(7)), vec!["stale"]);
+        assert_eq!(
+            ids(VoucherFilter::note_contains("CONFERENCE")),
+            vec!["stale", "expired"]
+        );
+    }
+}
@@ -22,7 +22,7 @@ fn test_device_deserialization() {
     let device: Device = serde_json::from_value(json_data).unwrap();
 
     assert_eq!(device.id, "abc123");
-    assert_eq!(device.mac.as_str(), "00:11:22:33:44:55");
+    assert_eq!(device.mac.to_string(), "00:11:22:33:44:55");
     assert_eq!(device.model, "U7MSH");
     assert_eq!(device.type_field, DeviceType::AccessPoint);
     assert_eq!(device.name, "Test AP");
@@ -1,8 +1,13 @@
+use rustifi::api::acl::GetAclRules;
 use rustifi::api::clients::GetClients;
 use rustifi::api::devices::GetDevices;
-use rustifi::api::Endpoint;
-use rustifi::pagination::DEFAULT_PAGE_SIZE;
+use rustifi::api::hotspot::GetVouchers;
+use rustifi::api::networks::GetNetworks;
+use rustifi::api::resources::GetDpiCategories;
+use rustifi::api::{Endpoint, Paginated};
+use rustifi::pagination::{PageStream, DEFAULT_PAGE_SIZE};
 use rustifi::response::SiteResponse;
+use rustifi::UnifiClient;
 
 #[test]
 fn test_default_page_size() {
@@ -153,3 +158,47 @@ fn test_site_response_partial_last_page() {
                                    // next_offset() returns None when there are no more pages
     assert_eq!(response.next_offset(), None);
 }
+
+#[test]
+fn test_paginated_page_replaces_offset_and_limit() {
+    let endpoint = GetVouchers::with_pagination("site-123", 5, 5).page(200, 50);
+
+    assert_eq!(endpoint.site_id, "site-123");
+    assert_eq!(endpoint.offset, Some(200));
+    assert_eq!(endpoint.limit, Some(50));
+    assert_eq!(endpoint.build_path(), "sites/site-123/hotspot/vouchers");
+}
+
+#[test]
+fn test_paginated_list_endpoints_emit_pagination_params() {
+    let networks = GetNetworks::new("site-1");
+    assert!(networks.query_params().is_empty());
+
+    let params = networks.page(10, 20).query_params();
+    assert!(params.contains(&("offset", "10".to_string())));
+    assert!(params.contains(&("limit", "20".to_string())));
+
+    let params = GetAclRules::new("site-1").page(0, 5).query_params();
+    assert_eq!(params.len(), 2);
+    assert!(params.contains(&("limit", "5".to_string())));
+}
+
+#[test]
+fn test_paginated_global_endpoint() {
+    let endpoint = GetDpiCategories::default();
+    assert!(endpoint.query_params().is_empty());
+
+    let page = endpoint.page(100, 100);
+    assert_eq!(page.build_path(), "dpi/categories");
+    assert!(page.query_params().contains(&("offset", "100".to_string())));
+}
+
+#[test]
+fn test_generic_page_stream_is_a_stream() {
+    fn assert_stream<S: futures::Stream>(_: &S) {}
+
+    let client = UnifiClient::new("https://unifi.example.com").unwrap();
+    let stream: PageStream<'_, GetNetworks> =
+        client.stream(GetNetworks::new("site-1")).page_size(0);
+    assert_stream(&stream);
+}
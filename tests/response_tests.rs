@@ -85,3 +85,36 @@ fn test_response_with_errors() {
     assert_eq!(errors.len(), 1);
     assert_eq!(errors[0].code, "AUTH_FAIL");
 }
+
+#[test]
+fn test_api_response_error_rc() {
+    let json_data = json!({
+        "meta": {
+            "rc": "error",
+            "msg": "api.err.NoSiteContext"
+        },
+        "data": []
+    });
+
+    let response: ApiResponse<Vec<MockSite>> = serde_json::from_value(json_data).unwrap();
+    assert!(!response.is_ok());
+
+    let error = response.into_result().unwrap_err();
+    assert!(matches!(
+        &error,
+        rustifi::Error::Api { code, message } if code == "error" && message == "api.err.NoSiteContext"
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Controller returned error: api.err.NoSiteContext"
+    );
+}
+
+#[test]
+fn test_api_response_ok_without_meta() {
+    let response: ApiResponse<Vec<MockSite>> =
+        serde_json::from_value(json!({ "data": [] })).unwrap();
+
+    assert!(response.is_ok());
+    assert!(response.into_result().unwrap().is_empty());
+}
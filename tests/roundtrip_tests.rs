@@ -0,0 +1,491 @@
+use rustifi::api::acl::AclRule;
+use rustifi::api::devices::PendingDevice;
+use rustifi::api::dns::DnsPolicy;
+use rustifi::api::networks::Network;
+use rustifi::api::resources::{
+    Country, DeviceTag, DpiApplication, DpiCategory, RadiusProfile, VpnServer, VpnTunnel, Wan,
+};
+use rustifi::api::traffic::TrafficList;
+use rustifi::models::{
+    Client, Device, DeviceDetails, DeviceStatistics, FirewallPolicy, FirewallZone, Site,
+    SiteDevice, Voucher, WifiBroadcast,
+};
+use rustifi::response::{ApiResponse, SiteResponse};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Parse the fixture, serialize it back, and check the JSON is unchanged.
+fn assert_round_trip<T>(fixture: Value)
+where
+    T: DeserializeOwned + Serialize + PartialEq + std::fmt::Debug,
+{
+    let parsed: T = serde_json::from_value(fixture.clone()).unwrap();
+    let serialized = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(serialized, fixture);
+
+    let reparsed: T = serde_json::from_value(serialized).unwrap();
+    assert_eq!(reparsed, parsed);
+}
+
+#[test]
+fn test_site_round_trip() {
+    assert_round_trip::<Site>(json!({
+        "id": "5f1a2b3c4d5e6f7a8b9c0d1e",
+        "name": "default",
+        "desc": "Head Office",
+        "role": "admin",
+        "attr_no_delete": true,
+        "attr_hidden_id": "default"
+    }));
+}
+
+#[test]
+fn test_site_device_round_trip() {
+    assert_round_trip::<SiteDevice>(json!({
+        "id": "497f6eca-6276-4993-bfeb-53cbbbba6f08",
+        "macAddress": "94:2a:6f:26:c6:ca",
+        "ipAddress": "192.168.1.55",
+        "name": "IW HD",
+        "model": "UHDIW",
+        "state": "ONLINE",
+        "supported": true,
+        "firmwareVersion": "6.6.55",
+        "firmwareUpdatable": true,
+        "features": ["switching", "accessPoint"],
+        "interfaces": ["ports", "radios"]
+    }));
+}
+
+#[test]
+fn test_client_round_trip() {
+    assert_round_trip::<Client>(json!({
+        "id": "497f6eca-6276-4993-bfeb-53cbbbba6f08",
+        "type": "WIRELESS",
+        "name": "My Laptop",
+        "connectedAt": "2019-08-24T14:15:22Z",
+        "macAddress": "da:a1:19:00:11:22",
+        "ipAddress": "192.168.1.50",
+        "ipv6Addresses": ["fe80::1", "2a01:4f8:c0c:1234::50"],
+        "access": {
+            "type": "GUEST"
+        },
+        "uplinkDeviceId": "device-456",
+        "hostname": "laptop",
+        "networkId": "net-1",
+        "vlanId": 10,
+        "wifiBroadcastId": "wlan-1",
+        "ssid": "Home",
+        "frequencyGHz": 5.0,
+        "channel": 36,
+        "rssi": 40,
+        "signalDbm": -55,
+        "txRateKbps": 866700,
+        "rxRateKbps": 780000,
+        "txBytes": 123456789,
+        "rxBytes": 987654,
+        "fixedIpAddress": "192.168.1.50",
+        "note": "Work laptop"
+    }));
+}
+
+#[test]
+fn test_device_details_round_trip() {
+    assert_round_trip::<DeviceDetails>(json!({
+        "id": "497f6eca-6276-4993-bfeb-53cbbbba6f08",
+        "macAddress": "94:2a:6f:26:c6:ca",
+        "ipAddress": "192.168.1.55",
+        "name": "IW HD",
+        "model": "UHDIW",
+        "supported": true,
+        "state": "ONLINE",
+        "firmwareVersion": "6.6.55",
+        "firmwareUpdatable": true,
+        "adoptedAt": "2019-08-24T14:15:22Z",
+        "provisionedAt": "2019-08-24T14:15:22.123+02:00",
+        "configurationId": "7596498d2f367dc2",
+        "uplink": {
+            "deviceId": "4de4adb9-21ee-47e3-aeb4-8cf8ed6c109a"
+        },
+        "features": {
+            "switching": {},
+            "accessPoint": {}
+        },
+        "interfaces": {
+            "ports": [
+                {
+                    "idx": 1,
+                    "state": "UP",
+                    "connector": "RJ45",
+                    "maxSpeedMbps": 1000,
+                    "speedMbps": 1000,
+                    "poe": {
+                        "standard": "802.3bt",
+                        "type": 3,
+                        "enabled": true,
+                        "state": "UP"
+                    }
+                },
+                {
+                    "idx": 2,
+                    "state": "DOWN",
+                    "connector": "SFPPLUS",
+                    "maxSpeedMbps": 10000
+                }
+            ],
+            "radios": [
+                {
+                    "wlanStandard": "802.11ax",
+                    "frequencyGHz": 5.0,
+                    "channelWidthMHz": 80,
+                    "channel": 36
+                }
+            ]
+        }
+    }));
+}
+
+#[test]
+fn test_device_statistics_round_trip() {
+    assert_round_trip::<DeviceStatistics>(json!({
+        "uptimeSec": 86400,
+        "lastHeartbeatAt": "2019-08-24T14:15:22Z",
+        "nextHeartbeatAt": "2019-08-24T14:15:52Z",
+        "loadAverage1Min": 0.5,
+        "loadAverage5Min": 0.4,
+        "loadAverage15Min": 0.3,
+        "cpuUtilizationPct": 12.5,
+        "memoryUtilizationPct": 48.2,
+        "uplink": {
+            "txRateBps": 1000000,
+            "rxRateBps": 2000000
+        },
+        "interfaces": {
+            "radios": [
+                {
+                    "frequencyGhz": 2.4,
+                    "txRetriesPct": 3.1
+                }
+            ]
+        }
+    }));
+}
+
+#[test]
+fn test_legacy_device_round_trip() {
+    assert_round_trip::<Device>(json!({
+        "id": "abc123",
+        "mac": "00:11:22:33:44:55",
+        "model": "U7MSH",
+        "type": "uap",
+        "name": "Test AP",
+        "ip": "192.168.1.10",
+        "version": "6.5.28",
+        "serial": "ABC123456",
+        "adopted": true,
+        "disabled": false,
+        "disconnected": false,
+        "last_seen": 1613456789,
+        "uptime": 86400,
+        "sys_stats": {
+            "cpu_load": 0.25,
+            "mem_used": 1024,
+            "mem_total": 4096
+        },
+        "config_network": {
+            "ip": "192.168.1.10",
+            "netmask": "255.255.255.0",
+            "gateway": "192.168.1.1",
+            "dns1": "1.1.1.1",
+            "dns2": "2606:4700:4700::1111"
+        },
+        "uplink": {
+            "mac": "aa:bb:cc:dd:ee:ff",
+            "ip": "192.168.1.1",
+            "speed": 1000,
+            "full_duplex": true,
+            "type_": "wire",
+            "state": "up"
+        },
+        "temperature": 45.5
+    }));
+}
+
+#[test]
+fn test_network_round_trip() {
+    assert_round_trip::<Network>(json!({
+        "id": "net-1",
+        "name": "IoT",
+        "enabled": true,
+        "vlanId": 20,
+        "management": "GATEWAY",
+        "metadata": {
+            "origin": "USER_DEFINED"
+        },
+        "ipv4Configuration": {
+            "hostIpAddress": "10.0.20.1",
+            "prefixLength": 24,
+            "dhcpConfiguration": {
+                "enabled": true,
+                "ipAddressRange": {"start": "10.0.20.100", "stop": "10.0.20.200"},
+                "leaseTimeSeconds": 86400,
+                "options": [{"type": "CUSTOM", "code": 43, "value": "0104c0a80105"}]
+            }
+        },
+        "ipv6Configuration": {"type": "SLAAC"},
+        "domainName": "iot.lan",
+        "isolationEnabled": false,
+        "igmpSnoopingEnabled": true,
+        "internetAccessEnabled": true
+    }));
+}
+
+#[test]
+fn test_firewall_round_trip() {
+    assert_round_trip::<FirewallZone>(json!({
+        "id": "zone-1",
+        "name": "Internal",
+        "networks": ["net-1", "net-2"]
+    }));
+
+    assert_round_trip::<FirewallPolicy>(json!({
+        "id": "policy-1",
+        "name": "Block IoT to LAN",
+        "action": {
+            "type": "BLOCK"
+        },
+        "sourceZoneId": "zone-iot",
+        "destinationZoneId": "zone-lan",
+        "enabled": true,
+        "order": 10,
+        "description": "Keep IoT devices isolated",
+        "protocol": "tcp",
+        "sourceAddresses": ["10.0.20.0/24", "network:net-iot"],
+        "destinationAddresses": ["10.0.0.0/24", "10.0.1.10-10.0.1.20", "list:tl-servers"],
+        "sourcePorts": [],
+        "destinationPorts": ["22", "443", "8000-8080", "list:tl-web"],
+        "connectionStates": ["NEW", "INVALID"],
+        "ipVersion": "IPV4",
+        "loggingEnabled": true,
+        "schedule": [{"days": ["Mon", "Tue"], "start": "09:00:00", "end": "17:00:00"}],
+        "updatedAt": "2024-03-01T12:00:00Z"
+    }));
+}
+
+#[test]
+fn test_voucher_round_trip() {
+    assert_round_trip::<Voucher>(json!({
+        "id": "voucher-1",
+        "code": "12345-67890",
+        "name": "Front desk",
+        "used": true,
+        "durationMinutes": 1440,
+        "dataLimitMb": 500,
+        "bandwidthLimitDown": 10000,
+        "bandwidthLimitUp": 2000,
+        "note": "Conference",
+        "createdAt": "2024-01-01T08:00:00Z",
+        "usedAt": "2024-01-01T12:00:00Z",
+        "validUntil": "2024-01-08T00:00:00Z",
+        "authorizedGuestLimit": 2,
+        "authorizedGuestCount": 1
+    }));
+}
+
+#[test]
+fn test_wifi_broadcast_round_trip() {
+    assert_round_trip::<WifiBroadcast>(json!({
+        "id": "wifi-1",
+        "name": "Guest",
+        "ssid": "Guest WiFi",
+        "enabled": true,
+        "security": "WPA2",
+        "vlanId": 30,
+        "hideSsid": false,
+        "pmfMode": "REQUIRED",
+        "broadcastingFrequenciesGHz": [5, 6],
+        "minimumDataRates": {"band5GHzKbps": 24000, "band6GHzKbps": 6000},
+        "bandSteeringEnabled": false,
+        "fastRoamingEnabled": true,
+        "bssTransitionEnabled": true,
+        "clientIsolationEnabled": true,
+        "multicastToUnicastEnabled": false,
+        "radiusProfileId": "radius-1",
+        "schedule": [{"days": ["Fri", "Sat"], "start": "22:00:00", "end": "02:00:00"}],
+        "broadcastingDeviceFilter": {"type": "DEVICES", "deviceIds": ["dev-1"]}
+    }));
+}
+
+#[test]
+fn test_resource_models_round_trip() {
+    assert_round_trip::<AclRule>(json!({
+        "id": "acl-1",
+        "name": "Block camera",
+        "action": "BLOCK",
+        "enabled": true,
+        "order": 1,
+        "description": "No camera to internet",
+        "sourceType": "MAC",
+        "sourceValue": "aa:bb:cc:dd:ee:ff",
+        "destinationType": "NETWORK",
+        "destinationValue": "net-1",
+        "updatedAt": "2024-03-01T12:00:00Z"
+    }));
+
+    assert_round_trip::<DnsPolicy>(json!({
+        "id": "dns-1",
+        "name": "Block ads",
+        "action": "BLOCK",
+        "enabled": true,
+        "description": "Ad domains",
+        "domains": ["ads.example.com"],
+        "categories": ["advertising"]
+    }));
+
+    assert_round_trip::<TrafficList>(json!({
+        "id": "list-1",
+        "name": "Web ports",
+        "listType": "PORT",
+        "description": "HTTP and HTTPS",
+        "entries": ["80", "443"]
+    }));
+
+    assert_round_trip::<PendingDevice>(json!({
+        "id": "pending-1",
+        "mac": "aa:bb:cc:dd:ee:ff",
+        "model": "U6-Pro",
+        "name": "New AP",
+        "ipAddress": "192.168.1.99"
+    }));
+
+    assert_round_trip::<PendingDevice>(json!({
+        "id": "pending-2",
+        "mac": "aa:bb:cc:dd:ee:00",
+        "ipAddress": "fe80::1e6a:1bff:fe00:1"
+    }));
+
+    assert_round_trip::<Wan>(json!({
+        "id": "wan-1",
+        "name": "Primary",
+        "enabled": true,
+        "interfaceType": "DHCP",
+        "ipAddress": "203.0.113.10",
+        "gateway": "203.0.113.1"
+    }));
+
+    assert_round_trip::<Wan>(json!({
+        "id": "wan-2",
+        "name": "Secondary",
+        "enabled": true,
+        "interfaceType": "SLAAC",
+        "ipAddress": "2001:db8:10::2",
+        "gateway": "fe80::1"
+    }));
+
+    assert_round_trip::<VpnServer>(json!({
+        "id": "vpn-1",
+        "name": "Remote access",
+        "enabled": true,
+        "vpnType": "WIREGUARD",
+        "status": "CONNECTED"
+    }));
+
+    assert_round_trip::<VpnTunnel>(json!({
+        "id": "tunnel-1",
+        "name": "Branch",
+        "enabled": false,
+        "status": "DISCONNECTED",
+        "remoteHost": "vpn.example.com"
+    }));
+
+    assert_round_trip::<RadiusProfile>(json!({
+        "id": "radius-1",
+        "name": "Corp",
+        "enabled": true,
+        "ipAddress": "10.0.0.5",
+        "port": 1812
+    }));
+
+    assert_round_trip::<DeviceTag>(json!({
+        "id": "tag-1",
+        "name": "Lobby",
+        "color": "#ff0000",
+        "deviceIds": ["device-1", "device-2"]
+    }));
+
+    assert_round_trip::<DpiCategory>(json!({
+        "id": "cat-1",
+        "name": "Streaming",
+        "description": "Video and music",
+        "applications": ["app-1"]
+    }));
+
+    assert_round_trip::<DpiApplication>(json!({
+        "id": "app-1",
+        "name": "Netflix",
+        "categoryId": "cat-1",
+        "description": "Video streaming"
+    }));
+
+    assert_round_trip::<Country>(json!({
+        "code": "NZ",
+        "name": "New Zealand"
+    }));
+}
+
+#[test]
+fn test_optional_fields_stay_absent() {
+    let fixture = json!({
+        "id": "client-789",
+        "type": "WIRED"
+    });
+
+    let client: Client = serde_json::from_value(fixture.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&client).unwrap(), fixture);
+}
+
+#[test]
+fn test_mac_address_serializes_canonical() {
+    let client: Client = serde_json::from_value(json!({
+        "id": "client-1",
+        "type": "WIRED",
+        "macAddress": "AA-BB-CC-DD-EE-FF"
+    }))
+    .unwrap();
+
+    let value = serde_json::to_value(&client).unwrap();
+    assert_eq!(value["macAddress"], "aa:bb:cc:dd:ee:ff");
+}
+
+#[test]
+fn test_response_wrappers_round_trip() {
+    let fixture = json!({
+        "offset": 0,
+        "limit": 25,
+        "count": 1,
+        "totalCount": 1,
+        "data": [
+            {
+                "id": "zone-1",
+                "name": "Internal",
+                "networks": []
+            }
+        ]
+    });
+    let parsed: SiteResponse<FirewallZone> = serde_json::from_value(fixture.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), fixture);
+
+    let fixture = json!({
+        "data": [
+            {
+                "id": "5f1a2b3c4d5e6f7a8b9c0d1e",
+                "name": "default"
+            }
+        ],
+        "meta": {
+            "rc": "ok"
+        }
+    });
+    let parsed: ApiResponse<Vec<Site>> = serde_json::from_value(fixture.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&parsed).unwrap(), fixture);
+}
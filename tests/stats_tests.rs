@@ -11,10 +11,16 @@ fn make_client(
         id: id.to_string(),
         client_type,
         name: Some(format!("Client {}", id)),
-        connected_at: Some("2024-01-01T12:00:00Z".to_string()),
+        connected_at: Some("2024-01-01T12:00:00Z".parse().unwrap()),
+        mac_address: None,
         ip_address: None,
-        access: access_type.map(|t| ClientAccess { access_type: t }),
+        ipv6_addresses: Vec::new(),
+        access: access_type.map(|t| ClientAccess {
+            access_type: t,
+            ..Default::default()
+        }),
         uplink_device_id: device_id.map(String::from),
+        ..Default::default()
     }
 }
 
@@ -1,18 +1,18 @@
 use rustifi::models::{IpAddress, MacAddress};
 use serde_json::json;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 #[test]
 fn test_ip_address_deserialization() {
     let json_data = json!("192.168.1.100");
     let ip: IpAddress = serde_json::from_value(json_data).unwrap();
-    assert_eq!(ip.0, Ipv4Addr::new(192, 168, 1, 100));
+    assert_eq!(ip.0, IpAddr::V4(Ipv4Addr::new(192, 168, 1, 100)));
 }
 
 #[test]
 fn test_ip_address_default() {
     let ip = IpAddress::default();
-    assert_eq!(ip.0, Ipv4Addr::new(0, 0, 0, 0));
+    assert_eq!(ip.0, IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0)));
 }
 
 #[test]
@@ -21,21 +21,130 @@ fn test_ip_address_to_string() {
     assert_eq!(ip.to_string(), "10.0.0.1");
 }
 
+#[test]
+fn test_ipv6_address_deserialization() {
+    let ip: IpAddress = serde_json::from_value(json!("2001:db8::1")).unwrap();
+    assert_eq!(
+        ip.as_ipv6(),
+        Some(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1))
+    );
+    assert!(ip.is_ipv6());
+    assert!(ip.as_ipv4().is_none());
+}
+
+#[test]
+fn test_ipv6_address_with_zone_index() {
+    let ip: IpAddress = serde_json::from_value(json!("fe80::1%br0")).unwrap();
+    assert_eq!(ip.to_string(), "fe80::1");
+    assert!(ip.is_link_local());
+}
+
+#[test]
+fn test_invalid_ip_address_rejected() {
+    assert!(serde_json::from_value::<IpAddress>(json!("not-an-ip")).is_err());
+}
+
+#[test]
+fn test_ip_address_scopes() {
+    let scope = |s: &str| {
+        let ip: IpAddress = s.parse().unwrap();
+        (ip.is_link_local(), ip.is_unique_local(), ip.is_global())
+    };
+
+    assert_eq!(scope("fe80::abcd"), (true, false, false));
+    assert_eq!(scope("169.254.10.1"), (true, false, false));
+    assert_eq!(scope("fd00:1234::1"), (false, true, false));
+    assert_eq!(scope("2606:4700::1111"), (false, false, true));
+    assert_eq!(scope("1.1.1.1"), (false, false, true));
+    assert_eq!(scope("192.168.1.1"), (false, false, false));
+    assert_eq!(scope("100.64.0.1"), (false, false, false));
+    assert_eq!(scope("2001:db8::1"), (false, false, false));
+    assert_eq!(scope("::1"), (false, false, false));
+}
+
+#[test]
+fn test_ip_address_from_std() {
+    let v4 = IpAddress::from(Ipv4Addr::new(10, 0, 0, 1));
+    let v6 = IpAddress::new(Ipv6Addr::LOCALHOST);
+    assert!(v4.is_ipv4() && v4.is_private());
+    assert_eq!(v6.to_string(), "::1");
+}
+
 #[test]
 fn test_mac_address_deserialization() {
     let json_data = json!("00:11:22:33:44:55");
     let mac: MacAddress = serde_json::from_value(json_data).unwrap();
-    assert_eq!(mac.as_str(), "00:11:22:33:44:55");
+    assert_eq!(mac.to_string(), "00:11:22:33:44:55");
+    assert_eq!(mac.octets(), [0x00, 0x11, 0x22, 0x33, 0x44, 0x55]);
 }
 
 #[test]
 fn test_mac_address_default() {
     let mac = MacAddress::default();
-    assert!(mac.as_str().is_empty());
+    assert!(mac.is_nil());
+    assert_eq!(mac.to_string(), "00:00:00:00:00:00");
 }
 
 #[test]
 fn test_mac_address_new() {
-    let mac = MacAddress::new("aa:bb:cc:dd:ee:ff");
-    assert_eq!(mac.as_str(), "aa:bb:cc:dd:ee:ff");
+    let mac = MacAddress::new("aa:bb:cc:dd:ee:ff").unwrap();
+    assert_eq!(mac.to_string(), "aa:bb:cc:dd:ee:ff");
+}
+
+#[test]
+fn test_mac_address_formats_compare_equal() {
+    let colon = MacAddress::new("aa:bb:cc:dd:ee:ff").unwrap();
+    let dash = MacAddress::new("AA-BB-CC-DD-EE-FF").unwrap();
+    let cisco = MacAddress::new("aabb.ccdd.eeff").unwrap();
+    let bare = MacAddress::new("AABBCCDDEEFF").unwrap();
+
+    assert_eq!(colon, dash);
+    assert_eq!(colon, cisco);
+    assert_eq!(colon, bare);
+    assert_eq!(dash.to_string(), "aa:bb:cc:dd:ee:ff");
+    assert_eq!(colon.to_string_with("-", true), "AA-BB-CC-DD-EE-FF");
+    assert_eq!(colon.to_cisco_string(), "aabb.ccdd.eeff");
+}
+
+#[test]
+fn test_invalid_mac_address_rejected() {
+    for input in [
+        "",
+        "aa:bb:cc:dd:ee",
+        "aa:bb:cc:dd:ee:gg",
+        "aa:bb-cc:dd:ee:ff",
+        "aabb.ccdd.ee",
+        "aa:bb:cc:dd:ee:ff:00",
+    ] {
+        assert!(MacAddress::new(input).is_err(), "accepted {:?}", input);
+    }
+    assert!(serde_json::from_value::<MacAddress>(json!("nope")).is_err());
+}
+
+#[test]
+fn test_mac_address_bits() {
+    let vendor = MacAddress::new("94:2a:6f:26:c6:ca").unwrap();
+    assert!(vendor.is_universal());
+    assert!(vendor.is_unicast());
+
+    let randomized = MacAddress::new("da:a1:19:12:34:56").unwrap();
+    assert!(randomized.is_locally_administered());
+
+    let multicast = MacAddress::new("01:00:5e:00:00:fb").unwrap();
+    assert!(multicast.is_multicast());
+    assert!(!multicast.is_broadcast());
+
+    let broadcast = MacAddress::from_bytes([0xff; 6]);
+    assert!(broadcast.is_broadcast());
+    assert!(broadcast.is_multicast());
+}
+
+#[cfg(feature = "oui")]
+#[test]
+fn test_mac_address_vendor() {
+    let ubiquiti = MacAddress::new("94:2a:6f:26:c6:ca").unwrap();
+    assert_eq!(ubiquiti.vendor(), Some("Ubiquiti"));
+
+    let randomized = MacAddress::new("da:a1:19:12:34:56").unwrap();
+    assert_eq!(randomized.vendor(), None);
 }
@@ -24,8 +24,8 @@ fn test_client_deserialization() {
     );
     assert_eq!(client.client_type, ClientType::Wireless);
     assert_eq!(
-        client.connected_at,
-        Some("2019-08-24T14:15:22Z".to_string())
+        client.connected_at.as_ref().map(|t| t.raw()),
+        Some("2019-08-24T14:15:22Z")
     );
     assert!(client.is_wireless());
     assert!(client.is_connected());